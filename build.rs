@@ -1,7 +1,207 @@
+// Messages that can be decoded from JSON by the HTTP gateway.
+// Matchers have no leading dot so prost-build matches them by suffix and
+// doesn't apply message attributes to nested enums and oneofs.
+const JSON_MESSAGES: &[&str] = &[
+    "render.RenderChartRequest",
+    "render.ChartSizes",
+    "render.ChartMargins",
+    "render.ChartAxes",
+    "render.ChartAxisOptions",
+    "render.ChartAxisNumberFormat",
+    "render.ChartLegend",
+    "render.ChartTheme",
+    "render.ChartAnnotation",
+    "render.ChartAnnotationValue",
+    "render.ChartScale",
+    "render.DomainNumeric",
+    "render.DomainCategories",
+    "render.DomainTime",
+    "render.DomainAuto",
+    "render.ChartView",
+    "render.ChartViewColors",
+    "render.ChartViewHistogramBinning",
+    "render.ChartElementColor",
+    "render.ChartElementColor.RGB",
+    "render.ChartViewBarsValues",
+    "render.ChartViewBarsValues.ChartViewBarsColors",
+    "render.ChartViewBarsValues.BarsDataset",
+    "render.ChartViewPointsValues",
+    "render.ChartViewPointsValues.Point",
+    "render.ChartViewScalarValues",
+    "render.ChartViewSlicesValues",
+    "render.ChartViewSlicesValues.Slice",
+];
+
+// Oneofs of JSON messages, they are flattened into their messages.
+const JSON_ONEOFS: &[&str] = &[
+    "render.ChartScale.domain",
+    "render.ChartView.values",
+    "render.ChartAnnotationValue.value",
+    "render.ChartElementColor.color_value",
+];
+
+// Timestamp fields of JSON messages, they are set with RFC 3339 strings.
+const JSON_TIMESTAMPS: &[&str] = &[
+    "render.DomainTime.start",
+    "render.DomainTime.end",
+    "render.ChartViewPointsValues.Point.time",
+];
+
+// Timestamp fields of JSON oneofs, they are set with RFC 3339 strings.
+const JSON_ONEOF_TIMESTAMPS: &[&str] = &["render.ChartAnnotationValue.value.time"];
+
+// Enum fields of JSON messages with their enum types.
+// Values can be set with enum value names or numbers.
+const JSON_ENUMS: &[(&str, &str, &str)] = &[
+    (
+        "render.RenderChartRequest.output_format",
+        "render.ChartOutputFormat",
+        "ChartOutputFormat",
+    ),
+    (
+        "render.ChartAxisNumberFormat.kind",
+        "render.ChartAxisNumberFormat.ChartAxisNumberFormatKind",
+        "chart_axis_number_format::ChartAxisNumberFormatKind",
+    ),
+    (
+        "render.ChartAnnotation.kind",
+        "render.ChartAnnotation.ChartAnnotationKind",
+        "chart_annotation::ChartAnnotationKind",
+    ),
+    (
+        "render.ChartAnnotation.layer",
+        "render.ChartAnnotation.ChartAnnotationLayer",
+        "chart_annotation::ChartAnnotationLayer",
+    ),
+    (
+        "render.ChartAnnotation.horizontal_axis",
+        "render.ChartView.ChartViewHorizontalAxis",
+        "chart_view::ChartViewHorizontalAxis",
+    ),
+    (
+        "render.ChartAnnotation.vertical_axis",
+        "render.ChartView.ChartViewVerticalAxis",
+        "chart_view::ChartViewVerticalAxis",
+    ),
+    (
+        "render.ChartLegend.position",
+        "render.ChartLegend.ChartLegendPosition",
+        "chart_legend::ChartLegendPosition",
+    ),
+    (
+        "render.ChartLegend.orientation",
+        "render.ChartLegend.ChartLegendOrientation",
+        "chart_legend::ChartLegendOrientation",
+    ),
+    (
+        "render.ChartTheme.name",
+        "render.ChartTheme.ChartThemeName",
+        "chart_theme::ChartThemeName",
+    ),
+    (
+        "render.ChartScale.kind",
+        "render.ChartScale.ChartScaleKind",
+        "chart_scale::ChartScaleKind",
+    ),
+    (
+        "render.ChartView.kind",
+        "render.ChartView.ChartViewKind",
+        "chart_view::ChartViewKind",
+    ),
+    (
+        "render.ChartView.bar_label_position",
+        "render.ChartView.ChartViewBarLabelPosition",
+        "chart_view::ChartViewBarLabelPosition",
+    ),
+    (
+        "render.ChartView.bar_layout",
+        "render.ChartView.ChartViewBarLayout",
+        "chart_view::ChartViewBarLayout",
+    ),
+    (
+        "render.ChartView.point_type",
+        "render.ChartView.ChartViewPointType",
+        "chart_view::ChartViewPointType",
+    ),
+    (
+        "render.ChartView.point_label_position",
+        "render.ChartView.ChartViewPointLabelPosition",
+        "chart_view::ChartViewPointLabelPosition",
+    ),
+    (
+        "render.ChartView.horizontal_axis",
+        "render.ChartView.ChartViewHorizontalAxis",
+        "chart_view::ChartViewHorizontalAxis",
+    ),
+    (
+        "render.ChartView.vertical_axis",
+        "render.ChartView.ChartViewVerticalAxis",
+        "chart_view::ChartViewVerticalAxis",
+    ),
+    (
+        "render.ChartViewHistogramBinning.kind",
+        "render.ChartViewHistogramBinning.ChartViewHistogramBinningKind",
+        "chart_view_histogram_binning::ChartViewHistogramBinningKind",
+    ),
+];
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::configure().out_dir("src/proto").compile(
-        &["proto/render/v0/renderer_service.proto"],
-        &["proto/render/v0"],
+    let mut builder = tonic_build::configure().out_dir("src/proto");
+
+    for message in JSON_MESSAGES {
+        builder = builder.type_attribute(
+            message,
+            "#[derive(serde::Deserialize)]\n#[serde(default, deny_unknown_fields, rename_all = \"camelCase\")]",
+        );
+    }
+    for oneof in JSON_ONEOFS {
+        builder = builder
+            .type_attribute(
+                oneof,
+                "#[derive(serde::Deserialize)]\n#[serde(rename_all = \"camelCase\")]",
+            )
+            .field_attribute(oneof, "#[serde(flatten)]");
+    }
+    for field in JSON_TIMESTAMPS {
+        builder = builder.field_attribute(
+            field,
+            "#[serde(deserialize_with = \"crate::json::timestamp\")]",
+        );
+    }
+    for field in JSON_ONEOF_TIMESTAMPS {
+        builder = builder.field_attribute(
+            field,
+            "#[serde(deserialize_with = \"crate::json::oneof_timestamp\")]",
+        );
+    }
+    for (idx, (field, enumeration, rust_type)) in JSON_ENUMS.iter().enumerate() {
+        // Enums that are shared by several fields are derived once.
+        if !JSON_ENUMS[..idx]
+            .iter()
+            .any(|(_, prev, _)| prev == enumeration)
+        {
+            builder = builder.type_attribute(
+                enumeration,
+                "#[derive(serde::Deserialize)]\n#[serde(rename_all = \"SCREAMING_SNAKE_CASE\")]",
+            );
+        }
+        builder = builder.field_attribute(
+            field,
+            format!(
+                "#[serde(deserialize_with = \"crate::json::enumeration::<{}, _>\")]",
+                rust_type
+            ),
+        );
+    }
+
+    builder.compile(
+        &[
+            "proto/render/v0/renderer_service.proto",
+            "proto/render/v0/api_service.proto",
+            "proto/google/rpc/status.proto",
+            "proto/google/rpc/error_details.proto",
+        ],
+        &["proto/render/v0", "proto"],
     )?;
 
     Ok(())
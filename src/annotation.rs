@@ -0,0 +1,292 @@
+use crate::color::get_color;
+use crate::draw::annotation::{AnnotationShape, AnnotationView};
+use crate::error::RendererError;
+use crate::proto::render::chart_annotation::{ChartAnnotationKind, ChartAnnotationLayer};
+use crate::proto::render::chart_annotation_value::Value;
+use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+use crate::proto::render::{ChartAnnotation, ChartAnnotationValue, ChartAxes, ChartScale};
+use crate::scale::{
+    get_band_h_scale, get_band_v_scale, get_h_axis, get_numeric_h_scale, get_numeric_v_scale,
+    get_time, get_time_scale, get_v_axis, is_time_valid,
+};
+use lc_render::Scale;
+
+/// Annotation views split by the layer they are drawn on.
+#[derive(Clone, Default)]
+pub(crate) struct AnnotationViews {
+    pub(crate) below: Vec<AnnotationView>,
+    pub(crate) above: Vec<AnnotationView>,
+}
+
+// Get scaled position of an annotation value on the scale.
+// Categories are placed in the middle of their bands, None is returned if the value
+// doesn't match the scale kind or its domain.
+fn get_position(
+    value: &ChartAnnotationValue,
+    scale: &ChartScale,
+    horizontal: bool,
+) -> Result<Option<f32>, RendererError> {
+    let position = match (ChartScaleKind::from_i32(scale.kind), &value.value) {
+        (Some(ChartScaleKind::Linear), Some(Value::Number(number)))
+        | (Some(ChartScaleKind::Log), Some(Value::Number(number))) => {
+            if !number.is_finite() {
+                return Ok(None);
+            }
+            let numeric_scale = if horizontal {
+                get_numeric_h_scale(scale)?
+            } else {
+                get_numeric_v_scale(scale)?
+            };
+            numeric_scale.scale_value(*number).ok()
+        }
+        (Some(ChartScaleKind::Band), Some(Value::Category(category))) => {
+            match &scale.domain {
+                Some(Domain::DomainCategories(domain)) if domain.categories.contains(category) => {}
+                _ => return Ok(None),
+            }
+            let band_scale = if horizontal {
+                get_band_h_scale(scale)?
+            } else {
+                get_band_v_scale(scale)?
+            };
+            Some(band_scale.scale(category) + band_scale.bandwidth() / 2_f32)
+        }
+        (Some(ChartScaleKind::Time), Some(Value::Time(time))) => {
+            if !is_time_valid(time) {
+                return Ok(None);
+            }
+            Some(get_time_scale(scale)?.scale(&get_time(time)))
+        }
+        _ => None,
+    };
+
+    Ok(position)
+}
+
+// Get scaled position of a required annotation value with errors for its field.
+fn get_required_position(
+    value: &Option<ChartAnnotationValue>,
+    scale: &ChartScale,
+    horizontal: bool,
+    not_specified: RendererError,
+    invalid: RendererError,
+) -> Result<f32, RendererError> {
+    let value = match value {
+        Some(value) => value,
+        None => return Err(not_specified),
+    };
+    match get_position(value, scale, horizontal)? {
+        Some(position) => Ok(position),
+        None => Err(invalid),
+    }
+}
+
+// Get horizontal position of the annotation from its x value.
+fn get_x(annotation: &ChartAnnotation, axes: &ChartAxes) -> Result<f32, RendererError> {
+    let (_, h_scale) = get_h_axis(axes, annotation.horizontal_axis)?;
+    get_required_position(
+        &annotation.x,
+        h_scale,
+        true,
+        RendererError::AnnotationXIsNotSpecified,
+        RendererError::AnnotationXIsInvalid,
+    )
+}
+
+// Get horizontal position of the annotation from its x_end value.
+fn get_x_end(annotation: &ChartAnnotation, axes: &ChartAxes) -> Result<f32, RendererError> {
+    let (_, h_scale) = get_h_axis(axes, annotation.horizontal_axis)?;
+    get_required_position(
+        &annotation.x_end,
+        h_scale,
+        true,
+        RendererError::AnnotationXEndIsNotSpecified,
+        RendererError::AnnotationXEndIsInvalid,
+    )
+}
+
+// Get vertical position of the annotation from its y value.
+fn get_y(annotation: &ChartAnnotation, axes: &ChartAxes) -> Result<f32, RendererError> {
+    let (_, v_scale) = get_v_axis(axes, annotation.vertical_axis)?;
+    get_required_position(
+        &annotation.y,
+        v_scale,
+        false,
+        RendererError::AnnotationYIsNotSpecified,
+        RendererError::AnnotationYIsInvalid,
+    )
+}
+
+// Get vertical position of the annotation from its y_end value.
+fn get_y_end(annotation: &ChartAnnotation, axes: &ChartAxes) -> Result<f32, RendererError> {
+    let (_, v_scale) = get_v_axis(axes, annotation.vertical_axis)?;
+    get_required_position(
+        &annotation.y_end,
+        v_scale,
+        false,
+        RendererError::AnnotationYEndIsNotSpecified,
+        RendererError::AnnotationYEndIsInvalid,
+    )
+}
+
+// Get annotation view from protobuf with its values resolved through the scales of axes.
+// Only the values that are used by the annotation kind are required.
+pub(crate) fn get_annotation_view(
+    annotation: &ChartAnnotation,
+    axes: Option<&ChartAxes>,
+    view_width: i32,
+    view_height: i32,
+) -> Result<AnnotationView, RendererError> {
+    let kind = match ChartAnnotationKind::from_i32(annotation.kind) {
+        Some(ChartAnnotationKind::UnspecifiedAnnotationKind) | None => {
+            return Err(RendererError::AnnotationKindIsUnknown)
+        }
+        Some(kind) => kind,
+    };
+    let axes = match axes {
+        Some(axes) => axes,
+        None => return Err(RendererError::ChartAxesAreNotSpecified),
+    };
+
+    let shape = match kind {
+        ChartAnnotationKind::HorizontalLine => {
+            AnnotationShape::HorizontalLine(get_y(annotation, axes)?)
+        }
+        ChartAnnotationKind::VerticalLine => {
+            AnnotationShape::VerticalLine(get_x(annotation, axes)?)
+        }
+        ChartAnnotationKind::HorizontalRange => {
+            AnnotationShape::HorizontalRange(get_y(annotation, axes)?, get_y_end(annotation, axes)?)
+        }
+        ChartAnnotationKind::VerticalRange => {
+            AnnotationShape::VerticalRange(get_x(annotation, axes)?, get_x_end(annotation, axes)?)
+        }
+        _ => AnnotationShape::Text(get_x(annotation, axes)?, get_y(annotation, axes)?),
+    };
+
+    Ok(AnnotationView::new(shape, view_width, view_height)
+        .set_text(&annotation.text)
+        .set_stroke_color(get_color(annotation.stroke.clone()).map(|color| color.to_string()))
+        .set_fill_color(get_color(annotation.fill.clone()).map(|color| color.to_string())))
+}
+
+// Check if the annotation is drawn below the views.
+pub(crate) fn is_below_views(annotation: &ChartAnnotation) -> Result<bool, RendererError> {
+    match ChartAnnotationLayer::from_i32(annotation.layer) {
+        Some(ChartAnnotationLayer::BelowViews) => Ok(true),
+        Some(_) => Ok(false),
+        None => Err(RendererError::AnnotationLayerIsUnknown),
+    }
+}
+
+// Get views of chart annotations from protobuf.
+pub(crate) fn get_annotation_views(
+    annotations: &[ChartAnnotation],
+    axes: Option<&ChartAxes>,
+    view_width: i32,
+    view_height: i32,
+) -> Result<AnnotationViews, RendererError> {
+    let mut res = AnnotationViews::default();
+    for annotation in annotations.iter() {
+        let below_views = is_below_views(annotation)?;
+        let annotation_view = get_annotation_view(annotation, axes, view_width, view_height)?;
+        if below_views {
+            res.below.push(annotation_view);
+        } else {
+            res.above.push(annotation_view);
+        }
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::{DomainCategories, DomainNumeric};
+
+    fn chart_axes() -> ChartAxes {
+        ChartAxes {
+            axis_bottom: Some(ChartScale {
+                kind: ChartScaleKind::Band as i32,
+                range_start: Some(0),
+                range_end: Some(100),
+                domain: Some(Domain::DomainCategories(DomainCategories {
+                    categories: vec!["A".to_string(), "B".to_string()],
+                })),
+                no_boundaries_offset: false,
+                inner_padding: Some(0_f32),
+                outer_padding: Some(0_f32),
+                log_base: None,
+                time_format: String::new(),
+            }),
+            axis_left: Some(ChartScale {
+                kind: ChartScaleKind::Linear as i32,
+                range_start: Some(100),
+                range_end: Some(0),
+                domain: Some(Domain::DomainNumeric(DomainNumeric {
+                    start: 0_f32,
+                    end: 10_f32,
+                })),
+                no_boundaries_offset: false,
+                inner_padding: None,
+                outer_padding: None,
+                log_base: None,
+                time_format: String::new(),
+            }),
+            ..ChartAxes::default()
+        }
+    }
+
+    fn number(number: f32) -> Option<ChartAnnotationValue> {
+        Some(ChartAnnotationValue {
+            value: Some(Value::Number(number)),
+        })
+    }
+
+    fn category(category: &str) -> Option<ChartAnnotationValue> {
+        Some(ChartAnnotationValue {
+            value: Some(Value::Category(category.to_string())),
+        })
+    }
+
+    #[test]
+    fn get_annotation_views_basic() {
+        let annotations = vec![
+            ChartAnnotation {
+                kind: ChartAnnotationKind::HorizontalRange as i32,
+                y: number(2_f32),
+                y_end: number(4_f32),
+                layer: ChartAnnotationLayer::BelowViews as i32,
+                ..ChartAnnotation::default()
+            },
+            ChartAnnotation {
+                kind: ChartAnnotationKind::Text as i32,
+                x: category("B"),
+                y: number(5_f32),
+                text: "Peak".to_string(),
+                ..ChartAnnotation::default()
+            },
+        ];
+
+        let annotation_views =
+            get_annotation_views(&annotations, Some(&chart_axes()), 100, 100).unwrap();
+
+        assert_eq!(1, annotation_views.below.len());
+        assert_eq!(1, annotation_views.above.len());
+        let text_svg = lc_render::View::to_svg(&annotation_views.above[0]).to_string();
+        assert!(text_svg.contains(r#"x="75" y="50""#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_annotation_view_err() {
+        let annotation = ChartAnnotation {
+            kind: ChartAnnotationKind::VerticalLine as i32,
+            x: category("C"),
+            ..ChartAnnotation::default()
+        };
+
+        get_annotation_view(&annotation, Some(&chart_axes()), 100, 100).unwrap();
+    }
+}
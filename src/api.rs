@@ -0,0 +1,316 @@
+use crate::defaults::Defaults;
+use crate::error::RendererError;
+use crate::proto::render::chart_api_server::ChartApi;
+use crate::proto::render::{
+    ChartReply, ChartStatus, CreateChartRequest, GetChartRequest, RenderChartRequest,
+};
+use crate::renderer::{render, ERR_UNABLE_TO_RENDER_CHART, LOG_KEY_ERR, LOG_KEY_REQ_ID};
+use crate::storage::ChartStorage;
+use crate::validation::get_invalid_argument_status;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+
+const ERR_CHART_IS_NOT_FOUND: &str = "Chart is not found";
+const ERR_UNABLE_TO_CREATE_CHART: &str = "Unable to create chart";
+const ERR_UNABLE_TO_GET_CHART: &str = "Unable to get chart";
+
+const LOG_KEY_CHART_ID: &str = "chart_id";
+
+/// Maximum TTL of created charts, it's one year.
+pub(crate) const MAX_CHART_TTL: Duration = Duration::from_secs(366 * 24 * 60 * 60);
+
+#[derive(Debug)]
+pub struct ApiServer {
+    log: slog::Logger,
+    storage: Arc<dyn ChartStorage>,
+    chart_ttl: Duration,
+    defaults: Defaults,
+}
+
+impl ApiServer {
+    pub(crate) fn new(
+        log: slog::Logger,
+        storage: Arc<dyn ChartStorage>,
+        chart_ttl: Duration,
+        defaults: Defaults,
+    ) -> ApiServer {
+        ApiServer {
+            log,
+            storage,
+            chart_ttl,
+            defaults,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ChartApi for ApiServer {
+    async fn create_chart(
+        &self,
+        request: Request<CreateChartRequest>,
+    ) -> Result<Response<ChartReply>, Status> {
+        let request_id = new_id();
+        let chart_id = new_id();
+
+        // Prepare request logger with request_id and chart_id set.
+        let log = self.log.new(o!(
+            LOG_KEY_REQ_ID => request_id.clone(),
+            LOG_KEY_CHART_ID => chart_id.clone(),
+        ));
+
+        let c_req = request.into_inner();
+        let chart_ttl = match get_chart_ttl(c_req.ttl.clone(), self.chart_ttl) {
+            Ok(chart_ttl) => chart_ttl,
+            Err(err) => {
+                let err = err.to_string();
+                error!(log, "{}", ERR_UNABLE_TO_CREATE_CHART; LOG_KEY_ERR => err.clone());
+                return Err(Status::invalid_argument(err));
+            }
+        };
+
+        // Render chart with the same pipeline and defaults as ChartRenderer service.
+        let mut r_req = get_render_chart_request(c_req, &request_id);
+        let defaulted_fields = self.defaults.apply(&mut r_req);
+        let output = match render(&r_req) {
+            Ok(output) => output,
+            Err(err) => {
+                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.to_string());
+                return Err(get_invalid_argument_status(&r_req, &err));
+            }
+        };
+
+        let created_at = SystemTime::now();
+        let deleted_at = match created_at.checked_add(chart_ttl) {
+            Some(deleted_at) => deleted_at,
+            None => {
+                let err = RendererError::ChartTtlIsTooLong.to_string();
+                error!(log, "{}", ERR_UNABLE_TO_CREATE_CHART; LOG_KEY_ERR => err.clone());
+                return Err(Status::invalid_argument(err));
+            }
+        };
+        let chart_reply = ChartReply {
+            request_id,
+            chart_id,
+            chart_status: ChartStatus::Created as i32,
+            created_at: Some(created_at.into()),
+            deleted_at: Some(deleted_at.into()),
+            chart_data: output.data,
+            defaulted_fields,
+        };
+        if let Err(err) = self.storage.put(chart_reply.clone()).await {
+            let err = err.to_string();
+            error!(log, "{}", ERR_UNABLE_TO_CREATE_CHART; LOG_KEY_ERR => err.clone());
+            return Err(Status::internal(err));
+        }
+
+        Ok(Response::new(chart_reply))
+    }
+
+    async fn get_chart(
+        &self,
+        request: Request<GetChartRequest>,
+    ) -> Result<Response<ChartReply>, Status> {
+        let request_id = new_id();
+        let chart_id = request.into_inner().chart_id;
+
+        // Prepare request logger with request_id and chart_id set.
+        let log = self.log.new(o!(
+            LOG_KEY_REQ_ID => request_id.clone(),
+            LOG_KEY_CHART_ID => chart_id.clone(),
+        ));
+
+        let mut chart_reply = match self.storage.get(&chart_id, SystemTime::now()).await {
+            Ok(Some(chart_reply)) => chart_reply,
+            Ok(None) => {
+                error!(log, "{}", ERR_CHART_IS_NOT_FOUND);
+                return Err(Status::not_found(ERR_CHART_IS_NOT_FOUND));
+            }
+            Err(RendererError::ChartIdIsInvalid) => {
+                let err = RendererError::ChartIdIsInvalid.to_string();
+                error!(log, "{}", ERR_UNABLE_TO_GET_CHART; LOG_KEY_ERR => err.clone());
+                return Err(Status::invalid_argument(err));
+            }
+            Err(err) => {
+                let err = err.to_string();
+                error!(log, "{}", ERR_UNABLE_TO_GET_CHART; LOG_KEY_ERR => err.clone());
+                return Err(Status::internal(err));
+            }
+        };
+        chart_reply.request_id = request_id;
+
+        Ok(Response::new(chart_reply))
+    }
+}
+
+// Generate a new random ID for requests and charts.
+fn new_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+// Get chart TTL from protobuf or use the default one.
+// TTL can't be longer than the maximum one.
+fn get_chart_ttl(
+    ttl: Option<prost_types::Duration>,
+    default_ttl: Duration,
+) -> Result<Duration, RendererError> {
+    match ttl {
+        Some(ttl) => match Duration::try_from(ttl) {
+            Ok(ttl) if ttl > MAX_CHART_TTL => Err(RendererError::ChartTtlIsTooLong),
+            Ok(ttl) => Ok(ttl),
+            Err(_) => Err(RendererError::ChartTtlIsNegative),
+        },
+        None => Ok(default_ttl),
+    }
+}
+
+// Get RenderChartRequest from CreateChartRequest.
+fn get_render_chart_request(c_req: CreateChartRequest, request_id: &str) -> RenderChartRequest {
+    RenderChartRequest {
+        request_id: request_id.to_string(),
+        title: c_req.title,
+        sizes: c_req.sizes,
+        margins: c_req.margins,
+        axes: c_req.axes,
+        views: c_req.views,
+        legend: c_req.legend,
+        theme: c_req.theme,
+        annotations: c_req.annotations,
+        ..RenderChartRequest::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::render_chart_request;
+    use crate::storage::memory::MemoryStorage;
+
+    fn api_server() -> ApiServer {
+        let log = slog::Logger::root(slog::Discard, o!());
+        ApiServer::new(
+            log,
+            Arc::new(MemoryStorage::new()),
+            Duration::from_secs(60),
+            Defaults::default(),
+        )
+    }
+
+    fn create_chart_request() -> CreateChartRequest {
+        let r_req = render_chart_request("");
+        CreateChartRequest {
+            title: "Chart".to_string(),
+            sizes: r_req.sizes,
+            margins: r_req.margins,
+            axes: r_req.axes,
+            views: r_req.views,
+            ttl: None,
+            legend: r_req.legend,
+            theme: r_req.theme,
+            annotations: r_req.annotations,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_chart_basic() {
+        let server = api_server();
+
+        let created = server
+            .create_chart(Request::new(create_chart_request()))
+            .await
+            .unwrap()
+            .into_inner();
+        let got = server
+            .get_chart(Request::new(GetChartRequest {
+                chart_id: created.chart_id.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(ChartStatus::Created as i32, created.chart_status);
+        assert!(!created.chart_data.is_empty());
+        assert_eq!(created.chart_id, got.chart_id);
+        assert_eq!(created.created_at, got.created_at);
+        assert_eq!(created.chart_data, got.chart_data);
+        assert_eq!(
+            SystemTime::from(created.created_at.unwrap()) + Duration::from_secs(60),
+            SystemTime::from(created.deleted_at.unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn create_chart_zero_ttl() {
+        let server = api_server();
+        let mut c_req = create_chart_request();
+        c_req.ttl = Some(Duration::from_secs(0).into());
+
+        let created = server
+            .create_chart(Request::new(c_req))
+            .await
+            .unwrap()
+            .into_inner();
+        let status = server
+            .get_chart(Request::new(GetChartRequest {
+                chart_id: created.chart_id,
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(created.created_at, created.deleted_at);
+        assert_eq!(tonic::Code::NotFound, status.code());
+    }
+
+    #[tokio::test]
+    async fn create_chart_ttl_too_long() {
+        let server = api_server();
+        let mut c_req = create_chart_request();
+        c_req.ttl = Some(prost_types::Duration {
+            seconds: i64::MAX,
+            nanos: 0,
+        });
+
+        let status = server.create_chart(Request::new(c_req)).await.unwrap_err();
+
+        assert_eq!(tonic::Code::InvalidArgument, status.code());
+    }
+
+    #[tokio::test]
+    async fn get_chart_not_found() {
+        let server = api_server();
+
+        let status = server
+            .get_chart(Request::new(GetChartRequest { chart_id: new_id() }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(tonic::Code::NotFound, status.code());
+    }
+
+    #[tokio::test]
+    async fn create_chart_err() {
+        let server = api_server();
+        let mut c_req = create_chart_request();
+        c_req.views[0].colors = None;
+
+        let status = server.create_chart(Request::new(c_req)).await.unwrap_err();
+
+        assert_eq!(tonic::Code::InvalidArgument, status.code());
+    }
+
+    #[tokio::test]
+    async fn get_chart_invalid_id() {
+        let server = api_server();
+
+        let status = server
+            .get_chart(Request::new(GetChartRequest {
+                chart_id: "../chart".to_string(),
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(tonic::Code::InvalidArgument, status.code());
+    }
+}
@@ -0,0 +1,263 @@
+use crate::draw::scale::{get_linear_ticks, MAX_TICK_COUNT};
+use crate::error::RendererError;
+use crate::proto::render::chart_axis_number_format::ChartAxisNumberFormatKind;
+use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+use crate::proto::render::{ChartAxisNumberFormat, ChartAxisOptions, ChartScale};
+
+const MAX_NUMBER_FORMAT_DECIMALS: u32 = 10;
+
+// SI prefixes with their factors from the largest to the smallest one.
+const SI_PREFIXES: &[(f32, &str)] = &[
+    (1e12_f32, "T"),
+    (1e9_f32, "G"),
+    (1e6_f32, "M"),
+    (1e3_f32, "k"),
+    (1_f32, ""),
+    (1e-3_f32, "m"),
+    (1e-6_f32, "µ"),
+    (1e-9_f32, "n"),
+];
+
+/// Format of numeric tick labels.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) enum NumberFormat {
+    #[default]
+    Plain,
+    Fixed(usize),
+    Si(usize),
+    Percent(usize),
+    Currency(usize, String),
+}
+
+impl NumberFormat {
+    // Format a tick value.
+    pub(crate) fn format(&self, value: f32) -> String {
+        match self {
+            NumberFormat::Plain => value.to_string(),
+            NumberFormat::Fixed(decimals) => format!("{:.*}", decimals, value),
+            NumberFormat::Si(decimals) => {
+                let (factor, prefix) = SI_PREFIXES
+                    .iter()
+                    .find(|(factor, _)| value.abs() >= *factor)
+                    .unwrap_or(&SI_PREFIXES[SI_PREFIXES.len() - 1]);
+                // Zero is formatted without a prefix.
+                if value == 0_f32 {
+                    format!("{:.*}", decimals, value)
+                } else {
+                    format!("{:.*}{}", decimals, value / factor, prefix)
+                }
+            }
+            NumberFormat::Percent(decimals) => format!("{:.*}%", decimals, value * 100_f32),
+            NumberFormat::Currency(decimals, prefix) if value < 0_f32 => {
+                format!("-{}{:.*}", prefix, decimals, -value)
+            }
+            NumberFormat::Currency(decimals, prefix) => {
+                format!("{}{:.*}", prefix, decimals, value)
+            }
+        }
+    }
+}
+
+/// Options of an axis prepared from protobuf.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct AxisOptions {
+    pub(crate) tick_count: Option<usize>,
+    pub(crate) tick_values: Vec<f32>,
+    pub(crate) number_format: NumberFormat,
+    pub(crate) tick_label_rotation: i32,
+    pub(crate) line_hidden: bool,
+    pub(crate) major_gridlines: bool,
+    pub(crate) minor_gridlines: bool,
+}
+
+impl AxisOptions {
+    // Check if axis is drawn without any options.
+    pub(crate) fn is_default(&self) -> bool {
+        self == &AxisOptions::default()
+    }
+
+    // Get tick values of a numeric axis from ticks of its scale.
+    // Explicit tick values are used as is, other ticks are thinned out to the tick count.
+    pub(crate) fn get_ticks(&self, scale_ticks: Vec<f32>) -> Vec<f32> {
+        if !self.tick_values.is_empty() {
+            return self.tick_values.clone();
+        }
+        match self.tick_count {
+            Some(tick_count) if scale_ticks.len() > tick_count => {
+                let step = scale_ticks.len().div_ceil(tick_count);
+                scale_ticks.into_iter().step_by(step).collect()
+            }
+            _ => scale_ticks,
+        }
+    }
+}
+
+// Get number format of tick labels from protobuf.
+fn get_number_format(
+    number_format: Option<ChartAxisNumberFormat>,
+) -> Result<NumberFormat, RendererError> {
+    let number_format = match number_format {
+        Some(number_format) => number_format,
+        None => return Ok(NumberFormat::Plain),
+    };
+    if number_format.decimals > MAX_NUMBER_FORMAT_DECIMALS {
+        return Err(RendererError::AxisNumberFormatDecimalsAreInvalid);
+    }
+    let decimals = number_format.decimals as usize;
+
+    match ChartAxisNumberFormatKind::from_i32(number_format.kind) {
+        Some(ChartAxisNumberFormatKind::UnspecifiedNumberFormat) => Ok(NumberFormat::Plain),
+        Some(ChartAxisNumberFormatKind::Fixed) => Ok(NumberFormat::Fixed(decimals)),
+        Some(ChartAxisNumberFormatKind::Si) => Ok(NumberFormat::Si(decimals)),
+        Some(ChartAxisNumberFormatKind::Percent) => Ok(NumberFormat::Percent(decimals)),
+        Some(ChartAxisNumberFormatKind::Currency) => Ok(NumberFormat::Currency(
+            decimals,
+            number_format.currency_prefix,
+        )),
+        None => Err(RendererError::AxisNumberFormatIsUnknown),
+    }
+}
+
+// Get axis options from protobuf for the axis scale.
+// Ticks of linear scales with a tick count are computed from the scale domain.
+pub(crate) fn get_axis_options(
+    options: Option<ChartAxisOptions>,
+    scale: Option<&ChartScale>,
+) -> Result<AxisOptions, RendererError> {
+    let options = match options {
+        Some(options) => options,
+        None => return Ok(AxisOptions::default()),
+    };
+    let tick_count = match options.tick_count {
+        Some(tick_count) if tick_count == 0 || tick_count as usize > MAX_TICK_COUNT => {
+            return Err(RendererError::AxisTickCountIsInvalid)
+        }
+        Some(tick_count) => Some(tick_count as usize),
+        None => None,
+    };
+    if options.tick_values.len() > MAX_TICK_COUNT
+        || options.tick_values.iter().any(|value| !value.is_finite())
+    {
+        return Err(RendererError::AxisTickValuesAreInvalid);
+    }
+    let mut res = AxisOptions {
+        tick_count,
+        tick_values: options.tick_values,
+        number_format: get_number_format(options.number_format)?,
+        tick_label_rotation: options.tick_label_rotation,
+        line_hidden: options.line_hidden,
+        major_gridlines: options.major_gridlines,
+        minor_gridlines: options.minor_gridlines,
+    };
+
+    // Numeric options are checked for axes that are set.
+    let scale = match scale {
+        Some(scale) => scale,
+        None => return Ok(res),
+    };
+    let has_numeric_options = res.tick_count.is_some()
+        || !res.tick_values.is_empty()
+        || res.number_format != NumberFormat::Plain;
+    match (ChartScaleKind::from_i32(scale.kind), &scale.domain) {
+        (Some(ChartScaleKind::Linear), Some(Domain::DomainNumeric(domain))) => {
+            if let (Some(tick_count), true) = (res.tick_count, res.tick_values.is_empty()) {
+                res.tick_values = get_linear_ticks(domain.start, domain.end, tick_count);
+            }
+        }
+        (Some(ChartScaleKind::Linear), _) | (Some(ChartScaleKind::Log), _) => {}
+        _ if has_numeric_options => {
+            return Err(RendererError::AxisNumericOptionsRequireNumericScale)
+        }
+        _ => {}
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::DomainNumeric;
+
+    fn chart_scale_linear() -> ChartScale {
+        ChartScale {
+            kind: ChartScaleKind::Linear as i32,
+            range_start: Some(0),
+            range_end: Some(100),
+            domain: Some(Domain::DomainNumeric(DomainNumeric {
+                start: 0_f32,
+                end: 1000_f32,
+            })),
+            no_boundaries_offset: false,
+            inner_padding: None,
+            outer_padding: None,
+            log_base: None,
+            time_format: String::new(),
+        }
+    }
+
+    #[test]
+    fn get_axis_options_basic() {
+        let options = ChartAxisOptions {
+            tick_count: Some(5),
+            tick_values: Vec::new(),
+            number_format: Some(ChartAxisNumberFormat {
+                kind: ChartAxisNumberFormatKind::Si as i32,
+                decimals: 1,
+                currency_prefix: String::new(),
+            }),
+            tick_label_rotation: -45,
+            line_hidden: true,
+            major_gridlines: true,
+            minor_gridlines: false,
+        };
+
+        let options = get_axis_options(Some(options), Some(&chart_scale_linear())).unwrap();
+
+        assert_eq!(
+            vec![0_f32, 200_f32, 400_f32, 600_f32, 800_f32, 1000_f32],
+            options.tick_values
+        );
+        assert_eq!(NumberFormat::Si(1), options.number_format);
+        assert!(!options.is_default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_axis_options_err() {
+        let mut scale = chart_scale_linear();
+        scale.kind = ChartScaleKind::Band as i32;
+        let options = ChartAxisOptions {
+            tick_count: Some(5),
+            ..ChartAxisOptions::default()
+        };
+
+        get_axis_options(Some(options), Some(&scale)).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_axis_options_tick_count_err() {
+        let options = ChartAxisOptions {
+            tick_count: Some(2_000_000_000),
+            ..ChartAxisOptions::default()
+        };
+
+        get_axis_options(Some(options), Some(&chart_scale_linear())).unwrap();
+    }
+
+    #[test]
+    fn number_format_basic() {
+        assert_eq!("1234.5", NumberFormat::Plain.format(1234.5_f32));
+        assert_eq!("1234.50", NumberFormat::Fixed(2).format(1234.5_f32));
+        assert_eq!("1.2k", NumberFormat::Si(1).format(1234.5_f32));
+        assert_eq!("3M", NumberFormat::Si(0).format(3_000_000_f32));
+        assert_eq!("250µ", NumberFormat::Si(0).format(0.00025_f32));
+        assert_eq!("0", NumberFormat::Si(0).format(0_f32));
+        assert_eq!("25%", NumberFormat::Percent(0).format(0.25_f32));
+        assert_eq!(
+            "-$5.00",
+            NumberFormat::Currency(2, "$".to_string()).format(-5_f32)
+        );
+    }
+}
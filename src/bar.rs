@@ -1,5 +1,6 @@
+use crate::draw::view::BarLayout;
 use crate::error::RendererError;
-use crate::proto::render::chart_view::ChartViewBarLabelPosition;
+use crate::proto::render::chart_view::{ChartViewBarLabelPosition, ChartViewBarLayout};
 use crate::proto::render::ChartView;
 use lc_render::BarLabelPosition;
 
@@ -15,6 +16,18 @@ pub(crate) fn get_bar_label_position(view: &ChartView) -> Result<BarLabelPositio
     }
 }
 
+// Get bar layout from protobuf, bars are stacked by default.
+pub(crate) fn get_bar_layout(view: &ChartView) -> Result<BarLayout, RendererError> {
+    match ChartViewBarLayout::from_i32(view.bar_layout) {
+        Some(ChartViewBarLayout::UnspecifiedBarLayout) | Some(ChartViewBarLayout::Stacked) => {
+            Ok(BarLayout::Stacked)
+        }
+        Some(ChartViewBarLayout::Grouped) => Ok(BarLayout::Grouped),
+        Some(ChartViewBarLayout::PercentStacked) => Ok(BarLayout::PercentStacked),
+        None => Err(RendererError::BarLayoutIsUnknown),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,6 +43,16 @@ mod tests {
             point_label_visible: Some(false),
             point_label_position: 0,
             values: None,
+            inner_radius: None,
+            start_angle: None,
+            slice_label_visible: None,
+            bar_layout: 0,
+            stack_group: String::new(),
+            stack_normalized: false,
+            name: String::new(),
+            horizontal_axis: 0,
+            vertical_axis: 0,
+            histogram_binning: None,
         }
     }
 
@@ -95,4 +118,25 @@ mod tests {
 
         get_bar_label_position(&view).unwrap();
     }
+
+    #[test]
+    fn get_bar_layout_basic() {
+        let mut view = chart_view_empty();
+        assert_eq!(BarLayout::Stacked, get_bar_layout(&view).unwrap());
+
+        view.bar_layout = ChartViewBarLayout::Grouped as i32;
+        assert_eq!(BarLayout::Grouped, get_bar_layout(&view).unwrap());
+
+        view.bar_layout = ChartViewBarLayout::PercentStacked as i32;
+        assert_eq!(BarLayout::PercentStacked, get_bar_layout(&view).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_bar_layout_unknown() {
+        let mut view = chart_view_empty();
+        view.bar_layout = 10;
+
+        get_bar_layout(&view).unwrap();
+    }
 }
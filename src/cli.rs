@@ -0,0 +1,282 @@
+use crate::defaults::Defaults;
+use crate::error::RendererError;
+use crate::proto::render::{ChartOutputFormat, RenderChartRequest};
+use crate::renderer::render;
+use crate::ENV_LC_RENDERER_DEFAULTS_FILE;
+use std::path::{Path, PathBuf};
+
+pub(crate) const CLI_RENDER_COMMAND: &str = "render";
+
+const CLI_USAGE: &str = "usage: lc-renderer render --input <chart.json|chart.yaml> --output <chart.svg|chart.png|chart.pdf> [--defaults <defaults.json|defaults.yaml>]";
+
+const EXIT_CODE_OK: i32 = 0;
+const EXIT_CODE_ERR: i32 = 1;
+const EXIT_CODE_USAGE: i32 = 2;
+
+/// Arguments of the render command.
+#[derive(Debug, PartialEq)]
+pub(crate) struct RenderArgs {
+    input: PathBuf,
+    output: PathBuf,
+    defaults: Option<PathBuf>,
+}
+
+// Format of a file with RenderChartRequest.
+#[derive(Debug, PartialEq)]
+enum InputFormat {
+    Json,
+    Yaml,
+}
+
+// Run render command with its arguments and return process exit code.
+pub(crate) fn run_render(args: &[String]) -> i32 {
+    let render_args = match get_render_args(args) {
+        Ok(render_args) => render_args,
+        Err(err) => {
+            eprintln!("{}\n{}", err, CLI_USAGE);
+            return EXIT_CODE_USAGE;
+        }
+    };
+
+    let defaults = match get_defaults(&render_args) {
+        Ok(defaults) => defaults,
+        Err(err) => {
+            eprintln!("unable to use defaults file: {}", err);
+            return EXIT_CODE_ERR;
+        }
+    };
+
+    match render_file(&render_args, &defaults) {
+        Ok(defaulted_fields) => {
+            if !defaulted_fields.is_empty() {
+                println!("defaulted fields: {}", defaulted_fields.join(", "));
+            }
+            EXIT_CODE_OK
+        }
+        Err(err) => {
+            eprintln!("unable to render {}: {}", render_args.input.display(), err);
+            EXIT_CODE_ERR
+        }
+    }
+}
+
+// Get render command arguments.
+fn get_render_args(args: &[String]) -> Result<RenderArgs, String> {
+    let mut input = None;
+    let mut output = None;
+    let mut defaults = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let target = match name {
+            "--input" | "-i" => &mut input,
+            "--output" | "-o" => &mut output,
+            "--defaults" | "-d" => &mut defaults,
+            _ => return Err(format!("unknown argument {}", arg)),
+        };
+        let value = match value.or_else(|| args.next().cloned()) {
+            Some(value) => value,
+            None => return Err(format!("value for {} is not specified", name)),
+        };
+        *target = Some(PathBuf::from(value));
+    }
+
+    match (input, output) {
+        (Some(input), Some(output)) => Ok(RenderArgs {
+            input,
+            output,
+            defaults,
+        }),
+        (None, _) => Err("--input is not specified".to_string()),
+        (_, None) => Err("--output is not specified".to_string()),
+    }
+}
+
+// Get defaults from the file of arguments or from the file that is set in env.
+// Built-in defaults are used if defaults file isn't set.
+fn get_defaults(render_args: &RenderArgs) -> Result<Defaults, RendererError> {
+    let path = render_args
+        .defaults
+        .clone()
+        .or_else(|| std::env::var_os(ENV_LC_RENDERER_DEFAULTS_FILE).map(PathBuf::from));
+
+    match path {
+        Some(path) => Defaults::from_file(&path),
+        None => Ok(Defaults::default()),
+    }
+}
+
+// Render chart from input file with defaults of omitted fields and write it into output file.
+// Fields that are set from defaults are returned.
+fn render_file(
+    render_args: &RenderArgs,
+    defaults: &Defaults,
+) -> Result<Vec<String>, RendererError> {
+    let data = std::fs::read(&render_args.input)?;
+    let mut r_req: RenderChartRequest = match get_input_format(&render_args.input) {
+        InputFormat::Json => serde_json::from_slice(&data)?,
+        InputFormat::Yaml => serde_yaml::from_slice(&data)?,
+    };
+
+    // Use output file extension if output format isn't set explicitly.
+    if r_req.output_format == ChartOutputFormat::UnspecifiedOutputFormat as i32 {
+        r_req.output_format = get_output_format_by_extension(&render_args.output) as i32;
+    }
+    let defaulted_fields = defaults.apply(&mut r_req);
+
+    let output = render(&r_req)?;
+    std::fs::write(&render_args.output, output.data)?;
+
+    Ok(defaulted_fields)
+}
+
+// Get input format by its file extension, JSON is used by default.
+fn get_input_format(path: &Path) -> InputFormat {
+    match get_extension(path).as_deref() {
+        Some("yaml") | Some("yml") => InputFormat::Yaml,
+        _ => InputFormat::Json,
+    }
+}
+
+// Get output format by its file extension, SVG is used by default.
+fn get_output_format_by_extension(path: &Path) -> ChartOutputFormat {
+    match get_extension(path).as_deref() {
+        Some("png") => ChartOutputFormat::Png,
+        Some("pdf") => ChartOutputFormat::Pdf,
+        _ => ChartOutputFormat::Svg,
+    }
+}
+
+// Get lowercase file extension.
+fn get_extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::{render_chart_request_without, TempDir, RENDER_CHART_REQUEST};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn get_render_args_basic() {
+        let expected = RenderArgs {
+            input: PathBuf::from("chart.json"),
+            output: PathBuf::from("chart.svg"),
+            defaults: None,
+        };
+
+        assert_eq!(
+            Ok(&expected),
+            get_render_args(&args(&["--input", "chart.json", "--output", "chart.svg"])).as_ref()
+        );
+        assert_eq!(
+            Ok(&expected),
+            get_render_args(&args(&["-o=chart.svg", "-i=chart.json"])).as_ref()
+        );
+        assert_eq!(
+            Some(PathBuf::from("defaults.yaml")),
+            get_render_args(&args(&[
+                "-i",
+                "chart.json",
+                "-o",
+                "chart.svg",
+                "-d",
+                "defaults.yaml"
+            ]))
+            .unwrap()
+            .defaults
+        );
+    }
+
+    #[test]
+    fn get_render_args_err() {
+        assert!(get_render_args(&args(&[])).is_err());
+        assert!(get_render_args(&args(&["--input", "chart.json"])).is_err());
+        assert!(get_render_args(&args(&["--input", "chart.json", "--output"])).is_err());
+        assert!(get_render_args(&args(&["--format", "png"])).is_err());
+        assert!(get_render_args(&args(&["-i", "chart.json", "-o", "chart.svg", "-d"])).is_err());
+    }
+
+    #[test]
+    fn get_output_format_by_extension_basic() {
+        assert_eq!(
+            ChartOutputFormat::Svg,
+            get_output_format_by_extension(Path::new("chart"))
+        );
+        assert_eq!(
+            ChartOutputFormat::Png,
+            get_output_format_by_extension(Path::new("chart.PNG"))
+        );
+        assert_eq!(
+            ChartOutputFormat::Pdf,
+            get_output_format_by_extension(Path::new("chart.pdf"))
+        );
+    }
+
+    #[test]
+    fn render_file_basic() {
+        let dir = TempDir::new();
+        let render_args = RenderArgs {
+            input: dir.join("chart.yaml"),
+            output: dir.join("chart.png"),
+            defaults: None,
+        };
+        // JSON request is a valid YAML document as well.
+        std::fs::write(&render_args.input, RENDER_CHART_REQUEST).unwrap();
+
+        render_file(&render_args, &Defaults::default()).unwrap();
+
+        let output = std::fs::read(&render_args.output).unwrap();
+        assert_eq!(b"\x89PNG", &output[..4]);
+    }
+
+    #[test]
+    fn render_file_defaults() {
+        let dir = TempDir::new();
+        let render_args = RenderArgs {
+            input: dir.join("chart.json"),
+            output: dir.join("chart.svg"),
+            defaults: Some(dir.join("defaults.yaml")),
+        };
+        std::fs::write(
+            &render_args.input,
+            render_chart_request_without(&["/sizes/width"]),
+        )
+        .unwrap();
+        std::fs::write(render_args.defaults.as_ref().unwrap(), "width: 1024\n").unwrap();
+
+        let defaults = get_defaults(&render_args).unwrap();
+        let defaulted_fields = render_file(&render_args, &defaults).unwrap();
+
+        let output = std::fs::read_to_string(&render_args.output).unwrap();
+        assert_eq!(vec!["sizes.width"], defaulted_fields);
+        assert!(output.contains(r#"width="1024""#));
+    }
+
+    #[test]
+    fn render_file_err() {
+        let dir = TempDir::new();
+        let render_args = RenderArgs {
+            input: dir.join("chart.json"),
+            output: dir.join("chart.svg"),
+            defaults: None,
+        };
+        std::fs::write(
+            &render_args.input,
+            r#"{"views": [{"kind": "VERTICAL_BAR"}]}"#,
+        )
+        .unwrap();
+
+        assert!(render_file(&render_args, &Defaults::default()).is_err());
+        assert!(!render_args.output.exists());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::error::RendererError;
+use crate::proto::render::chart_scale::ChartScaleKind;
+use crate::proto::render::chart_view::{
+    ChartViewBarLabelPosition, ChartViewKind, ChartViewPointLabelPosition, ChartViewPointType,
+};
+use crate::proto::render::{ChartMargins, ChartScale, ChartSizes, RenderChartRequest};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Defaults contains values of optional request fields that are used if they are omitted.
+/// They can be overridden with a JSON or YAML config file.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct Defaults {
+    width: i32,
+    height: i32,
+    margin_top: i32,
+    margin_bottom: i32,
+    margin_left: i32,
+    margin_right: i32,
+    inner_padding: f32,
+    outer_padding: f32,
+    bar_label_visible: bool,
+    #[serde(deserialize_with = "crate::json::enumeration::<ChartViewBarLabelPosition, _>")]
+    bar_label_position: i32,
+    point_visible: bool,
+    #[serde(deserialize_with = "crate::json::enumeration::<ChartViewPointType, _>")]
+    point_type: i32,
+    point_label_visible: bool,
+    #[serde(deserialize_with = "crate::json::enumeration::<ChartViewPointLabelPosition, _>")]
+    point_label_position: i32,
+    slice_label_visible: bool,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Defaults {
+            width: 800,
+            height: 600,
+            margin_top: 90,
+            margin_bottom: 50,
+            margin_left: 60,
+            margin_right: 40,
+            inner_padding: 0.1_f32,
+            outer_padding: 0.1_f32,
+            bar_label_visible: true,
+            bar_label_position: ChartViewBarLabelPosition::Center as i32,
+            point_visible: true,
+            point_type: ChartViewPointType::Circle as i32,
+            point_label_visible: false,
+            point_label_position: ChartViewPointLabelPosition::Top as i32,
+            slice_label_visible: true,
+        }
+    }
+}
+
+// Set a default value for an omitted field and remember its path.
+fn set_default<T: Clone>(field: &mut Option<T>, value: &T, path: String, paths: &mut Vec<String>) {
+    if field.is_none() {
+        *field = Some(value.clone());
+        paths.push(path);
+    }
+}
+
+// Set a default value for an unspecified enum field and remember its path.
+fn set_default_enum(field: &mut i32, value: i32, path: String, paths: &mut Vec<String>) {
+    if *field == 0 {
+        *field = value;
+        paths.push(path);
+    }
+}
+
+impl Defaults {
+    // Read defaults from a JSON or YAML config file, fields that are not set keep built-in values.
+    pub(crate) fn from_file(path: &Path) -> Result<Defaults, RendererError> {
+        let data = std::fs::read(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yaml") | Some("yml") => Ok(serde_yaml::from_slice(&data)?),
+            _ => Ok(serde_json::from_slice(&data)?),
+        }
+    }
+
+    // Set paddings of a band scale.
+    fn set_scale(&self, scale: &mut Option<ChartScale>, path: &str, paths: &mut Vec<String>) {
+        let scale = match scale {
+            Some(scale) if scale.kind == ChartScaleKind::Band as i32 => scale,
+            _ => return,
+        };
+        set_default(
+            &mut scale.inner_padding,
+            &self.inner_padding,
+            format!("{}.inner_padding", path),
+            paths,
+        );
+        set_default(
+            &mut scale.outer_padding,
+            &self.outer_padding,
+            format!("{}.outer_padding", path),
+            paths,
+        );
+    }
+
+    // Set defaults for omitted fields of the request and get paths of the defaulted fields.
+    // Only fields that are used by the kind of every view are set.
+    pub(crate) fn apply(&self, r_req: &mut RenderChartRequest) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        if r_req.sizes.is_none() {
+            r_req.sizes = Some(ChartSizes::default());
+        }
+        if let Some(sizes) = &mut r_req.sizes {
+            set_default(
+                &mut sizes.width,
+                &self.width,
+                "sizes.width".to_string(),
+                &mut paths,
+            );
+            set_default(
+                &mut sizes.height,
+                &self.height,
+                "sizes.height".to_string(),
+                &mut paths,
+            );
+        }
+
+        if r_req.margins.is_none() {
+            r_req.margins = Some(ChartMargins::default());
+        }
+        if let Some(margins) = &mut r_req.margins {
+            set_default(
+                &mut margins.margin_top,
+                &self.margin_top,
+                "margins.margin_top".to_string(),
+                &mut paths,
+            );
+            set_default(
+                &mut margins.margin_bottom,
+                &self.margin_bottom,
+                "margins.margin_bottom".to_string(),
+                &mut paths,
+            );
+            set_default(
+                &mut margins.margin_left,
+                &self.margin_left,
+                "margins.margin_left".to_string(),
+                &mut paths,
+            );
+            set_default(
+                &mut margins.margin_right,
+                &self.margin_right,
+                "margins.margin_right".to_string(),
+                &mut paths,
+            );
+        }
+
+        if let Some(axes) = &mut r_req.axes {
+            self.set_scale(&mut axes.axis_top, "axes.axis_top", &mut paths);
+            self.set_scale(&mut axes.axis_bottom, "axes.axis_bottom", &mut paths);
+            self.set_scale(&mut axes.axis_left, "axes.axis_left", &mut paths);
+            self.set_scale(&mut axes.axis_right, "axes.axis_right", &mut paths);
+        }
+
+        for (idx, view) in r_req.views.iter_mut().enumerate() {
+            let path = format!("views[{}]", idx);
+            match ChartViewKind::from_i32(view.kind) {
+                Some(ChartViewKind::Area)
+                | Some(ChartViewKind::Line)
+                | Some(ChartViewKind::Scatter) => {
+                    set_default(
+                        &mut view.point_visible,
+                        &self.point_visible,
+                        format!("{}.point_visible", path),
+                        &mut paths,
+                    );
+                    set_default_enum(
+                        &mut view.point_type,
+                        self.point_type,
+                        format!("{}.point_type", path),
+                        &mut paths,
+                    );
+                    set_default(
+                        &mut view.point_label_visible,
+                        &self.point_label_visible,
+                        format!("{}.point_label_visible", path),
+                        &mut paths,
+                    );
+                    set_default_enum(
+                        &mut view.point_label_position,
+                        self.point_label_position,
+                        format!("{}.point_label_position", path),
+                        &mut paths,
+                    );
+                }
+                Some(ChartViewKind::HorizontalBar)
+                | Some(ChartViewKind::VerticalBar)
+                | Some(ChartViewKind::Histogram) => {
+                    set_default(
+                        &mut view.bar_label_visible,
+                        &self.bar_label_visible,
+                        format!("{}.bar_label_visible", path),
+                        &mut paths,
+                    );
+                    set_default_enum(
+                        &mut view.bar_label_position,
+                        self.bar_label_position,
+                        format!("{}.bar_label_position", path),
+                        &mut paths,
+                    );
+                }
+                Some(ChartViewKind::Pie) | Some(ChartViewKind::Donut) => {
+                    set_default(
+                        &mut view.slice_label_visible,
+                        &self.slice_label_visible,
+                        format!("{}.slice_label_visible", path),
+                        &mut paths,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::{ChartAxes, ChartView};
+
+    #[test]
+    fn apply_basic() {
+        let mut r_req = RenderChartRequest {
+            sizes: Some(ChartSizes {
+                width: Some(400),
+                height: None,
+            }),
+            axes: Some(ChartAxes {
+                axis_bottom: Some(ChartScale {
+                    kind: ChartScaleKind::Band as i32,
+                    inner_padding: Some(0.2_f32),
+                    ..ChartScale::default()
+                }),
+                axis_left: Some(ChartScale {
+                    kind: ChartScaleKind::Linear as i32,
+                    ..ChartScale::default()
+                }),
+                ..ChartAxes::default()
+            }),
+            views: vec![ChartView {
+                kind: ChartViewKind::VerticalBar as i32,
+                bar_label_visible: Some(false),
+                ..ChartView::default()
+            }],
+            ..RenderChartRequest::default()
+        };
+
+        let paths = Defaults::default().apply(&mut r_req);
+
+        assert_eq!(
+            vec![
+                "sizes.height",
+                "margins.margin_top",
+                "margins.margin_bottom",
+                "margins.margin_left",
+                "margins.margin_right",
+                "axes.axis_bottom.outer_padding",
+                "views[0].bar_label_position",
+            ],
+            paths
+        );
+        let sizes = r_req.sizes.unwrap();
+        assert_eq!((Some(400), Some(600)), (sizes.width, sizes.height));
+        let axes = r_req.axes.unwrap();
+        let axis_bottom = axes.axis_bottom.unwrap();
+        assert_eq!(Some(0.2_f32), axis_bottom.inner_padding);
+        assert_eq!(Some(0.1_f32), axis_bottom.outer_padding);
+        assert_eq!(None, axes.axis_left.unwrap().inner_padding);
+        assert_eq!(Some(false), r_req.views[0].bar_label_visible);
+        assert_eq!(
+            ChartViewBarLabelPosition::Center as i32,
+            r_req.views[0].bar_label_position
+        );
+        assert_eq!(None, r_req.views[0].point_visible);
+    }
+
+    #[test]
+    fn from_file_basic() {
+        let path = std::env::temp_dir().join(format!("{}.yaml", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "width: 1024\npointLabelPosition: BOTTOM\n").unwrap();
+
+        let defaults = Defaults::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            Defaults {
+                width: 1024,
+                point_label_position: ChartViewPointLabelPosition::Bottom as i32,
+                ..Defaults::default()
+            },
+            defaults.unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_file_err() {
+        let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, r#"{"unknownField": 1}"#).unwrap();
+
+        let defaults = Defaults::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        defaults.unwrap();
+    }
+}
@@ -0,0 +1,600 @@
+use crate::draw::axis::AxisPosition;
+use crate::draw::scale::{get_nice_step, DEFAULT_LOG_BASE};
+use crate::error::RendererError;
+use crate::histogram::get_histogram_bins;
+use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+use crate::proto::render::chart_view::{ChartViewBarLayout, ChartViewKind, Values};
+use crate::proto::render::{
+    ChartAxes, ChartScale, ChartView, ChartViewBarsValues, DomainAuto, DomainCategories,
+    DomainNumeric, DomainTime,
+};
+use crate::scale::{get_h_axis, get_time, get_v_axis};
+use prost_types::Timestamp;
+use std::collections::HashMap;
+
+// Approximate amount of ticks that is used to round numeric domains.
+const NICE_TICK_COUNT: f32 = 10_f32;
+
+// Values of views along one direction that are used to compute auto domains.
+#[derive(Default)]
+struct DomainValues {
+    numbers: Vec<f32>,
+    times: Vec<Timestamp>,
+    categories: Vec<String>,
+}
+
+impl DomainValues {
+    // Add categories that are not added yet keeping their order.
+    fn add_categories(&mut self, categories: &[String]) {
+        for category in categories.iter() {
+            if !self.categories.contains(category) {
+                self.categories.push(category.clone());
+            }
+        }
+    }
+}
+
+// Get bounds of bars values with their layout.
+// Stacked bars are summed by categories separately for positive and negative values,
+// lower and upper bounds of values are included only for grouped bars.
+fn get_bars_bounds(bar_layout: i32, bars_values: &ChartViewBarsValues) -> Vec<f32> {
+    let values = bars_values
+        .bars_datasets
+        .iter()
+        .map(|dataset| dataset.values.as_slice());
+    match ChartViewBarLayout::from_i32(bar_layout) {
+        Some(ChartViewBarLayout::Grouped) => bars_values
+            .bars_datasets
+            .iter()
+            .flat_map(|dataset| {
+                dataset
+                    .values
+                    .iter()
+                    .chain(dataset.lower_bounds.iter())
+                    .chain(dataset.upper_bounds.iter())
+            })
+            .copied()
+            .collect(),
+        Some(ChartViewBarLayout::PercentStacked) => vec![0_f32, 100_f32],
+        _ => {
+            let mut positive: Vec<f32> = Vec::new();
+            let mut negative: Vec<f32> = Vec::new();
+            for dataset_values in values {
+                positive.resize(positive.len().max(dataset_values.len()), 0_f32);
+                negative.resize(negative.len().max(dataset_values.len()), 0_f32);
+                for (idx, value) in dataset_values.iter().enumerate() {
+                    if *value < 0_f32 {
+                        negative[idx] += value;
+                    } else {
+                        positive[idx] += value;
+                    }
+                }
+            }
+            positive.into_iter().chain(negative).collect()
+        }
+    }
+}
+
+// Get values of views along the horizontal or vertical direction.
+// Values of unexpected kinds are skipped, they're reported when views are prepared.
+fn get_domain_values(views: &[&ChartView], horizontal: bool) -> DomainValues {
+    let mut res = DomainValues::default();
+    let mut stack_totals: HashMap<&str, Vec<f32>> = HashMap::new();
+
+    for view in views.iter() {
+        let kind = ChartViewKind::from_i32(view.kind);
+        match (&view.values, kind) {
+            (
+                Some(Values::ScalarValues(scalar_values)),
+                Some(ChartViewKind::Area) | Some(ChartViewKind::Line),
+            ) => {
+                if horizontal {
+                    res.add_categories(&scalar_values.categories);
+                    continue;
+                }
+                res.numbers.extend(scalar_values.values.iter());
+                if view.stack_group.is_empty() {
+                    res.numbers.extend(scalar_values.lower_bounds.iter());
+                    res.numbers.extend(scalar_values.upper_bounds.iter());
+                }
+                if kind != Some(ChartViewKind::Area) || view.stack_group.is_empty() {
+                    continue;
+                }
+                // Stacked areas are bounded by totals of their groups.
+                if view.stack_normalized {
+                    res.numbers.extend(&[0_f32, 100_f32]);
+                    continue;
+                }
+                let totals = stack_totals.entry(&view.stack_group).or_default();
+                totals.resize(totals.len().max(scalar_values.values.len()), 0_f32);
+                for (total, value) in totals.iter_mut().zip(scalar_values.values.iter()) {
+                    *total += value;
+                }
+            }
+            (
+                Some(Values::BarsValues(bars_values)),
+                Some(ChartViewKind::HorizontalBar) | Some(ChartViewKind::VerticalBar),
+            ) => {
+                // Categories of vertical bars are placed along the horizontal direction.
+                if (kind == Some(ChartViewKind::VerticalBar)) == horizontal {
+                    res.add_categories(&bars_values.categories);
+                } else {
+                    res.numbers
+                        .extend(get_bars_bounds(view.bar_layout, bars_values));
+                }
+            }
+            (Some(Values::ScalarValues(_)), Some(ChartViewKind::Histogram)) => {
+                // Histograms are bounded by edges of their bins and by heights of non-empty ones.
+                let bins = get_histogram_bins(view).unwrap_or_default();
+                if horizontal {
+                    res.numbers.extend(bins.first().map(|bin| bin.start).iter());
+                    res.numbers.extend(bins.last().map(|bin| bin.end).iter());
+                } else {
+                    res.numbers.extend(
+                        bins.iter()
+                            .map(|bin| bin.value)
+                            .filter(|value| *value > 0_f32),
+                    );
+                }
+            }
+            (Some(Values::PointsValues(points_values)), _) => {
+                if !horizontal {
+                    res.numbers.extend(points_values.lower_bounds.iter());
+                    res.numbers.extend(points_values.upper_bounds.iter());
+                }
+                for point in points_values.points.iter() {
+                    if !horizontal {
+                        res.numbers.push(point.y);
+                        continue;
+                    }
+                    res.numbers.push(point.x);
+                    if let Some(time) = &point.time {
+                        res.times.push(time.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for totals in stack_totals.values() {
+        res.numbers.extend(totals.iter());
+    }
+
+    res
+}
+
+// Get minimum and maximum of finite numbers.
+fn get_bounds(numbers: &[f32]) -> Result<(f32, f32), RendererError> {
+    let mut numbers = numbers.iter().filter(|number| number.is_finite());
+    let first = match numbers.next() {
+        Some(first) => *first,
+        None => return Err(RendererError::AutoDomainValuesAreNotSpecified),
+    };
+
+    Ok(numbers.fold((first, first), |(min, max), number| {
+        (min.min(*number), max.max(*number))
+    }))
+}
+
+// Get linear scale domain that contains all numbers.
+// Domain of a single number is extended by one in both directions.
+fn get_linear_domain(numbers: &[f32], auto: &DomainAuto) -> Result<DomainNumeric, RendererError> {
+    let (mut start, mut end) = get_bounds(numbers)?;
+    if auto.include_zero {
+        start = start.min(0_f32);
+        end = end.max(0_f32);
+    }
+    if start == end {
+        start -= 1_f32;
+        end += 1_f32;
+    }
+    if auto.nice {
+        let step = get_nice_step(end - start, NICE_TICK_COUNT);
+        start = (start / step).floor() * step;
+        end = (end / step).ceil() * step;
+    }
+
+    Ok(DomainNumeric { start, end })
+}
+
+// Get log scale domain that contains all numbers, zero can't be included into it.
+// Domain of a single number is extended by the base in both directions.
+fn get_log_domain(
+    numbers: &[f32],
+    auto: &DomainAuto,
+    base: f32,
+) -> Result<DomainNumeric, RendererError> {
+    let (mut start, mut end) = get_bounds(numbers)?;
+    if start == end {
+        start /= base;
+        end *= base;
+    }
+    // Domains with non-positive bounds are reported by the log scale.
+    if auto.nice && start > 0_f32 {
+        start = base.powf(start.log(base).floor());
+        end = base.powf(end.log(base).ceil());
+    }
+
+    Ok(DomainNumeric { start, end })
+}
+
+// Get time scale domain from the earliest to the latest time.
+// Domain of a single time is extended by one second in both directions.
+fn get_time_domain(times: &[Timestamp]) -> Result<DomainTime, RendererError> {
+    let start = times
+        .iter()
+        .min_by(|a, b| get_time(a).total_cmp(&get_time(b)));
+    let end = times
+        .iter()
+        .max_by(|a, b| get_time(a).total_cmp(&get_time(b)));
+    let (mut start, mut end) = match (start, end) {
+        (Some(start), Some(end)) => (start.clone(), end.clone()),
+        _ => return Err(RendererError::AutoDomainValuesAreNotSpecified),
+    };
+    if start == end {
+        start.seconds -= 1;
+        end.seconds += 1;
+    }
+
+    Ok(DomainTime {
+        start: Some(start),
+        end: Some(end),
+    })
+}
+
+// Get scale of the axis with auto domain replaced by domain computed from values of views
+// that are bound to the axis.
+pub(crate) fn get_resolved_scale(
+    axes: &ChartAxes,
+    position: AxisPosition,
+    views: &[ChartView],
+) -> Result<Option<ChartScale>, RendererError> {
+    let scale = match position {
+        AxisPosition::Top => &axes.axis_top,
+        AxisPosition::Bottom => &axes.axis_bottom,
+        AxisPosition::Left => &axes.axis_left,
+        AxisPosition::Right => &axes.axis_right,
+    };
+    let mut scale = match scale {
+        Some(scale) => scale.clone(),
+        None => return Ok(None),
+    };
+    let auto = match &scale.domain {
+        Some(Domain::DomainAuto(auto)) => auto.clone(),
+        _ => return Ok(Some(scale)),
+    };
+
+    // Views with invalid bindings are skipped, they're reported when views are prepared.
+    let horizontal = matches!(position, AxisPosition::Top | AxisPosition::Bottom);
+    let views: Vec<&ChartView> = views
+        .iter()
+        .filter(|view| {
+            let view_axis = if horizontal {
+                get_h_axis(axes, view.horizontal_axis)
+            } else {
+                get_v_axis(axes, view.vertical_axis)
+            };
+            matches!(view_axis, Ok((view_position, _)) if view_position == position)
+        })
+        .collect();
+    let values = get_domain_values(&views, horizontal);
+
+    let domain = match ChartScaleKind::from_i32(scale.kind) {
+        Some(ChartScaleKind::Band) if values.categories.is_empty() => {
+            return Err(RendererError::AutoDomainValuesAreNotSpecified)
+        }
+        Some(ChartScaleKind::Band) => Domain::DomainCategories(DomainCategories {
+            categories: values.categories,
+        }),
+        Some(ChartScaleKind::Linear) => {
+            Domain::DomainNumeric(get_linear_domain(&values.numbers, &auto)?)
+        }
+        Some(ChartScaleKind::Log) => {
+            let base = scale.log_base.unwrap_or(DEFAULT_LOG_BASE);
+            Domain::DomainNumeric(get_log_domain(&values.numbers, &auto, base)?)
+        }
+        Some(ChartScaleKind::Time) => Domain::DomainTime(get_time_domain(&values.times)?),
+        // Unknown scale kinds are reported by axes.
+        _ => return Ok(Some(scale)),
+    };
+    scale.domain = Some(domain);
+
+    Ok(Some(scale))
+}
+
+// Check if any scale of axes has a domain that is computed from values of views.
+pub(crate) fn has_auto_domains(axes: Option<&ChartAxes>) -> bool {
+    let axes = match axes {
+        Some(axes) => axes,
+        None => return false,
+    };
+
+    [
+        &axes.axis_top,
+        &axes.axis_bottom,
+        &axes.axis_left,
+        &axes.axis_right,
+    ]
+    .iter()
+    .any(|scale| {
+        matches!(
+            scale,
+            Some(ChartScale {
+                domain: Some(Domain::DomainAuto(_)),
+                ..
+            })
+        )
+    })
+}
+
+// Get axes with auto domains computed from values of views.
+pub(crate) fn get_resolved_axes(
+    axes: Option<&ChartAxes>,
+    views: &[ChartView],
+) -> Result<Option<ChartAxes>, RendererError> {
+    let axes = match axes {
+        Some(axes) => axes,
+        None => return Ok(None),
+    };
+
+    Ok(Some(ChartAxes {
+        axis_top: get_resolved_scale(axes, AxisPosition::Top, views)?,
+        axis_bottom: get_resolved_scale(axes, AxisPosition::Bottom, views)?,
+        axis_left: get_resolved_scale(axes, AxisPosition::Left, views)?,
+        axis_right: get_resolved_scale(axes, AxisPosition::Right, views)?,
+        ..axes.clone()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::chart_view::ChartViewVerticalAxis;
+    use crate::proto::render::chart_view_bars_values::BarsDataset;
+    use crate::proto::render::chart_view_points_values::Point;
+    use crate::proto::render::{ChartViewPointsValues, ChartViewScalarValues};
+    use crate::scale::get_time_scale;
+
+    fn chart_scale_auto(kind: ChartScaleKind) -> ChartScale {
+        ChartScale {
+            kind: kind as i32,
+            range_start: Some(0),
+            range_end: Some(100),
+            domain: Some(Domain::DomainAuto(DomainAuto {
+                nice: true,
+                include_zero: true,
+            })),
+            no_boundaries_offset: false,
+            inner_padding: Some(0.1_f32),
+            outer_padding: Some(0.1_f32),
+            log_base: None,
+            time_format: String::new(),
+        }
+    }
+
+    fn chart_view(kind: ChartViewKind, values: Values) -> ChartView {
+        ChartView {
+            kind: kind as i32,
+            colors: None,
+            bar_label_visible: Some(false),
+            bar_label_position: 0,
+            point_visible: Some(false),
+            point_type: 0,
+            point_label_visible: Some(false),
+            point_label_position: 0,
+            values: Some(values),
+            inner_radius: None,
+            start_angle: None,
+            slice_label_visible: Some(false),
+            bar_layout: 0,
+            stack_group: String::new(),
+            stack_normalized: false,
+            name: String::new(),
+            horizontal_axis: 0,
+            vertical_axis: 0,
+            histogram_binning: None,
+        }
+    }
+
+    #[test]
+    fn get_resolved_axes_basic() {
+        let axes = ChartAxes {
+            axis_bottom: Some(chart_scale_auto(ChartScaleKind::Band)),
+            axis_left: Some(chart_scale_auto(ChartScaleKind::Linear)),
+            ..ChartAxes::default()
+        };
+        let views = vec![
+            chart_view(
+                ChartViewKind::Line,
+                Values::ScalarValues(ChartViewScalarValues {
+                    values: vec![13_f32, 87_f32],
+                    categories: vec!["a".to_string(), "b".to_string()],
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                }),
+            ),
+            chart_view(
+                ChartViewKind::Area,
+                Values::ScalarValues(ChartViewScalarValues {
+                    values: vec![42_f32, 17_f32, 21_f32],
+                    categories: vec!["b".to_string(), "c".to_string()],
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                }),
+            ),
+        ];
+
+        let axes = get_resolved_axes(Some(&axes), &views).unwrap().unwrap();
+
+        assert_eq!(
+            Some(Domain::DomainCategories(DomainCategories {
+                categories: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            })),
+            axes.axis_bottom.unwrap().domain
+        );
+        assert_eq!(
+            Some(Domain::DomainNumeric(DomainNumeric {
+                start: 0_f32,
+                end: 90_f32,
+            })),
+            axes.axis_left.unwrap().domain
+        );
+    }
+
+    #[test]
+    fn get_resolved_axes_bound_views() {
+        let axes = ChartAxes {
+            axis_bottom: Some(chart_scale_auto(ChartScaleKind::Band)),
+            axis_left: Some(chart_scale_auto(ChartScaleKind::Linear)),
+            axis_right: Some(chart_scale_auto(ChartScaleKind::Linear)),
+            ..ChartAxes::default()
+        };
+        let mut views = vec![
+            chart_view(
+                ChartViewKind::Line,
+                Values::ScalarValues(ChartViewScalarValues {
+                    values: vec![13_f32, 87_f32],
+                    categories: vec!["a".to_string(), "b".to_string()],
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                }),
+            ),
+            chart_view(
+                ChartViewKind::Line,
+                Values::ScalarValues(ChartViewScalarValues {
+                    values: vec![1200_f32, 3400_f32],
+                    categories: vec!["a".to_string(), "b".to_string()],
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                }),
+            ),
+        ];
+        views[1].vertical_axis = ChartViewVerticalAxis::AxisRight as i32;
+
+        let axes = get_resolved_axes(Some(&axes), &views).unwrap().unwrap();
+
+        assert_eq!(
+            Some(Domain::DomainNumeric(DomainNumeric {
+                start: 0_f32,
+                end: 90_f32,
+            })),
+            axes.axis_left.unwrap().domain
+        );
+        assert_eq!(
+            Some(Domain::DomainNumeric(DomainNumeric {
+                start: 0_f32,
+                end: 3500_f32,
+            })),
+            axes.axis_right.unwrap().domain
+        );
+    }
+
+    #[test]
+    fn get_resolved_scale_stacked_bars() {
+        let mut view = chart_view(
+            ChartViewKind::HorizontalBar,
+            Values::BarsValues(ChartViewBarsValues {
+                bars_datasets: vec![
+                    BarsDataset {
+                        values: vec![10_f32, -5_f32],
+                        colors: None,
+                        name: String::new(),
+                        lower_bounds: Vec::new(),
+                        upper_bounds: Vec::new(),
+                    },
+                    BarsDataset {
+                        values: vec![15_f32, -20_f32],
+                        colors: None,
+                        name: String::new(),
+                        lower_bounds: Vec::new(),
+                        upper_bounds: Vec::new(),
+                    },
+                ],
+                categories: Vec::new(),
+            }),
+        );
+        view.bar_layout = ChartViewBarLayout::Stacked as i32;
+        let mut scale = chart_scale_auto(ChartScaleKind::Linear);
+        scale.domain = Some(Domain::DomainAuto(DomainAuto::default()));
+
+        let axes = ChartAxes {
+            axis_bottom: Some(scale),
+            ..ChartAxes::default()
+        };
+
+        let scale = get_resolved_scale(&axes, AxisPosition::Bottom, &[view])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(Domain::DomainNumeric(DomainNumeric {
+                start: -25_f32,
+                end: 25_f32,
+            })),
+            scale.domain
+        );
+    }
+
+    #[test]
+    fn get_resolved_scale_single_time() {
+        let view = chart_view(
+            ChartViewKind::Line,
+            Values::PointsValues(ChartViewPointsValues {
+                points: vec![Point {
+                    x: 0_f32,
+                    y: 10_f32,
+                    time: Some(Timestamp {
+                        seconds: 1_600_000_000,
+                        nanos: 0,
+                    }),
+                }],
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
+            }),
+        );
+        let axes = ChartAxes {
+            axis_bottom: Some(chart_scale_auto(ChartScaleKind::Time)),
+            ..ChartAxes::default()
+        };
+
+        let scale = get_resolved_scale(&axes, AxisPosition::Bottom, &[view])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(Domain::DomainTime(DomainTime {
+                start: Some(Timestamp {
+                    seconds: 1_599_999_999,
+                    nanos: 0,
+                }),
+                end: Some(Timestamp {
+                    seconds: 1_600_000_001,
+                    nanos: 0,
+                }),
+            })),
+            scale.domain
+        );
+        get_time_scale(&scale).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_resolved_scale_err() {
+        let view = chart_view(
+            ChartViewKind::Line,
+            Values::ScalarValues(ChartViewScalarValues {
+                values: vec![13_f32, 87_f32],
+                categories: Vec::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
+            }),
+        );
+
+        let axes = ChartAxes {
+            axis_bottom: Some(chart_scale_auto(ChartScaleKind::Band)),
+            ..ChartAxes::default()
+        };
+
+        get_resolved_scale(&axes, AxisPosition::Bottom, &[view]).unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+use crate::draw::*;
+use lc_render::View;
+use svg::Node;
+
+const DEFAULT_LINE_STROKE_COLOR: &str = DEFAULT_FONT_COLOR;
+const DEFAULT_RANGE_FILL_COLOR: &str = DEFAULT_STROKE_COLOR;
+const DEFAULT_RANGE_FILL_OPACITY: f32 = 0.3_f32;
+const DEFAULT_LABEL_FONT_SIZE: i32 = 12;
+const DEFAULT_LABEL_OFFSET: f32 = 4_f32;
+
+/// Shape of an annotation with scaled coordinates relative to the views area.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum AnnotationShape {
+    HorizontalLine(f32),
+    VerticalLine(f32),
+    HorizontalRange(f32, f32),
+    VerticalRange(f32, f32),
+    Text(f32, f32),
+}
+
+/// View of a reference line, a filled range or a text label.
+/// It's drawn as a chart view so its position is relative to the views area.
+#[derive(Clone)]
+pub(crate) struct AnnotationView {
+    shape: AnnotationShape,
+    text: String,
+    stroke_color: Option<String>,
+    fill_color: Option<String>,
+    view_width: i32,
+    view_height: i32,
+}
+
+impl AnnotationView {
+    pub(crate) fn new(shape: AnnotationShape, view_width: i32, view_height: i32) -> Self {
+        AnnotationView {
+            shape,
+            text: String::new(),
+            stroke_color: None,
+            fill_color: None,
+            view_width,
+            view_height,
+        }
+    }
+
+    pub(crate) fn set_text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: Option<String>) -> Self {
+        self.stroke_color = stroke_color;
+        self
+    }
+
+    pub(crate) fn set_fill_color(mut self, fill_color: Option<String>) -> Self {
+        self.fill_color = fill_color;
+        self
+    }
+
+    fn line_to_svg(&self, x1: f32, y1: f32, x2: f32, y2: f32) -> svg::node::element::Line {
+        let stroke_color = self
+            .stroke_color
+            .as_deref()
+            .unwrap_or(DEFAULT_LINE_STROKE_COLOR);
+
+        svg::node::element::Line::new()
+            .set(X1_ATTR, x1)
+            .set(Y1_ATTR, y1)
+            .set(X2_ATTR, x2)
+            .set(Y2_ATTR, y2)
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+            .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+            .set(STROKE_ATTR, stroke_color)
+    }
+
+    fn range_to_svg(
+        &self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) -> svg::node::element::Rectangle {
+        let fill_color = self
+            .fill_color
+            .as_deref()
+            .unwrap_or(DEFAULT_RANGE_FILL_COLOR);
+        let mut res = svg::node::element::Rectangle::new()
+            .set(X_ATTR, x)
+            .set(Y_ATTR, y)
+            .set(WIDTH_ATTR, width)
+            .set(HEIGHT_ATTR, height)
+            .set(FILL_ATTR, fill_color)
+            .set(FILL_OPACITY_ATTR, DEFAULT_RANGE_FILL_OPACITY)
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES);
+        if let Some(stroke_color) = &self.stroke_color {
+            res = res
+                .set(STROKE_ATTR, stroke_color.as_str())
+                .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH);
+        }
+
+        res
+    }
+
+    fn text_to_svg(&self, x: f32, y: f32, text_anchor: &str) -> svg::node::element::Text {
+        // Text labels use the fill color, labels of lines use their stroke color.
+        let color = match self.shape {
+            AnnotationShape::Text(_, _) => self.fill_color.as_deref(),
+            AnnotationShape::HorizontalLine(_) | AnnotationShape::VerticalLine(_) => {
+                self.stroke_color.as_deref()
+            }
+            _ => None,
+        };
+
+        let text = svg::node::element::Text::new()
+            .set(X_ATTR, x)
+            .set(Y_ATTR, y)
+            .set(DY_ATTR, DEFAULT_DY)
+            .set(TEXT_ANCHOR_ATTR, text_anchor)
+            .set(FONT_SIZE_ATTR, format!("{}px", DEFAULT_LABEL_FONT_SIZE))
+            .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+            .set(FILL_ATTR, color.unwrap_or(DEFAULT_FONT_COLOR))
+            .add(svg::node::Text::new(self.text.as_str()));
+
+        // Labels without explicit color are marked to be restyled by themes.
+        match color {
+            Some(_) => text,
+            None => text.set(CLASS_ATTR, CLASS_DEFAULT_COLOR),
+        }
+    }
+}
+
+impl View for AnnotationView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let width = self.view_width as f32;
+        let height = self.view_height as f32;
+        let font_size = DEFAULT_LABEL_FONT_SIZE as f32;
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_ANNOTATION);
+
+        // Labels of lines and ranges are placed at their top right or top left corners.
+        let (label_x, label_y, text_anchor) = match self.shape {
+            AnnotationShape::HorizontalLine(y) => {
+                res.append(self.line_to_svg(START, y, width, y));
+                (
+                    width - DEFAULT_LABEL_OFFSET,
+                    y - font_size / 2_f32 - DEFAULT_LABEL_OFFSET,
+                    TEXT_ANCHOR_END,
+                )
+            }
+            AnnotationShape::VerticalLine(x) => {
+                res.append(self.line_to_svg(x, START, x, height));
+                (x + DEFAULT_LABEL_OFFSET, font_size, TEXT_ANCHOR_START)
+            }
+            AnnotationShape::HorizontalRange(y1, y2) => {
+                let y = y1.min(y2);
+                res.append(self.range_to_svg(START, y, width, (y2 - y1).abs()));
+                (DEFAULT_LABEL_OFFSET, y + font_size, TEXT_ANCHOR_START)
+            }
+            AnnotationShape::VerticalRange(x1, x2) => {
+                let x = x1.min(x2);
+                res.append(self.range_to_svg(x, START, (x2 - x1).abs(), height));
+                (x + DEFAULT_LABEL_OFFSET, font_size, TEXT_ANCHOR_START)
+            }
+            AnnotationShape::Text(x, y) => (x, y, TEXT_ANCHOR_MIDDLE),
+        };
+        if !self.text.is_empty() {
+            res.append(self.text_to_svg(label_x, label_y, text_anchor));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annotation_view_line() {
+        let expected_svg_group = r##"<g class="annotation">
+<line shape-rendering="crispEdges" stroke="#ff0000" stroke-width="1" x1="0" x2="400" y1="100" y2="100"/>
+<text dy=".35em" fill="#ff0000" font-family="sans-serif" font-size="12px" text-anchor="end" x="396" y="90">
+SLO
+</text>
+</g>"##;
+
+        let annotation_view =
+            AnnotationView::new(AnnotationShape::HorizontalLine(100_f32), 400, 300)
+                .set_text("SLO")
+                .set_stroke_color(Some("#ff0000".to_string()));
+
+        assert_eq!(expected_svg_group, annotation_view.to_svg().to_string());
+    }
+
+    #[test]
+    fn annotation_view_range() {
+        let annotation_svg =
+            AnnotationView::new(AnnotationShape::VerticalRange(120_f32, 80_f32), 400, 300)
+                .to_svg()
+                .to_string();
+
+        assert!(annotation_svg.contains(r##"fill="#bbbbbb" fill-opacity="0.3""##));
+        assert!(annotation_svg
+            .contains(r#"height="300" shape-rendering="crispEdges" width="40" x="80" y="0""#));
+        assert!(!annotation_svg.contains("<text"));
+    }
+}
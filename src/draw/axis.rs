@@ -0,0 +1,353 @@
+use crate::draw::*;
+use lc_render::{Scale, View};
+use svg::Node;
+
+const DEFAULT_TICK_LINE_LENGTH: i32 = 6;
+const DEFAULT_TICK_FONT_SIZE: &str = "12px";
+const DEFAULT_TICK_LABEL_HORIZONTAL_OFFSET: i32 = 12;
+const DEFAULT_TICK_LABEL_VERTICAL_OFFSET: i32 = 16;
+
+const DEFAULT_FONT_SIZE: &str = "14px";
+
+const CLASS_GRID: &str = "grid";
+const CLASS_GRID_MINOR: &str = "grid minor";
+const MINOR_GRIDLINE_OPACITY: f32 = 0.5_f32;
+const STROKE_OPACITY_ATTR: &str = "stroke-opacity";
+
+const DEFAULT_AXIS_LABEL_TOP_OFFSET: i32 = -32;
+const DEFAULT_AXIS_LABEL_BOTTOM_OFFSET: i32 = 42;
+const DEFAULT_AXIS_LABEL_LEFT_OFFSET: i32 = -42;
+const DEFAULT_AXIS_LABEL_RIGHT_OFFSET: i32 = -28;
+
+/// Position of an axis on a chart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum AxisPosition {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+// Axis tick with its scaled offset and label.
+#[derive(Clone)]
+struct AxisTick {
+    offset: f32,
+    label: String,
+}
+
+/// Axis view for scales that can't be set on lc-render chart.
+/// It's drawn as a chart view so its position is relative to the views area.
+#[derive(Clone)]
+pub(crate) struct AxisView {
+    position: AxisPosition,
+    ticks: Vec<AxisTick>,
+    view_width: i32,
+    view_height: i32,
+    label: String,
+    tick_label_rotation: i32,
+    line_hidden: bool,
+    major_gridlines: bool,
+    minor_gridlines: bool,
+}
+
+impl AxisView {
+    pub(crate) fn new<T: ToString>(
+        scale: &dyn Scale<T>,
+        position: AxisPosition,
+        view_width: i32,
+        view_height: i32,
+    ) -> Self {
+        let ticks = scale
+            .ticks()
+            .iter()
+            .map(|tick| (scale.scale(tick) + scale.tick_offset(), tick.to_string()))
+            .collect();
+
+        AxisView::from_ticks(ticks, position, view_width, view_height)
+    }
+
+    // Create axis view from scaled tick offsets with their labels.
+    pub(crate) fn from_ticks(
+        ticks: Vec<(f32, String)>,
+        position: AxisPosition,
+        view_width: i32,
+        view_height: i32,
+    ) -> Self {
+        AxisView {
+            position,
+            ticks: ticks
+                .into_iter()
+                .map(|(offset, label)| AxisTick { offset, label })
+                .collect(),
+            view_width,
+            view_height,
+            label: String::new(),
+            tick_label_rotation: 0,
+            line_hidden: false,
+            major_gridlines: false,
+            minor_gridlines: false,
+        }
+    }
+
+    pub(crate) fn set_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub(crate) fn set_tick_label_rotation(mut self, tick_label_rotation: i32) -> Self {
+        self.tick_label_rotation = tick_label_rotation;
+        self
+    }
+
+    pub(crate) fn set_line_hidden(mut self, line_hidden: bool) -> Self {
+        self.line_hidden = line_hidden;
+        self
+    }
+
+    pub(crate) fn set_gridlines(mut self, major_gridlines: bool, minor_gridlines: bool) -> Self {
+        self.major_gridlines = major_gridlines;
+        self.minor_gridlines = minor_gridlines;
+        self
+    }
+
+    // Get axis offset from the start of the views area.
+    fn translate(&self) -> (i32, i32) {
+        match self.position {
+            AxisPosition::Top | AxisPosition::Left => (0, 0),
+            AxisPosition::Bottom => (0, self.view_height),
+            AxisPosition::Right => (self.view_width, 0),
+        }
+    }
+
+    fn line_to_svg(&self) -> svg::node::element::Line {
+        let (x2, y2) = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => (self.view_width, 0),
+            AxisPosition::Left | AxisPosition::Right => (0, self.view_height),
+        };
+
+        svg::node::element::Line::new()
+            .set(X1_ATTR, START)
+            .set(X2_ATTR, x2)
+            .set(Y1_ATTR, START)
+            .set(Y2_ATTR, y2)
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+            .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+            .set(STROKE_ATTR, DEFAULT_STROKE_COLOR)
+    }
+
+    fn tick_to_svg(&self, tick: &AxisTick) -> svg::node::element::Group {
+        let (translate_x, translate_y, x2, y2, text_anchor, label_x, label_y) = match self.position
+        {
+            AxisPosition::Top => (
+                tick.offset,
+                START,
+                0,
+                -DEFAULT_TICK_LINE_LENGTH,
+                TEXT_ANCHOR_MIDDLE,
+                0,
+                -DEFAULT_TICK_LABEL_VERTICAL_OFFSET,
+            ),
+            AxisPosition::Bottom => (
+                tick.offset,
+                START,
+                0,
+                DEFAULT_TICK_LINE_LENGTH,
+                TEXT_ANCHOR_MIDDLE,
+                0,
+                DEFAULT_TICK_LABEL_VERTICAL_OFFSET,
+            ),
+            AxisPosition::Left => (
+                START,
+                tick.offset,
+                -DEFAULT_TICK_LINE_LENGTH,
+                0,
+                TEXT_ANCHOR_END,
+                -DEFAULT_TICK_LABEL_HORIZONTAL_OFFSET,
+                0,
+            ),
+            AxisPosition::Right => (
+                START,
+                tick.offset,
+                DEFAULT_TICK_LINE_LENGTH,
+                0,
+                TEXT_ANCHOR_START,
+                DEFAULT_TICK_LABEL_HORIZONTAL_OFFSET,
+                0,
+            ),
+        };
+
+        // Rotated labels of horizontal axes are anchored at the tick.
+        let text_anchor = match (self.position, self.tick_label_rotation) {
+            (AxisPosition::Top | AxisPosition::Bottom, 0) => text_anchor,
+            (AxisPosition::Top, rotation) if rotation > 0 => TEXT_ANCHOR_END,
+            (AxisPosition::Bottom, rotation) if rotation < 0 => TEXT_ANCHOR_END,
+            (AxisPosition::Top | AxisPosition::Bottom, _) => TEXT_ANCHOR_START,
+            _ => text_anchor,
+        };
+
+        svg::node::element::Group::new()
+            .set(CLASS_ATTR, CLASS_TICK)
+            .set(TRANSFORM_ATTR, translate_x_y(translate_x, translate_y))
+            .add(
+                svg::node::element::Line::new()
+                    .set(X1_ATTR, START)
+                    .set(Y1_ATTR, START)
+                    .set(X2_ATTR, x2)
+                    .set(Y2_ATTR, y2)
+                    .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+                    .set(STROKE_ATTR, DEFAULT_STROKE_COLOR)
+                    .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH),
+            )
+            .add(
+                svg::node::element::Text::new()
+                    .set(
+                        TRANSFORM_ATTR,
+                        rotate_a_x_y(self.tick_label_rotation, label_x, label_y),
+                    )
+                    .set(X_ATTR, label_x)
+                    .set(Y_ATTR, label_y)
+                    .set(DY_ATTR, DEFAULT_DY)
+                    .set(TEXT_ANCHOR_ATTR, text_anchor)
+                    .set(FONT_SIZE_ATTR, DEFAULT_TICK_FONT_SIZE)
+                    .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                    .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                    .add(svg::node::Text::new(tick.label.as_str())),
+            )
+    }
+
+    // Get gridlines across the views area at tick offsets, minor gridlines are placed
+    // between ticks.
+    fn grid_to_svg(&self, offsets: &[f32], class: &str) -> svg::node::element::Group {
+        // Gridlines go from the axis to the opposite side of the views area.
+        let (x2, y2) = match self.position {
+            AxisPosition::Top => (0, self.view_height),
+            AxisPosition::Bottom => (0, -self.view_height),
+            AxisPosition::Left => (self.view_width, 0),
+            AxisPosition::Right => (-self.view_width, 0),
+        };
+
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, class);
+        for offset in offsets.iter() {
+            let (translate_x, translate_y) = match self.position {
+                AxisPosition::Top | AxisPosition::Bottom => (*offset, START),
+                AxisPosition::Left | AxisPosition::Right => (START, *offset),
+            };
+            let mut line = svg::node::element::Line::new()
+                .set(TRANSFORM_ATTR, translate_x_y(translate_x, translate_y))
+                .set(X1_ATTR, START)
+                .set(Y1_ATTR, START)
+                .set(X2_ATTR, x2)
+                .set(Y2_ATTR, y2)
+                .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+                .set(STROKE_ATTR, DEFAULT_STROKE_COLOR)
+                .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH);
+            if class == CLASS_GRID_MINOR {
+                line = line.set(STROKE_OPACITY_ATTR, MINOR_GRIDLINE_OPACITY);
+            }
+            res.append(line);
+        }
+
+        res
+    }
+
+    fn label_to_svg(&self) -> svg::node::element::Text {
+        let length = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => self.view_width,
+            AxisPosition::Left | AxisPosition::Right => self.view_height,
+        };
+        let (label_x, label_y, rotation) = match self.position {
+            AxisPosition::Top => (length / 2, DEFAULT_AXIS_LABEL_TOP_OFFSET, 0),
+            AxisPosition::Bottom => (length / 2, DEFAULT_AXIS_LABEL_BOTTOM_OFFSET, 0),
+            AxisPosition::Left => (-(length / 2), DEFAULT_AXIS_LABEL_LEFT_OFFSET, -90),
+            AxisPosition::Right => (length / 2, DEFAULT_AXIS_LABEL_RIGHT_OFFSET, 90),
+        };
+
+        svg::node::element::Text::new()
+            .set(X_ATTR, label_x)
+            .set(Y_ATTR, label_y)
+            .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE)
+            .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+            .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+            .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+            .set(TRANSFORM_ATTR, rotate_a(rotation))
+            .add(svg::node::Text::new(self.label.as_str()))
+    }
+}
+
+impl View for AxisView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let axis_class = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => CLASS_X_AXIS,
+            AxisPosition::Left | AxisPosition::Right => CLASS_Y_AXIS,
+        };
+        let (translate_x, translate_y) = self.translate();
+
+        let mut res = svg::node::element::Group::new()
+            .set(CLASS_ATTR, axis_class)
+            .set(TRANSFORM_ATTR, translate_x_y(translate_x, translate_y));
+
+        // Gridlines are drawn first so the axis line and ticks are drawn over them.
+        let mut offsets: Vec<f32> = self.ticks.iter().map(|tick| tick.offset).collect();
+        offsets.sort_by(|a, b| a.total_cmp(b));
+        if self.minor_gridlines {
+            let minor_offsets: Vec<f32> = offsets
+                .windows(2)
+                .map(|pair| (pair[0] + pair[1]) / 2_f32)
+                .collect();
+            res.append(self.grid_to_svg(&minor_offsets, CLASS_GRID_MINOR));
+        }
+        if self.major_gridlines {
+            res.append(self.grid_to_svg(&offsets, CLASS_GRID));
+        }
+        if !self.line_hidden {
+            res.append(self.line_to_svg());
+        }
+        for tick in self.ticks.iter() {
+            res.append(self.tick_to_svg(tick));
+        }
+        if !self.label.is_empty() {
+            res.append(self.label_to_svg());
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::scale::LogScale;
+
+    #[test]
+    fn axis_view_basic() {
+        let scale = LogScale::new(10_f32, 1_f32, 100_f32, 400, 0);
+        let axis_svg = AxisView::new(&scale, AxisPosition::Right, 600, 400)
+            .set_label("latency")
+            .to_svg()
+            .to_string();
+
+        assert!(axis_svg.starts_with(r#"<g class="y-axis" transform="translate(600,0)">"#));
+        assert!(axis_svg.contains(r#"<g class="tick" transform="translate(0,200)">"#));
+        assert!(axis_svg.contains(">\n100\n<"));
+        assert!(axis_svg.contains(">\nlatency\n<"));
+    }
+
+    #[test]
+    fn axis_view_gridlines() {
+        let ticks = vec![(0_f32, "0".to_string()), (100_f32, "10".to_string())];
+        let axis_svg = AxisView::from_ticks(ticks, AxisPosition::Bottom, 200, 300)
+            .set_tick_label_rotation(-45)
+            .set_line_hidden(true)
+            .set_gridlines(true, true)
+            .to_svg()
+            .to_string();
+
+        assert!(axis_svg.contains(r#"<g class="grid minor">"#));
+        assert!(axis_svg.contains(r#"transform="translate(50,0)""#));
+        assert!(axis_svg.contains(r#"<g class="grid">"#));
+        assert!(axis_svg.contains(r#"y2="-300""#));
+        assert!(axis_svg.contains(r#"text-anchor="end""#));
+        assert!(axis_svg.contains("rotate(-45,0,16)"));
+        assert!(!axis_svg.contains(r#"x2="200""#));
+    }
+}
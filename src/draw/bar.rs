@@ -0,0 +1,163 @@
+use crate::draw::*;
+use lc_render::{BarLabelPosition, Orientation};
+use svg::Node;
+
+const DEFAULT_FONT_SIZE: &str = "14px";
+const DEFAULT_LABEL_HORIZONTAL_OFFSET: f32 = 12_f32;
+const DEFAULT_LABEL_VERTICAL_OFFSET: f32 = 16_f32;
+
+/// Bar shape of a view with its label.
+/// Start and end are scaled positions along the value axis.
+#[derive(Clone)]
+pub(crate) struct Bar {
+    start: f32,
+    end: f32,
+    label: String,
+    width: f32,
+    offset: f32,
+    orientation: Orientation,
+    fill_color: String,
+    stroke_color: String,
+    label_visible: bool,
+    label_position: BarLabelPosition,
+}
+
+impl Bar {
+    pub(crate) fn new(
+        start: f32,
+        end: f32,
+        label: &str,
+        width: f32,
+        offset: f32,
+        orientation: Orientation,
+    ) -> Self {
+        Bar {
+            start,
+            end,
+            label: label.to_string(),
+            width,
+            offset,
+            orientation,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            label_visible: true,
+            label_position: BarLabelPosition::Center,
+        }
+    }
+
+    pub(crate) fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    pub(crate) fn set_label_position(mut self, label_position: BarLabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    fn label_text_anchor(&self) -> &'static str {
+        if self.orientation == Orientation::Vertical {
+            return TEXT_ANCHOR_MIDDLE;
+        }
+
+        match self.label_position {
+            BarLabelPosition::StartOutside | BarLabelPosition::EndInside => TEXT_ANCHOR_END,
+            BarLabelPosition::StartInside | BarLabelPosition::EndOutside => TEXT_ANCHOR_START,
+            BarLabelPosition::Center => TEXT_ANCHOR_MIDDLE,
+        }
+    }
+
+    // Label position along the value axis, it mirrors lc-render bar labels.
+    fn label_x_attr(&self) -> f32 {
+        let horizontal = self.orientation == Orientation::Horizontal;
+        match self.label_position {
+            BarLabelPosition::StartOutside if horizontal => {
+                self.start - DEFAULT_LABEL_HORIZONTAL_OFFSET
+            }
+            BarLabelPosition::StartOutside => self.end + DEFAULT_LABEL_VERTICAL_OFFSET,
+            BarLabelPosition::StartInside if horizontal => {
+                self.start + DEFAULT_LABEL_HORIZONTAL_OFFSET
+            }
+            BarLabelPosition::StartInside => self.end - DEFAULT_LABEL_VERTICAL_OFFSET,
+            BarLabelPosition::Center => self.start + (self.end - self.start) / 2_f32,
+            BarLabelPosition::EndInside if horizontal => self.end - DEFAULT_LABEL_VERTICAL_OFFSET,
+            BarLabelPosition::EndInside => self.start + DEFAULT_LABEL_HORIZONTAL_OFFSET,
+            BarLabelPosition::EndOutside if horizontal => self.end + DEFAULT_LABEL_VERTICAL_OFFSET,
+            BarLabelPosition::EndOutside => self.start - DEFAULT_LABEL_HORIZONTAL_OFFSET,
+        }
+    }
+
+    pub(crate) fn to_svg(&self) -> svg::node::element::Group {
+        let (x_attr, y_attr, w_attr, h_attr, offset_x, offset_y) = match self.orientation {
+            Orientation::Horizontal => {
+                (X_ATTR, Y_ATTR, WIDTH_ATTR, HEIGHT_ATTR, 0_f32, self.offset)
+            }
+            Orientation::Vertical => (Y_ATTR, X_ATTR, HEIGHT_ATTR, WIDTH_ATTR, self.offset, 0_f32),
+        };
+
+        let rect = svg::node::element::Rectangle::new()
+            .set(x_attr, self.start)
+            .set(y_attr, START)
+            .set(w_attr, self.end - self.start)
+            .set(h_attr, self.width)
+            .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES)
+            .set(FILL_ATTR, self.fill_color.as_str())
+            .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+            .set(STROKE_ATTR, self.stroke_color.as_str());
+
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(offset_x, offset_y))
+            .set(CLASS_ATTR, CLASS_BAR);
+        res.append(rect);
+
+        if !self.label_visible {
+            return res;
+        }
+
+        res.append(
+            svg::node::element::Text::new()
+                .set(x_attr, self.label_x_attr())
+                .set(y_attr, self.width / 2_f32)
+                .set(TEXT_ANCHOR_ATTR, self.label_text_anchor())
+                .set(DY_ATTR, DEFAULT_DY)
+                .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+                .add(svg::node::Text::new(self.label.as_str())),
+        );
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_basic() {
+        let expected_svg_group = r##"<g class="bar" transform="translate(10,0)">
+<rect fill="#f289ff" height="40" shape-rendering="crispEdges" stroke="#8a87f6" stroke-width="1" width="30" x="0" y="20"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="15" y="40">
+100
+</text>
+</g>"##;
+
+        let bar_svg = Bar::new(20_f32, 60_f32, "100", 30_f32, 10_f32, Orientation::Vertical)
+            .set_fill_color("#f289ff")
+            .set_stroke_color("#8a87f6")
+            .to_svg();
+
+        assert_eq!(expected_svg_group, bar_svg.to_string());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::draw::*;
+use lc_render::Orientation;
+use svg::Node;
+
+const DEFAULT_CAP_WIDTH: f32 = 8_f32;
+const DEFAULT_BAND_FILL_OPACITY: f32 = 0.3_f32;
+
+/// Interval of a value with scaled positions of its lower and upper bounds.
+/// Position is a scaled coordinate along the other axis, e.g. x of a point or center of a bar.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Interval {
+    position: f32,
+    lower: f32,
+    upper: f32,
+}
+
+impl Interval {
+    pub(crate) fn new(position: f32, lower: f32, upper: f32) -> Self {
+        Interval {
+            position,
+            lower,
+            upper,
+        }
+    }
+}
+
+/// Error bar with whiskers from the lower to the upper bound of an interval.
+#[derive(Clone)]
+pub(crate) struct ErrorBar {
+    interval: Interval,
+    orientation: Orientation,
+    cap_width: f32,
+    stroke_color: String,
+}
+
+impl ErrorBar {
+    pub(crate) fn new(interval: Interval, orientation: Orientation) -> Self {
+        ErrorBar {
+            interval,
+            orientation,
+            cap_width: DEFAULT_CAP_WIDTH,
+            stroke_color: String::new(),
+        }
+    }
+
+    // Set maximum width of caps, they're not wider than the default width.
+    pub(crate) fn set_max_cap_width(mut self, max_cap_width: f32) -> Self {
+        self.cap_width = DEFAULT_CAP_WIDTH.min(max_cap_width);
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    // Get SVG line between two points of the value and position axes.
+    fn line(
+        &self,
+        value1: f32,
+        position1: f32,
+        value2: f32,
+        position2: f32,
+    ) -> svg::node::element::Line {
+        let (x1, y1, x2, y2) = match self.orientation {
+            Orientation::Vertical => (position1, value1, position2, value2),
+            Orientation::Horizontal => (value1, position1, value2, position2),
+        };
+
+        svg::node::element::Line::new()
+            .set(X1_ATTR, x1)
+            .set(Y1_ATTR, y1)
+            .set(X2_ATTR, x2)
+            .set(Y2_ATTR, y2)
+            .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+            .set(STROKE_ATTR, self.stroke_color.as_str())
+    }
+
+    pub(crate) fn to_svg(&self) -> svg::node::element::Group {
+        let Interval {
+            position,
+            lower,
+            upper,
+        } = self.interval;
+        let cap_start = position - self.cap_width / 2_f32;
+        let cap_end = position + self.cap_width / 2_f32;
+
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_ERROR_BAR);
+        res.append(self.line(lower, position, upper, position));
+        res.append(self.line(lower, cap_start, lower, cap_end));
+        res.append(self.line(upper, cap_start, upper, cap_end));
+
+        res
+    }
+}
+
+// Get SVG path of a band between lower and upper bounds of intervals along x.
+// The path goes along upper bounds and returns along lower bounds in the reverse order.
+pub(crate) fn get_band_svg(intervals: &[Interval], fill_color: &str) -> svg::node::element::Path {
+    let mut data = svg::node::element::path::Data::new();
+    for (idx, interval) in intervals.iter().enumerate() {
+        if idx == 0 {
+            data = data.move_to((interval.position, interval.upper));
+        } else {
+            data = data.line_to((interval.position, interval.upper));
+        }
+    }
+    for interval in intervals.iter().rev() {
+        data = data.line_to((interval.position, interval.lower));
+    }
+    if !intervals.is_empty() {
+        data = data.close();
+    }
+
+    svg::node::element::Path::new()
+        .set(CLASS_ATTR, CLASS_INTERVAL)
+        .set(FILL_ATTR, fill_color)
+        .set(FILL_OPACITY_ATTR, DEFAULT_BAND_FILL_OPACITY)
+        .set(STROKE_ATTR, FILL_NONE)
+        .set(D_ATTR, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_bar_basic() {
+        let expected_svg_group = r##"<g class="error-bar">
+<line stroke="#8a87f6" stroke-width="1" x1="50" x2="50" y1="80" y2="20"/>
+<line stroke="#8a87f6" stroke-width="1" x1="47" x2="53" y1="80" y2="80"/>
+<line stroke="#8a87f6" stroke-width="1" x1="47" x2="53" y1="20" y2="20"/>
+</g>"##;
+
+        let error_bar_svg =
+            ErrorBar::new(Interval::new(50_f32, 80_f32, 20_f32), Orientation::Vertical)
+                .set_max_cap_width(6_f32)
+                .set_stroke_color("#8a87f6")
+                .to_svg();
+
+        assert_eq!(expected_svg_group, error_bar_svg.to_string());
+    }
+
+    #[test]
+    fn band_svg_basic() {
+        let intervals = vec![
+            Interval::new(0_f32, 60_f32, 40_f32),
+            Interval::new(100_f32, 50_f32, 10_f32),
+        ];
+
+        let band_svg = get_band_svg(&intervals, "#226974").to_string();
+
+        assert!(band_svg.contains(r#"d="M0,40 L100,10 L100,50 L0,60 z""#));
+        assert!(band_svg.contains(r#"fill-opacity="0.3""#));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::draw::*;
+use lc_render::View;
+use svg::Node;
+
+const SWATCH_OFFSET_RATIO: f32 = 1.5;
+const CHAR_WIDTH_RATIO: f32 = 0.6;
+const ENTRY_GAP_RATIO: f32 = 0.5;
+
+/// Legend entry with a color swatch and a series name.
+#[derive(Clone)]
+pub(crate) struct LegendEntry {
+    name: String,
+    fill_color: String,
+    stroke_color: String,
+}
+
+impl LegendEntry {
+    pub(crate) fn new(name: &str, fill_color: &str, stroke_color: &str) -> Self {
+        LegendEntry {
+            name: name.to_string(),
+            fill_color: fill_color.to_string(),
+            stroke_color: stroke_color.to_string(),
+        }
+    }
+
+    // Get approximate entry width, text width is estimated from its length.
+    fn width(&self, font_size: i32) -> f32 {
+        let font_size = font_size as f32;
+
+        font_size * SWATCH_OFFSET_RATIO
+            + self.name.chars().count() as f32 * font_size * CHAR_WIDTH_RATIO
+    }
+}
+
+/// Legend orientation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum LegendOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// View that represents a chart legend.
+/// Its position is set relative to the top left corner of chart views.
+#[derive(Clone)]
+pub(crate) struct LegendView {
+    x: f32,
+    y: f32,
+    orientation: LegendOrientation,
+    font_size: i32,
+    entries: Vec<LegendEntry>,
+}
+
+impl LegendView {
+    pub(crate) fn new(
+        entries: Vec<LegendEntry>,
+        orientation: LegendOrientation,
+        font_size: i32,
+    ) -> Self {
+        LegendView {
+            x: START,
+            y: START,
+            orientation,
+            font_size,
+            entries,
+        }
+    }
+
+    pub(crate) fn set_position(mut self, x: f32, y: f32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    // Get distance between entries.
+    fn gap(&self) -> f32 {
+        match self.orientation {
+            LegendOrientation::Horizontal => self.font_size as f32,
+            LegendOrientation::Vertical => self.font_size as f32 * ENTRY_GAP_RATIO,
+        }
+    }
+
+    pub(crate) fn width(&self) -> f32 {
+        let widths = self.entries.iter().map(|entry| entry.width(self.font_size));
+        match self.orientation {
+            LegendOrientation::Horizontal => {
+                let gaps = self.entries.len().saturating_sub(1) as f32 * self.gap();
+                widths.sum::<f32>() + gaps
+            }
+            LegendOrientation::Vertical => widths.fold(0_f32, f32::max),
+        }
+    }
+
+    pub(crate) fn height(&self) -> f32 {
+        let font_size = self.font_size as f32;
+        match self.orientation {
+            LegendOrientation::Horizontal => font_size,
+            LegendOrientation::Vertical => {
+                let count = self.entries.len() as f32;
+                count * font_size + (count - 1_f32).max(0_f32) * self.gap()
+            }
+        }
+    }
+}
+
+impl View for LegendView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let font_size = self.font_size as f32;
+        let mut res = svg::node::element::Group::new()
+            .set(CLASS_ATTR, CLASS_LEGEND)
+            .set(TRANSFORM_ATTR, translate_x_y(self.x, self.y));
+
+        let mut offset = START;
+        for entry in self.entries.iter() {
+            let (x, y) = match self.orientation {
+                LegendOrientation::Horizontal => (offset, START),
+                LegendOrientation::Vertical => (START, offset),
+            };
+            let mut entry_group =
+                svg::node::element::Group::new().set(TRANSFORM_ATTR, translate_x_y(x, y));
+            entry_group.append(
+                svg::node::element::Rectangle::new()
+                    .set(X_ATTR, START)
+                    .set(Y_ATTR, START)
+                    .set(WIDTH_ATTR, font_size)
+                    .set(HEIGHT_ATTR, font_size)
+                    .set(FILL_ATTR, entry.fill_color.as_str())
+                    .set(STROKE_ATTR, entry.stroke_color.as_str())
+                    .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+                    .set(SHAPE_RENDERING_ATTR, SHAPE_RENDERING_CRISP_EDGES),
+            );
+            entry_group.append(
+                svg::node::element::Text::new()
+                    .set(X_ATTR, font_size * SWATCH_OFFSET_RATIO)
+                    .set(Y_ATTR, font_size / 2_f32)
+                    .set(DY_ATTR, DEFAULT_DY)
+                    .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_START)
+                    .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                    .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                    .set(FONT_SIZE_ATTR, format!("{}px", self.font_size))
+                    .add(svg::node::Text::new(entry.name.as_str())),
+            );
+            res.append(entry_group);
+
+            offset += match self.orientation {
+                LegendOrientation::Horizontal => entry.width(self.font_size),
+                LegendOrientation::Vertical => font_size,
+            } + self.gap();
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legend_entries() -> Vec<LegendEntry> {
+        vec![
+            LegendEntry::new("ab", "#f289ff", "#ffffff"),
+            LegendEntry::new("abcd", "#226974", "#50c5b6"),
+        ]
+    }
+
+    #[test]
+    fn legend_view_basic() {
+        let expected_svg_group = r##"<g class="legend" transform="translate(10,20)">
+<g transform="translate(0,0)">
+<rect fill="#f289ff" height="10" shape-rendering="crispEdges" stroke="#ffffff" stroke-width="1" width="10" x="0" y="0"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="10px" text-anchor="start" x="15" y="5">
+ab
+</text>
+</g>
+<g transform="translate(37,0)">
+<rect fill="#226974" height="10" shape-rendering="crispEdges" stroke="#50c5b6" stroke-width="1" width="10" x="0" y="0"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="10px" text-anchor="start" x="15" y="5">
+abcd
+</text>
+</g>
+</g>"##;
+
+        let legend_view = LegendView::new(legend_entries(), LegendOrientation::Horizontal, 10)
+            .set_position(10_f32, 20_f32);
+
+        assert_eq!(76_f32, legend_view.width());
+        assert_eq!(10_f32, legend_view.height());
+        assert_eq!(expected_svg_group, legend_view.to_svg().to_string());
+    }
+
+    #[test]
+    fn legend_view_vertical() {
+        let legend_view = LegendView::new(legend_entries(), LegendOrientation::Vertical, 10);
+        let legend_svg = legend_view.to_svg().to_string();
+
+        assert_eq!(39_f32, legend_view.width());
+        assert_eq!(25_f32, legend_view.height());
+        assert!(legend_svg.contains(r#"<g transform="translate(0,15)">"#));
+    }
+}
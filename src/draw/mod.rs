@@ -0,0 +1,80 @@
+pub(crate) mod annotation;
+pub(crate) mod axis;
+pub(crate) mod bar;
+pub(crate) mod interval;
+pub(crate) mod legend;
+pub(crate) mod point;
+pub(crate) mod scale;
+pub(crate) mod slice;
+pub(crate) mod view;
+
+// Elements are drawn with the same SVG structure and styles as lc-render ones
+// so they look the same on a chart.
+pub(crate) const DEFAULT_POINT_SIZE: i32 = 5;
+pub(crate) const DEFAULT_FONT_COLOR: &str = "#080808";
+pub(crate) const DEFAULT_FONT_FAMILY: &str = "sans-serif";
+pub(crate) const DEFAULT_DY: &str = ".35em";
+pub(crate) const DEFAULT_STROKE_WIDTH: i32 = 1;
+pub(crate) const DEFAULT_STROKE_COLOR: &str = "#bbbbbb";
+
+pub(crate) const X_ATTR: &str = "x";
+pub(crate) const X1_ATTR: &str = "x1";
+pub(crate) const X2_ATTR: &str = "x2";
+pub(crate) const Y_ATTR: &str = "y";
+pub(crate) const Y1_ATTR: &str = "y1";
+pub(crate) const Y2_ATTR: &str = "y2";
+pub(crate) const CX_ATTR: &str = "cx";
+pub(crate) const CY_ATTR: &str = "cy";
+pub(crate) const R_ATTR: &str = "r";
+pub(crate) const D_ATTR: &str = "d";
+pub(crate) const DY_ATTR: &str = "dy";
+pub(crate) const WIDTH_ATTR: &str = "width";
+pub(crate) const HEIGHT_ATTR: &str = "height";
+pub(crate) const STROKE_ATTR: &str = "stroke";
+pub(crate) const STROKE_WIDTH_ATTR: &str = "stroke-width";
+pub(crate) const FILL_ATTR: &str = "fill";
+pub(crate) const FILL_OPACITY_ATTR: &str = "fill-opacity";
+pub(crate) const TRANSFORM_ATTR: &str = "transform";
+pub(crate) const TEXT_ANCHOR_ATTR: &str = "text-anchor";
+pub(crate) const FONT_SIZE_ATTR: &str = "font-size";
+pub(crate) const FONT_FAMILY_ATTR: &str = "font-family";
+pub(crate) const SHAPE_RENDERING_ATTR: &str = "shape-rendering";
+pub(crate) const CLASS_ATTR: &str = "class";
+
+pub(crate) const TEXT_ANCHOR_START: &str = "start";
+pub(crate) const TEXT_ANCHOR_MIDDLE: &str = "middle";
+pub(crate) const TEXT_ANCHOR_END: &str = "end";
+pub(crate) const SHAPE_RENDERING_CRISP_EDGES: &str = "crispEdges";
+pub(crate) const FILL_NONE: &str = "none";
+
+pub(crate) const CLASS_ANNOTATION: &str = "annotation";
+pub(crate) const CLASS_AREA: &str = "area";
+pub(crate) const CLASS_BAR: &str = "bar";
+pub(crate) const CLASS_DEFAULT_COLOR: &str = "default-color";
+pub(crate) const CLASS_ERROR_BAR: &str = "error-bar";
+pub(crate) const CLASS_INTERVAL: &str = "interval";
+pub(crate) const CLASS_LEGEND: &str = "legend";
+pub(crate) const CLASS_LINE: &str = "line";
+pub(crate) const CLASS_POINT: &str = "point";
+pub(crate) const CLASS_SLICE: &str = "slice";
+pub(crate) const CLASS_TICK: &str = "tick";
+pub(crate) const CLASS_X_AXIS: &str = "x-axis";
+pub(crate) const CLASS_Y_AXIS: &str = "y-axis";
+
+pub(crate) const START: f32 = 0_f32;
+
+pub(crate) fn translate_x_y<T: std::fmt::Display>(x: T, y: T) -> String {
+    format!("translate({},{})", x, y)
+}
+
+pub(crate) fn rotate_a_x_y<T: std::fmt::Display>(a: T, x: T, y: T) -> String {
+    format!("rotate({},{},{})", a, x, y)
+}
+
+pub(crate) fn rotate_a<T: std::fmt::Display>(a: T) -> String {
+    format!("rotate({})", a)
+}
+
+pub(crate) fn pair_x_y<T: std::fmt::Display>(x: T, y: T) -> String {
+    format!("({},{})", x, y)
+}
@@ -0,0 +1,226 @@
+use crate::draw::*;
+use lc_render::{PointLabelPosition, PointType};
+use svg::Node;
+
+const DEFAULT_POINT_STROKE_WIDTH: &str = "2px";
+
+const DEFAULT_X_LABEL_HORIZONTAL: i32 = 8;
+const DEFAULT_X_LABEL_VERTICAL: i32 = 0;
+const DEFAULT_X_LABEL_BETWEEN: i32 = 4;
+
+const DEFAULT_Y_LABEL_HORIZONTAL: i32 = 0;
+const DEFAULT_Y_LABEL_VERTICAL: i32 = 12;
+const DEFAULT_Y_LABEL_BETWEEN: i32 = 8;
+
+const DEFAULT_FONT_SIZE: &str = "14px";
+
+/// Point shape of a view with its label.
+#[derive(Clone)]
+pub(crate) struct Point {
+    x: f32,
+    y: f32,
+    point_type: PointType,
+    point_visible: bool,
+    size: i32,
+    x_label: String,
+    y_label: String,
+    fill_color: String,
+    stroke_color: String,
+    label_visible: bool,
+    label_position: PointLabelPosition,
+}
+
+impl Point {
+    pub(crate) fn new(
+        x: f32,
+        y: f32,
+        point_type: PointType,
+        y_label: &str,
+        fill_color: &str,
+        stroke_color: &str,
+    ) -> Self {
+        Point {
+            x,
+            y,
+            point_type,
+            point_visible: true,
+            size: DEFAULT_POINT_SIZE,
+            x_label: String::new(),
+            y_label: y_label.to_string(),
+            fill_color: fill_color.to_string(),
+            stroke_color: stroke_color.to_string(),
+            label_visible: true,
+            label_position: PointLabelPosition::Top,
+        }
+    }
+
+    pub(crate) fn set_point_visible(mut self, point_visible: bool) -> Self {
+        self.point_visible = point_visible;
+        self
+    }
+
+    pub(crate) fn set_x_label(mut self, x_label: &str) -> Self {
+        self.x_label = x_label.to_string();
+        self
+    }
+
+    pub(crate) fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    pub(crate) fn set_label_position(mut self, label_position: PointLabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    pub(crate) fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub(crate) fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn label_text_anchor(&self) -> &'static str {
+        match self.label_position {
+            PointLabelPosition::Top | PointLabelPosition::Bottom => TEXT_ANCHOR_MIDDLE,
+            PointLabelPosition::TopRight
+            | PointLabelPosition::BottomRight
+            | PointLabelPosition::Right => TEXT_ANCHOR_START,
+            PointLabelPosition::TopLeft
+            | PointLabelPosition::BottomLeft
+            | PointLabelPosition::Left => TEXT_ANCHOR_END,
+        }
+    }
+
+    fn label_x_attr(&self) -> i32 {
+        match self.label_position {
+            PointLabelPosition::Top | PointLabelPosition::Bottom => DEFAULT_X_LABEL_VERTICAL,
+            PointLabelPosition::TopRight | PointLabelPosition::BottomRight => {
+                self.size + DEFAULT_X_LABEL_BETWEEN
+            }
+            PointLabelPosition::Right => self.size + DEFAULT_X_LABEL_HORIZONTAL,
+            PointLabelPosition::TopLeft | PointLabelPosition::BottomLeft => {
+                -self.size - DEFAULT_X_LABEL_BETWEEN
+            }
+            PointLabelPosition::Left => -self.size - DEFAULT_X_LABEL_HORIZONTAL,
+        }
+    }
+
+    fn label_y_attr(&self) -> i32 {
+        match self.label_position {
+            PointLabelPosition::Top => -self.size - DEFAULT_Y_LABEL_VERTICAL,
+            PointLabelPosition::TopRight | PointLabelPosition::TopLeft => {
+                -self.size - DEFAULT_Y_LABEL_BETWEEN
+            }
+            PointLabelPosition::Right | PointLabelPosition::Left => DEFAULT_Y_LABEL_HORIZONTAL,
+            PointLabelPosition::BottomRight | PointLabelPosition::BottomLeft => {
+                self.size + DEFAULT_Y_LABEL_BETWEEN
+            }
+            PointLabelPosition::Bottom => self.size + DEFAULT_Y_LABEL_VERTICAL,
+        }
+    }
+
+    fn append_shape(&self, group: &mut svg::node::element::Group) {
+        match self.point_type {
+            PointType::Circle => group.append(
+                svg::node::element::Circle::new()
+                    .set(CX_ATTR, START)
+                    .set(CY_ATTR, START)
+                    .set(R_ATTR, self.size)
+                    .set(FILL_ATTR, self.fill_color.as_str())
+                    .set(STROKE_ATTR, self.stroke_color.as_str()),
+            ),
+            PointType::Square => group.append(
+                svg::node::element::Rectangle::new()
+                    .set(X_ATTR, -self.size)
+                    .set(Y_ATTR, -self.size)
+                    .set(WIDTH_ATTR, 2 * self.size)
+                    .set(HEIGHT_ATTR, 2 * self.size)
+                    .set(FILL_ATTR, self.fill_color.as_str())
+                    .set(STROKE_ATTR, self.stroke_color.as_str()),
+            ),
+            PointType::X => group.append(
+                svg::node::element::Group::new()
+                    .add(
+                        svg::node::element::Line::new()
+                            .set(X1_ATTR, -self.size)
+                            .set(Y1_ATTR, -self.size)
+                            .set(X2_ATTR, self.size)
+                            .set(Y2_ATTR, self.size)
+                            .set(STROKE_WIDTH_ATTR, DEFAULT_POINT_STROKE_WIDTH)
+                            .set(STROKE_ATTR, self.stroke_color.as_str()),
+                    )
+                    .add(
+                        svg::node::element::Line::new()
+                            .set(X1_ATTR, self.size)
+                            .set(Y1_ATTR, -self.size)
+                            .set(X2_ATTR, -self.size)
+                            .set(Y2_ATTR, self.size)
+                            .set(STROKE_WIDTH_ATTR, DEFAULT_POINT_STROKE_WIDTH)
+                            .set(STROKE_ATTR, self.stroke_color.as_str()),
+                    ),
+            ),
+        }
+    }
+
+    pub(crate) fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(self.x, self.y))
+            .set(CLASS_ATTR, CLASS_POINT);
+
+        if self.point_visible {
+            self.append_shape(&mut res);
+        }
+
+        if self.label_visible {
+            let label = if self.x_label.is_empty() {
+                self.y_label.clone()
+            } else {
+                pair_x_y(&self.x_label, &self.y_label)
+            };
+            res.append(
+                svg::node::element::Text::new()
+                    .set(DY_ATTR, DEFAULT_DY)
+                    .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                    .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                    .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+                    .set(X_ATTR, self.label_x_attr())
+                    .set(Y_ATTR, self.label_y_attr())
+                    .set(TEXT_ANCHOR_ATTR, self.label_text_anchor())
+                    .add(svg::node::Text::new(label)),
+            );
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_basic() {
+        let expected_svg_group = r##"<g class="point" transform="translate(10,20)">
+<circle cx="0" cy="0" fill="#f289ff" r="5" stroke="#8a87f6"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="end" x="-9" y="13">
+thirty
+</text>
+</g>"##;
+
+        let point_svg = Point::new(
+            10_f32,
+            20_f32,
+            PointType::Circle,
+            "thirty",
+            "#f289ff",
+            "#8a87f6",
+        )
+        .set_label_position(PointLabelPosition::BottomLeft)
+        .to_svg();
+
+        assert_eq!(expected_svg_group, point_svg.to_string());
+    }
+}
@@ -0,0 +1,621 @@
+use crate::error::RendererError;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use lc_render::{BandScale, LinearScale, Scale, ScaleKind};
+
+pub(crate) const DEFAULT_LOG_BASE: f32 = 10_f32;
+
+const MIN_TICK_COUNT: usize = 2;
+
+// Maximum count of ticks that can be requested for an axis.
+pub(crate) const MAX_TICK_COUNT: usize = 100;
+
+// Maximum count of ticks that a scale generates, nice steps can make more ticks than requested.
+const MAX_GENERATED_TICK_COUNT: usize = 1000;
+const MAX_TIME_TICK_COUNT: f64 = 10_f64;
+
+const SECONDS_IN_MINUTE: i64 = 60;
+const SECONDS_IN_HOUR: i64 = 3600;
+const SECONDS_IN_DAY: i64 = 86400;
+const SECONDS_IN_MONTH: i64 = 30 * SECONDS_IN_DAY;
+const SECONDS_IN_YEAR: i64 = 365 * SECONDS_IN_DAY;
+
+// Days from the epoch start on Thursday to the previous Monday.
+const EPOCH_DAYS_FROM_MONDAY: i64 = 3;
+
+// Relative tolerance to keep ticks that are equal to domain bounds.
+const TICK_TOLERANCE: f32 = 1e-6_f32;
+
+// Get step between about count ticks that is 1, 2 or 5 multiplied by a power of ten.
+pub(crate) fn get_nice_step(span: f32, count: f32) -> f32 {
+    let step = span / count;
+    let power = 10_f32.powf(step.log10().floor());
+    let error = step / power;
+    let factor = if error >= 50_f32.sqrt() {
+        10_f32
+    } else if error >= 10_f32.sqrt() {
+        5_f32
+    } else if error >= 2_f32.sqrt() {
+        2_f32
+    } else {
+        1_f32
+    };
+
+    factor * power
+}
+
+// Get about count ticks of a linear domain that are multiples of a nice step.
+pub(crate) fn get_linear_ticks(domain_start: f32, domain_end: f32, count: usize) -> Vec<f32> {
+    let (min, max) = (domain_start.min(domain_end), domain_start.max(domain_end));
+    if min == max || count == 0 {
+        return vec![min];
+    }
+    let step = get_nice_step(max - min, count.min(MAX_TICK_COUNT) as f32);
+    let first = (min / step - TICK_TOLERANCE).ceil() as i64;
+    let last = (max / step + TICK_TOLERANCE).floor() as i64;
+
+    (first..=last)
+        .take(MAX_GENERATED_TICK_COUNT)
+        .map(|idx| idx as f32 * step)
+        .collect()
+}
+
+/// LogScale represents axis scale with numerical values on a logarithmic scale.
+#[derive(Clone)]
+pub(crate) struct LogScale {
+    base: f32,
+    domain_start: f32,
+    domain_end: f32,
+    range_start: i32,
+    range_end: i32,
+}
+
+impl LogScale {
+    // Create a new LogScale, base and domain should be already validated.
+    pub(crate) fn new(
+        base: f32,
+        domain_start: f32,
+        domain_end: f32,
+        range_start: i32,
+        range_end: i32,
+    ) -> Self {
+        Self {
+            base,
+            domain_start,
+            domain_end,
+            range_start,
+            range_end,
+        }
+    }
+
+    fn log(&self, value: f32) -> f32 {
+        value.log(self.base)
+    }
+
+    fn domain_min(&self) -> f32 {
+        self.domain_start.min(self.domain_end)
+    }
+
+    fn domain_max(&self) -> f32 {
+        self.domain_start.max(self.domain_end)
+    }
+
+    // Get ticks for every power of base in domain.
+    fn power_ticks(&self, first_power: i32, last_power: i32) -> Vec<f32> {
+        (first_power..=last_power)
+            .map(|power| self.base.powi(power))
+            .filter(|tick| self.contains(*tick))
+            .take(MAX_GENERATED_TICK_COUNT)
+            .collect()
+    }
+
+    // Get ticks for every multiple of powers of base in domain.
+    // It's used for narrow domains that contain less than two powers of base.
+    fn multiple_ticks(&self, first_power: i32, last_power: i32) -> Vec<f32> {
+        if self.base.fract() != 0_f32 {
+            return Vec::new();
+        }
+
+        let mut res = Vec::new();
+        for power in first_power..=last_power {
+            for multiplier in 1..self.base as i32 {
+                let tick = multiplier as f32 * self.base.powi(power);
+                if self.contains(tick) && res.len() < MAX_GENERATED_TICK_COUNT {
+                    res.push(tick);
+                }
+            }
+        }
+
+        res
+    }
+
+    fn contains(&self, value: f32) -> bool {
+        value >= self.domain_min() * (1_f32 - TICK_TOLERANCE)
+            && value <= self.domain_max() * (1_f32 + TICK_TOLERANCE)
+    }
+}
+
+impl Scale<f32> for LogScale {
+    fn scale(&self, domain: &f32) -> f32 {
+        let log_start = self.log(self.domain_start);
+        let log_end = self.log(self.domain_end);
+        let normalized = if (log_end - log_start).abs() < f32::EPSILON {
+            0.5_f32
+        } else {
+            (self.log(*domain) - log_start) / (log_end - log_start)
+        };
+
+        (self.range_end - self.range_start) as f32 * normalized + self.range_start as f32
+    }
+
+    fn ticks(&self) -> Vec<f32> {
+        let first_power = self.log(self.domain_min()).floor() as i32;
+        let last_power = self.log(self.domain_max()).ceil() as i32;
+
+        let ticks = self.power_ticks(first_power, last_power);
+        if ticks.len() >= MIN_TICK_COUNT {
+            return ticks;
+        }
+
+        let ticks = self.multiple_ticks(first_power, last_power);
+        if ticks.len() >= MIN_TICK_COUNT {
+            return ticks;
+        }
+
+        vec![self.domain_min(), self.domain_max()]
+    }
+
+    fn kind(&self) -> ScaleKind {
+        ScaleKind::Linear
+    }
+
+    fn bandwidth(&self) -> f32 {
+        0_f32
+    }
+
+    fn is_range_reversed(&self) -> bool {
+        self.range_start > self.range_end
+    }
+
+    fn tick_offset(&self) -> f32 {
+        0_f32
+    }
+}
+
+/// Calendar unit of time scale ticks.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    // Get approximate unit duration that is used to choose ticks interval.
+    fn seconds(self) -> i64 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => SECONDS_IN_MINUTE,
+            TimeUnit::Hour => SECONDS_IN_HOUR,
+            TimeUnit::Day => SECONDS_IN_DAY,
+            TimeUnit::Month => SECONDS_IN_MONTH,
+            TimeUnit::Year => SECONDS_IN_YEAR,
+        }
+    }
+
+    // Get label format that is used if time scale format is not set.
+    fn default_format(self) -> &'static str {
+        match self {
+            TimeUnit::Second => "%H:%M:%S",
+            TimeUnit::Minute | TimeUnit::Hour => "%H:%M",
+            TimeUnit::Day => "%b %d",
+            TimeUnit::Month => "%b %Y",
+            TimeUnit::Year => "%Y",
+        }
+    }
+}
+
+// Available ticks intervals from the smallest to the largest.
+// Intervals that are larger than a year use multiples of years.
+const TIME_INTERVALS: &[(TimeUnit, i64)] = &[
+    (TimeUnit::Second, 1),
+    (TimeUnit::Second, 5),
+    (TimeUnit::Second, 15),
+    (TimeUnit::Second, 30),
+    (TimeUnit::Minute, 1),
+    (TimeUnit::Minute, 5),
+    (TimeUnit::Minute, 15),
+    (TimeUnit::Minute, 30),
+    (TimeUnit::Hour, 1),
+    (TimeUnit::Hour, 3),
+    (TimeUnit::Hour, 6),
+    (TimeUnit::Hour, 12),
+    (TimeUnit::Day, 1),
+    (TimeUnit::Day, 2),
+    (TimeUnit::Day, 7),
+    (TimeUnit::Month, 1),
+    (TimeUnit::Month, 3),
+    (TimeUnit::Month, 6),
+    (TimeUnit::Year, 1),
+];
+
+// Check that strftime format doesn't contain unknown specifiers.
+pub(crate) fn is_time_format_valid(format: &str) -> bool {
+    StrftimeItems::new(format).all(|item| item != Item::Error)
+}
+
+/// TimeScale represents axis scale with UTC time values.
+/// Time values are seconds since the Unix epoch.
+#[derive(Clone)]
+pub(crate) struct TimeScale {
+    domain_start: f64,
+    domain_end: f64,
+    range_start: i32,
+    range_end: i32,
+    unit: TimeUnit,
+    step: i64,
+    format: String,
+}
+
+impl TimeScale {
+    // Create a new TimeScale, domain should be already validated.
+    pub(crate) fn new(
+        domain_start: f64,
+        domain_end: f64,
+        range_start: i32,
+        range_end: i32,
+    ) -> Self {
+        let span = (domain_end - domain_start).abs();
+        let (unit, step) = TIME_INTERVALS
+            .iter()
+            .find(|(unit, step)| span / (unit.seconds() * step) as f64 <= MAX_TIME_TICK_COUNT)
+            .copied()
+            .unwrap_or_else(|| (TimeUnit::Year, years_step(span)));
+
+        Self {
+            domain_start,
+            domain_end,
+            range_start,
+            range_end,
+            unit,
+            step,
+            format: String::new(),
+        }
+    }
+
+    // Set strftime format of labels, it should be already validated.
+    pub(crate) fn set_format(mut self, format: &str) -> Self {
+        self.format = format.to_string();
+        self
+    }
+
+    // Format time value with the scale labels format.
+    pub(crate) fn format_time(&self, time: f64) -> String {
+        let format = if self.format.is_empty() {
+            self.unit.default_format()
+        } else {
+            &self.format
+        };
+
+        match get_date_time(time) {
+            Some(date_time) => date_time.format(format).to_string(),
+            None => time.to_string(),
+        }
+    }
+
+    fn domain_min(&self) -> f64 {
+        self.domain_start.min(self.domain_end)
+    }
+
+    fn domain_max(&self) -> f64 {
+        self.domain_start.max(self.domain_end)
+    }
+
+    // Get ticks with a fixed duration between them.
+    // Ticks are aligned to the interval from the start of the epoch so hours and days
+    // are started at midnight.
+    fn fixed_ticks(&self, interval: i64, offset: i64) -> Vec<f64> {
+        let first = ((self.domain_min() as i64 - offset) as f64 / interval as f64).ceil() as i64;
+        let mut res = Vec::new();
+        let mut tick = first
+            .checked_mul(interval)
+            .and_then(|tick| tick.checked_add(offset));
+        while let Some(value) = tick {
+            if value as f64 > self.domain_max() || res.len() >= MAX_GENERATED_TICK_COUNT {
+                break;
+            }
+            res.push(value as f64);
+            tick = value.checked_add(interval);
+        }
+
+        res
+    }
+
+    // Get ticks on the first day of months.
+    fn month_ticks(&self, months_step: i64) -> Vec<f64> {
+        let start = match get_date_time(self.domain_min()) {
+            Some(start) => start,
+            None => return Vec::new(),
+        };
+        let mut month = start.year() as i64 * 12 + start.month0() as i64;
+        month -= month % months_step;
+
+        let mut res = Vec::new();
+        while let Some(tick) = get_month_start(month) {
+            if tick > self.domain_max() || res.len() >= MAX_GENERATED_TICK_COUNT {
+                break;
+            }
+            if tick >= self.domain_min() {
+                res.push(tick);
+            }
+            month += months_step;
+        }
+
+        res
+    }
+}
+
+impl Scale<f64> for TimeScale {
+    fn scale(&self, domain: &f64) -> f32 {
+        let normalized = (*domain - self.domain_start) / (self.domain_end - self.domain_start);
+
+        ((self.range_end - self.range_start) as f64 * normalized + self.range_start as f64) as f32
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        match self.unit {
+            TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour => {
+                self.fixed_ticks(self.unit.seconds() * self.step, 0)
+            }
+            // Weeks are started on Monday.
+            TimeUnit::Day if self.step == 7 => {
+                self.fixed_ticks(7 * SECONDS_IN_DAY, -EPOCH_DAYS_FROM_MONDAY * SECONDS_IN_DAY)
+            }
+            TimeUnit::Day => self.fixed_ticks(self.step * SECONDS_IN_DAY, 0),
+            TimeUnit::Month => self.month_ticks(self.step),
+            TimeUnit::Year => self.month_ticks(self.step * 12),
+        }
+    }
+
+    fn kind(&self) -> ScaleKind {
+        ScaleKind::Linear
+    }
+
+    fn bandwidth(&self) -> f32 {
+        0_f32
+    }
+
+    fn is_range_reversed(&self) -> bool {
+        self.range_start > self.range_end
+    }
+
+    fn tick_offset(&self) -> f32 {
+        0_f32
+    }
+}
+
+// Get UTC date and time from seconds since the Unix epoch.
+fn get_date_time(time: f64) -> Option<DateTime<Utc>> {
+    let seconds = time.floor();
+    let nanos = ((time - seconds) * 1e9_f64) as u32;
+
+    Utc.timestamp_opt(seconds as i64, nanos).single()
+}
+
+// Get start of a month that is counted from the start of the year zero.
+fn get_month_start(month: i64) -> Option<f64> {
+    let date = NaiveDate::from_ymd_opt((month / 12) as i32, (month % 12) as u32 + 1, 1)?;
+
+    Some(
+        Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .timestamp() as f64,
+    )
+}
+
+// Get step in years for domains that are too long for yearly ticks.
+fn years_step(span: f64) -> i64 {
+    let years = span / SECONDS_IN_YEAR as f64 / MAX_TIME_TICK_COUNT;
+    let magnitude = 10_f64.powf(years.log10().floor());
+    let step = [1_f64, 2_f64, 5_f64, 10_f64]
+        .iter()
+        .map(|multiplier| multiplier * magnitude)
+        .find(|step| *step >= years)
+        .unwrap_or(years);
+
+    step.ceil().max(1_f64) as i64
+}
+
+/// Horizontal scale of views with points.
+#[derive(Clone)]
+pub(crate) enum PointsScale {
+    Band(BandScale),
+    Numeric(NumericScale),
+    Time(TimeScale),
+}
+
+impl PointsScale {
+    // Scale x coordinate of a point, band scale has only categories.
+    pub(crate) fn scale_value(&self, value: f64) -> Result<f32, RendererError> {
+        match self {
+            PointsScale::Band(_) => Err(RendererError::ExpectedScalarValues),
+            PointsScale::Numeric(scale) => scale.scale_value(value as f32),
+            PointsScale::Time(scale) => Ok(scale.scale(&value)),
+        }
+    }
+
+    // Get label of x coordinate of a point.
+    pub(crate) fn format_value(&self, value: f64) -> String {
+        match self {
+            PointsScale::Time(scale) => scale.format_time(value),
+            _ => (value as f32).to_string(),
+        }
+    }
+}
+
+/// Scale for numeric values of one of the supported kinds.
+#[derive(Clone)]
+pub(crate) enum NumericScale {
+    Linear(LinearScale),
+    Log(LogScale),
+}
+
+impl NumericScale {
+    // Scale a view value, logarithmic scale accepts only positive values.
+    pub(crate) fn scale_value(&self, value: f32) -> Result<f32, RendererError> {
+        match self {
+            NumericScale::Log(_) if value <= 0_f32 => {
+                Err(RendererError::LogScaleValueIsNotPositive)
+            }
+            _ => Ok(self.scale(&value)),
+        }
+    }
+
+    // Get scaled position that bars start from.
+    pub(crate) fn baseline(&self) -> f32 {
+        match self {
+            NumericScale::Linear(scale) => scale.scale(&0_f32),
+            NumericScale::Log(scale) => scale.scale(&scale.domain_min()),
+        }
+    }
+
+    pub(crate) fn range_start(&self) -> i32 {
+        match self {
+            NumericScale::Linear(scale) => scale.range_start(),
+            NumericScale::Log(scale) => scale.range_start,
+        }
+    }
+
+    pub(crate) fn range_end(&self) -> i32 {
+        match self {
+            NumericScale::Linear(scale) => scale.range_end(),
+            NumericScale::Log(scale) => scale.range_end,
+        }
+    }
+}
+
+impl Scale<f32> for NumericScale {
+    fn scale(&self, domain: &f32) -> f32 {
+        match self {
+            NumericScale::Linear(scale) => scale.scale(domain),
+            NumericScale::Log(scale) => scale.scale(domain),
+        }
+    }
+
+    fn ticks(&self) -> Vec<f32> {
+        match self {
+            NumericScale::Linear(scale) => scale.ticks(),
+            NumericScale::Log(scale) => scale.ticks(),
+        }
+    }
+
+    fn kind(&self) -> ScaleKind {
+        ScaleKind::Linear
+    }
+
+    fn bandwidth(&self) -> f32 {
+        0_f32
+    }
+
+    fn is_range_reversed(&self) -> bool {
+        self.range_start() > self.range_end()
+    }
+
+    fn tick_offset(&self) -> f32 {
+        0_f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_scale_basic() {
+        let scale = LogScale::new(10_f32, 1_f32, 1000_f32, 0, 300);
+
+        assert!((scale.scale(&1_f32) - 0_f32).abs() < 0.001);
+        assert!((scale.scale(&10_f32) - 100_f32).abs() < 0.001);
+        assert!((scale.scale(&1000_f32) - 300_f32).abs() < 0.001);
+        assert_eq!(vec![1_f32, 10_f32, 100_f32, 1000_f32], scale.ticks());
+    }
+
+    #[test]
+    fn log_scale_narrow_domain() {
+        let scale = LogScale::new(10_f32, 2_f32, 8_f32, 100, 0);
+
+        assert_eq!(
+            vec![2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32],
+            scale.ticks()
+        );
+        assert!(scale.is_range_reversed());
+    }
+
+    #[test]
+    fn numeric_scale_baseline() {
+        let linear = NumericScale::Linear(LinearScale::new(-10_f32, 10_f32, 0, 100));
+        let log = NumericScale::Log(LogScale::new(2_f32, 4_f32, 64_f32, 100, 0));
+
+        assert!((linear.baseline() - 50_f32).abs() < 0.001);
+        assert!((log.baseline() - 100_f32).abs() < 0.001);
+    }
+
+    #[test]
+    fn time_scale_hours() {
+        // 2021-03-01T00:00:00Z - 2021-03-01T09:00:00Z.
+        let scale = TimeScale::new(1614556800_f64, 1614589200_f64, 0, 700);
+
+        assert_eq!(TimeUnit::Hour, scale.unit);
+        assert_eq!(10, scale.ticks().len());
+        assert_eq!(1614556800_f64, scale.ticks()[0]);
+        assert!((scale.scale(&1614573000_f64) - 350_f32).abs() < 0.001);
+        assert_eq!("01:00", scale.format_time(scale.ticks()[1]));
+    }
+
+    #[test]
+    fn time_scale_months() {
+        // 2020-11-15T00:00:00Z - 2021-06-20T00:00:00Z.
+        let scale = TimeScale::new(1605398400_f64, 1624147200_f64, 0, 700).set_format("%Y-%m");
+        let labels: Vec<String> = scale
+            .ticks()
+            .iter()
+            .map(|tick| scale.format_time(*tick))
+            .collect();
+
+        assert_eq!(TimeUnit::Month, scale.unit);
+        assert_eq!(
+            vec!["2020-12", "2021-01", "2021-02", "2021-03", "2021-04", "2021-05", "2021-06"],
+            labels
+        );
+    }
+
+    #[test]
+    fn time_scale_years() {
+        // 1970-01-01T00:00:00Z - 2021-01-01T00:00:00Z.
+        let scale = TimeScale::new(0_f64, 1609459200_f64, 0, 700);
+        let labels: Vec<String> = scale
+            .ticks()
+            .iter()
+            .map(|tick| scale.format_time(*tick))
+            .collect();
+
+        assert_eq!(TimeUnit::Year, scale.unit);
+        assert_eq!(vec!["1970", "1980", "1990", "2000", "2010", "2020"], labels);
+    }
+
+    #[test]
+    fn time_format_validation() {
+        assert!(is_time_format_valid("%Y-%m-%d %H:%M"));
+        assert!(!is_time_format_valid("%Q"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn numeric_scale_value_err() {
+        let log = NumericScale::Log(LogScale::new(10_f32, 1_f32, 100_f32, 0, 100));
+
+        log.scale_value(0_f32).unwrap();
+    }
+}
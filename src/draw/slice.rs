@@ -0,0 +1,157 @@
+use crate::draw::*;
+use svg::Node;
+
+const DEFAULT_FONT_SIZE: &str = "14px";
+
+/// Slice shape of a pie or donut view with its label.
+/// Angles are in radians and counted clockwise from the top of the circle.
+#[derive(Clone)]
+pub(crate) struct Slice {
+    start_angle: f32,
+    end_angle: f32,
+    outer_radius: f32,
+    inner_radius: f32,
+    label: String,
+    fill_color: String,
+    stroke_color: String,
+    label_visible: bool,
+}
+
+impl Slice {
+    pub(crate) fn new(
+        start_angle: f32,
+        end_angle: f32,
+        outer_radius: f32,
+        inner_radius: f32,
+        label: &str,
+    ) -> Self {
+        Slice {
+            start_angle,
+            end_angle,
+            outer_radius,
+            inner_radius,
+            label: label.to_string(),
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            label_visible: true,
+        }
+    }
+
+    pub(crate) fn set_fill_color(mut self, fill_color: &str) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: &str) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_label_visible(mut self, label_visible: bool) -> Self {
+        self.label_visible = label_visible;
+        self
+    }
+
+    // Get SVG path of the slice.
+    // Every arc is split in the middle so a slice of the whole circle can be drawn too.
+    fn path_data(&self) -> svg::node::element::path::Data {
+        let middle_angle = (self.start_angle + self.end_angle) / 2_f32;
+        let outer = self.outer_radius;
+        let inner = self.inner_radius;
+
+        let mut data = svg::node::element::path::Data::new()
+            .move_to(polar_x_y(outer, self.start_angle))
+            .elliptical_arc_to(arc(outer, middle_angle, true))
+            .elliptical_arc_to(arc(outer, self.end_angle, true));
+        if inner > 0_f32 {
+            data = data
+                .line_to(polar_x_y(inner, self.end_angle))
+                .elliptical_arc_to(arc(inner, middle_angle, false))
+                .elliptical_arc_to(arc(inner, self.start_angle, false));
+        } else {
+            data = data.line_to((START, START));
+        }
+
+        data.close()
+    }
+
+    pub(crate) fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_SLICE);
+        res.append(
+            svg::node::element::Path::new()
+                .set(FILL_ATTR, self.fill_color.as_str())
+                .set(STROKE_ATTR, self.stroke_color.as_str())
+                .set(STROKE_WIDTH_ATTR, DEFAULT_STROKE_WIDTH)
+                .set(D_ATTR, self.path_data()),
+        );
+
+        if !self.label_visible {
+            return res;
+        }
+
+        let (label_x, label_y) = polar_x_y(
+            (self.outer_radius + self.inner_radius) / 2_f32,
+            (self.start_angle + self.end_angle) / 2_f32,
+        );
+        res.append(
+            svg::node::element::Text::new()
+                .set(X_ATTR, label_x)
+                .set(Y_ATTR, label_y)
+                .set(TEXT_ANCHOR_ATTR, TEXT_ANCHOR_MIDDLE)
+                .set(DY_ATTR, DEFAULT_DY)
+                .set(FONT_FAMILY_ATTR, DEFAULT_FONT_FAMILY)
+                .set(FILL_ATTR, DEFAULT_FONT_COLOR)
+                .set(FONT_SIZE_ATTR, DEFAULT_FONT_SIZE)
+                .add(svg::node::Text::new(self.label.as_str())),
+        );
+
+        res
+    }
+}
+
+// Get point on a circle with the center at the origin.
+fn polar_x_y(radius: f32, angle: f32) -> (f32, f32) {
+    (radius * angle.sin(), -radius * angle.cos())
+}
+
+// Get parameters of an arc that is not larger than a half of a circle.
+fn arc(radius: f32, end_angle: f32, clockwise: bool) -> (f32, f32, i32, i32, i32, f32, f32) {
+    let (x, y) = polar_x_y(radius, end_angle);
+
+    (radius, radius, 0, 0, clockwise as i32, x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn slice_basic() {
+        let expected_svg_group = r##"<g class="slice">
+<path d="M0,-100 A100,100,0,0,1,70.71068,-70.71068 A100,100,0,0,1,100,0.000004371139 L0,0 z" fill="#f289ff" stroke="#ffffff" stroke-width="1"/>
+<text dy=".35em" fill="#080808" font-family="sans-serif" font-size="14px" text-anchor="middle" x="35.35534" y="-35.35534">
+a 25%
+</text>
+</g>"##;
+
+        let slice_svg = Slice::new(0_f32, PI / 2_f32, 100_f32, 0_f32, "a 25%")
+            .set_fill_color("#f289ff")
+            .set_stroke_color("#ffffff")
+            .to_svg();
+
+        assert_eq!(expected_svg_group, slice_svg.to_string());
+    }
+
+    #[test]
+    fn slice_donut() {
+        let slice_svg = Slice::new(0_f32, 2_f32 * PI, 100_f32, 50_f32, "")
+            .set_label_visible(false)
+            .to_svg()
+            .to_string();
+
+        assert!(slice_svg.contains("A100,100,0,0,1,"));
+        assert!(slice_svg.contains("A50,50,0,0,0,"));
+        assert!(!slice_svg.contains("<text"));
+    }
+}
@@ -0,0 +1,1213 @@
+use crate::draw::axis::AxisPosition;
+use crate::draw::bar::Bar;
+use crate::draw::interval::{get_band_svg, ErrorBar, Interval};
+use crate::draw::point::Point;
+use crate::draw::scale::{NumericScale, PointsScale};
+use crate::draw::slice::Slice;
+use crate::draw::*;
+use crate::error::RendererError;
+use lc_render::{
+    BandScale, BarLabelPosition, BarsValues, Color, Orientation, PointLabelPosition, PointType,
+    Scale, View,
+};
+use std::cmp::Ordering;
+use std::f32::consts::PI;
+use svg::Node;
+
+const DEFAULT_LINE_STROKE_WIDTH: i32 = 2;
+
+// Points values with lower and upper bounds of their y values.
+type PointsWithBounds = (Vec<(f64, f32)>, Vec<(f32, f32)>);
+
+// Get offset of a tick in the middle of a band.
+fn bandwidth_offset<T>(scale: &dyn Scale<T>) -> f32 {
+    if scale.is_range_reversed() {
+        return -scale.tick_offset();
+    }
+
+    scale.tick_offset()
+}
+
+// Options of points drawn by views with points.
+#[derive(Clone)]
+struct PointsOptions {
+    fill_color: String,
+    stroke_color: String,
+    point_type: PointType,
+    visible: bool,
+    label_visible: bool,
+    label_position: PointLabelPosition,
+}
+
+impl Default for PointsOptions {
+    fn default() -> Self {
+        PointsOptions {
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            point_type: PointType::Circle,
+            visible: true,
+            label_visible: true,
+            label_position: PointLabelPosition::Top,
+        }
+    }
+}
+
+impl PointsOptions {
+    fn point(&self, x: f32, y: f32, label: &str) -> Point {
+        Point::new(
+            x,
+            y,
+            self.point_type,
+            label,
+            &self.fill_color,
+            &self.stroke_color,
+        )
+        .set_point_visible(self.visible)
+        .set_label_visible(self.label_visible)
+        .set_label_position(self.label_position)
+    }
+}
+
+// Get points for scalar values of categories of a band scale.
+fn get_category_points(
+    x_scale: &PointsScale,
+    y_scale: &NumericScale,
+    options: &PointsOptions,
+    data: &[f32],
+) -> Result<Vec<Point>, RendererError> {
+    get_labeled_category_points(x_scale, y_scale, options, data, data)
+}
+
+// Get points for categories of a band scale that are labeled with another values.
+fn get_labeled_category_points(
+    x_scale: &PointsScale,
+    y_scale: &NumericScale,
+    options: &PointsOptions,
+    data: &[f32],
+    labels: &[f32],
+) -> Result<Vec<Point>, RendererError> {
+    let x_scale = match x_scale {
+        PointsScale::Band(x_scale) => x_scale,
+        _ => return Err(RendererError::ExpectedPointsValues),
+    };
+    if data.is_empty() {
+        return Err(lc_render::Error::DataIsEmpty.into());
+    }
+    let categories = x_scale.ticks();
+    if data.len() != categories.len() {
+        return Err(lc_render::Error::CategoriesCountDoesntEqual.into());
+    }
+
+    let x_offset = bandwidth_offset(x_scale);
+    let mut points = Vec::with_capacity(data.len());
+    for ((category, value), label) in categories.iter().zip(data.iter()).zip(labels.iter()) {
+        points.push(options.point(
+            x_scale.scale(category) + x_offset,
+            y_scale.scale_value(*value)?,
+            &label.to_string(),
+        ));
+    }
+
+    Ok(points)
+}
+
+// Get points for x and y values, points of lines and areas are sorted by x.
+fn get_xy_points(
+    x_scale: &PointsScale,
+    y_scale: &NumericScale,
+    options: &PointsOptions,
+    data: &[(f64, f32)],
+    with_x_labels: bool,
+) -> Result<Vec<Point>, RendererError> {
+    if data.is_empty() {
+        return Err(lc_render::Error::DataIsEmpty.into());
+    }
+
+    let mut points = Vec::with_capacity(data.len());
+    for (x, y) in data.iter() {
+        let mut point = options.point(
+            x_scale.scale_value(*x)?,
+            y_scale.scale_value(*y)?,
+            &y.to_string(),
+        );
+        if with_x_labels {
+            point = point.set_x_label(&x_scale.format_value(*x));
+        }
+        points.push(point);
+    }
+
+    Ok(points)
+}
+
+// Sort points values by x with their bounds so a path connects them from left to right.
+fn sort_by_x(data: &[(f64, f32)], bounds: &[(f32, f32)]) -> PointsWithBounds {
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    order.sort_by(|a, b| {
+        data[*a]
+            .0
+            .partial_cmp(&data[*b].0)
+            .unwrap_or(Ordering::Equal)
+    });
+    let sorted_data = order.iter().map(|idx| data[*idx]).collect();
+    let sorted_bounds = order
+        .iter()
+        .filter_map(|idx| bounds.get(*idx).copied())
+        .collect();
+
+    (sorted_data, sorted_bounds)
+}
+
+// Get intervals of values with scaled lower and upper bounds at x coordinates of their points.
+// Bounds are empty for values without intervals.
+fn get_intervals(
+    points: &[Point],
+    y_scale: &NumericScale,
+    bounds: &[(f32, f32)],
+) -> Result<Vec<Interval>, RendererError> {
+    let mut intervals = Vec::with_capacity(bounds.len());
+    for (point, (lower, upper)) in points.iter().zip(bounds.iter()) {
+        intervals.push(Interval::new(
+            point.x(),
+            y_scale.scale_value(*lower)?,
+            y_scale.scale_value(*upper)?,
+        ));
+    }
+
+    Ok(intervals)
+}
+
+// Get percentage of the total rounded to tenths.
+fn get_percentage(value: f32, total: f32) -> f32 {
+    (value / total * 1000_f32).round() / 10_f32
+}
+
+// Get SVG path that connects points.
+fn get_path_data(points: &[Point]) -> svg::node::element::path::Data {
+    let mut data = svg::node::element::path::Data::new();
+    for (idx, point) in points.iter().enumerate() {
+        if idx == 0 {
+            data = data.move_to((point.x(), point.y()));
+        } else {
+            data = data.line_to((point.x(), point.y()));
+        }
+    }
+
+    data
+}
+
+/// View that represents area on a numeric vertical scale.
+#[derive(Clone)]
+pub(crate) struct AreaView {
+    x_scale: PointsScale,
+    y_scale: NumericScale,
+    fill_color: String,
+    stroke_color: String,
+    points_options: PointsOptions,
+    stack_group: String,
+    stack_normalized: bool,
+    stack_axes: (AxisPosition, AxisPosition),
+    points: Vec<Point>,
+    baseline: Vec<(f32, f32)>,
+    intervals: Vec<Interval>,
+}
+
+impl AreaView {
+    pub(crate) fn new(x_scale: PointsScale, y_scale: NumericScale) -> Self {
+        AreaView {
+            x_scale,
+            y_scale,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            points_options: PointsOptions::default(),
+            stack_group: String::new(),
+            stack_normalized: false,
+            stack_axes: (AxisPosition::Bottom, AxisPosition::Left),
+            points: Vec::new(),
+            baseline: Vec::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
+        self.points_options.fill_color = point_fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
+        self.points_options.stroke_color = point_stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_type(mut self, point_type: PointType) -> Self {
+        self.points_options.point_type = point_type;
+        self
+    }
+
+    pub(crate) fn set_point_visible(mut self, point_visible: bool) -> Self {
+        self.points_options.visible = point_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_visible(mut self, point_label_visible: bool) -> Self {
+        self.points_options.label_visible = point_label_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_position(
+        mut self,
+        point_label_position: PointLabelPosition,
+    ) -> Self {
+        self.points_options.label_position = point_label_position;
+        self
+    }
+
+    pub(crate) fn set_stack_group(mut self, stack_group: &str) -> Self {
+        self.stack_group = stack_group.to_string();
+        self
+    }
+
+    pub(crate) fn set_stack_normalized(mut self, stack_normalized: bool) -> Self {
+        self.stack_normalized = stack_normalized;
+        self
+    }
+
+    // Set horizontal and vertical axes the area is drawn on, areas are stacked only on the same axes.
+    pub(crate) fn set_stack_axes(mut self, h_axis: AxisPosition, v_axis: AxisPosition) -> Self {
+        self.stack_axes = (h_axis, v_axis);
+        self
+    }
+
+    pub(crate) fn x_scale(&self) -> &PointsScale {
+        &self.x_scale
+    }
+
+    pub(crate) fn stack_group(&self) -> &str {
+        &self.stack_group
+    }
+
+    pub(crate) fn stack_normalized(&self) -> bool {
+        self.stack_normalized
+    }
+
+    pub(crate) fn stack_axes(&self) -> (AxisPosition, AxisPosition) {
+        self.stack_axes
+    }
+
+    pub(crate) fn set_data(
+        mut self,
+        data: &[f32],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        self.points =
+            get_category_points(&self.x_scale, &self.y_scale, &self.points_options, data)?;
+        self.intervals = get_intervals(&self.points, &self.y_scale, bounds)?;
+
+        Ok(self)
+    }
+
+    // Set data of an area that is stacked over the bottom values.
+    // Area is drawn between bottom and top values, points are labeled with the own data values.
+    // Bounds are stacked over the bottom values the same way as the data values.
+    pub(crate) fn set_stacked_data(
+        mut self,
+        data: &[f32],
+        bottom: &[f32],
+        top: &[f32],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        self.points = get_labeled_category_points(
+            &self.x_scale,
+            &self.y_scale,
+            &self.points_options,
+            top,
+            data,
+        )?;
+        let mut baseline = Vec::with_capacity(bottom.len());
+        for (point, value) in self.points.iter().zip(bottom.iter()) {
+            baseline.push((point.x(), self.y_scale.scale_value(*value)?));
+        }
+        self.baseline = baseline;
+
+        // Values of normalized groups are scaled to percentages, so are their bounds.
+        let mut stacked_bounds = Vec::with_capacity(bounds.len());
+        for (((lower, upper), value), (start, end)) in bounds
+            .iter()
+            .zip(data.iter())
+            .zip(bottom.iter().zip(top.iter()))
+        {
+            let factor = if *value != 0_f32 {
+                (end - start) / value
+            } else {
+                1_f32
+            };
+            stacked_bounds.push((start + lower * factor, start + upper * factor));
+        }
+        self.intervals = get_intervals(&self.points, &self.y_scale, &stacked_bounds)?;
+
+        Ok(self)
+    }
+
+    pub(crate) fn set_points_data(
+        mut self,
+        data: &[(f64, f32)],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        let (data, bounds) = sort_by_x(data, bounds);
+        self.points = get_xy_points(
+            &self.x_scale,
+            &self.y_scale,
+            &self.points_options,
+            &data,
+            false,
+        )?;
+        self.intervals = get_intervals(&self.points, &self.y_scale, &bounds)?;
+
+        Ok(self)
+    }
+}
+
+impl View for AreaView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        // Area is closed at the bottom of the vertical scale.
+        let y_origin = if self.y_scale.is_range_reversed() {
+            self.y_scale.range_start()
+        } else {
+            self.y_scale.range_end()
+        } as f32;
+        let mut data = get_path_data(&self.points);
+        if !self.baseline.is_empty() {
+            // Stacked area is closed along its baseline in the reverse order.
+            for (x, y) in self.baseline.iter().rev() {
+                data = data.line_to((*x, *y));
+            }
+            data = data.close();
+        } else if let (Some(first), Some(last)) = (self.points.first(), self.points.last()) {
+            data = data
+                .line_to((last.x(), y_origin))
+                .line_to((first.x(), y_origin))
+                .close();
+        }
+
+        let mut area = svg::node::element::Group::new().set(CLASS_ATTR, CLASS_AREA);
+        area.append(
+            svg::node::element::Path::new()
+                .set(FILL_ATTR, self.fill_color.as_str())
+                .set(STROKE_ATTR, self.stroke_color.as_str())
+                .set(D_ATTR, data),
+        );
+        if !self.intervals.is_empty() {
+            area.append(get_band_svg(&self.intervals, &self.stroke_color));
+        }
+        for point in self.points.iter() {
+            area.append(point.to_svg());
+        }
+
+        svg::node::element::Group::new().add(area)
+    }
+}
+
+/// View that represents line on a numeric vertical scale.
+#[derive(Clone)]
+pub(crate) struct LineView {
+    x_scale: PointsScale,
+    y_scale: NumericScale,
+    stroke_color: String,
+    points_options: PointsOptions,
+    points: Vec<Point>,
+    intervals: Vec<Interval>,
+}
+
+impl LineView {
+    pub(crate) fn new(x_scale: PointsScale, y_scale: NumericScale) -> Self {
+        LineView {
+            x_scale,
+            y_scale,
+            stroke_color: String::new(),
+            points_options: PointsOptions::default(),
+            points: Vec::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
+        self.points_options.fill_color = point_fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
+        self.points_options.stroke_color = point_stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_type(mut self, point_type: PointType) -> Self {
+        self.points_options.point_type = point_type;
+        self
+    }
+
+    pub(crate) fn set_point_visible(mut self, point_visible: bool) -> Self {
+        self.points_options.visible = point_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_visible(mut self, point_label_visible: bool) -> Self {
+        self.points_options.label_visible = point_label_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_position(
+        mut self,
+        point_label_position: PointLabelPosition,
+    ) -> Self {
+        self.points_options.label_position = point_label_position;
+        self
+    }
+
+    pub(crate) fn x_scale(&self) -> &PointsScale {
+        &self.x_scale
+    }
+
+    pub(crate) fn set_data(
+        mut self,
+        data: &[f32],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        self.points =
+            get_category_points(&self.x_scale, &self.y_scale, &self.points_options, data)?;
+        self.intervals = get_intervals(&self.points, &self.y_scale, bounds)?;
+
+        Ok(self)
+    }
+
+    pub(crate) fn set_points_data(
+        mut self,
+        data: &[(f64, f32)],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        let (data, bounds) = sort_by_x(data, bounds);
+        self.points = get_xy_points(
+            &self.x_scale,
+            &self.y_scale,
+            &self.points_options,
+            &data,
+            false,
+        )?;
+        self.intervals = get_intervals(&self.points, &self.y_scale, &bounds)?;
+
+        Ok(self)
+    }
+}
+
+impl View for LineView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        if !self.intervals.is_empty() {
+            res.append(get_band_svg(&self.intervals, &self.stroke_color));
+        }
+        for point in self.points.iter() {
+            res.append(point.to_svg());
+        }
+        res.append(
+            svg::node::element::Path::new()
+                .set(CLASS_ATTR, CLASS_LINE)
+                .set(FILL_ATTR, FILL_NONE)
+                .set(STROKE_ATTR, self.stroke_color.as_str())
+                .set(STROKE_WIDTH_ATTR, DEFAULT_LINE_STROKE_WIDTH)
+                .set(D_ATTR, get_path_data(&self.points)),
+        );
+
+        res
+    }
+}
+
+/// View that represents scatter points on numeric scales.
+#[derive(Clone)]
+pub(crate) struct ScatterView {
+    x_scale: PointsScale,
+    y_scale: NumericScale,
+    points_options: PointsOptions,
+    points: Vec<Point>,
+    intervals: Vec<Interval>,
+}
+
+impl ScatterView {
+    pub(crate) fn new(x_scale: PointsScale, y_scale: NumericScale) -> Self {
+        ScatterView {
+            x_scale,
+            y_scale,
+            points_options: PointsOptions::default(),
+            points: Vec::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_point_fill_color(mut self, point_fill_color: Color) -> Self {
+        self.points_options.fill_color = point_fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_stroke_color(mut self, point_stroke_color: Color) -> Self {
+        self.points_options.stroke_color = point_stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_point_type(mut self, point_type: PointType) -> Self {
+        self.points_options.point_type = point_type;
+        self
+    }
+
+    pub(crate) fn set_point_visible(mut self, point_visible: bool) -> Self {
+        self.points_options.visible = point_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_visible(mut self, point_label_visible: bool) -> Self {
+        self.points_options.label_visible = point_label_visible;
+        self
+    }
+
+    pub(crate) fn set_point_label_position(
+        mut self,
+        point_label_position: PointLabelPosition,
+    ) -> Self {
+        self.points_options.label_position = point_label_position;
+        self
+    }
+
+    pub(crate) fn x_scale(&self) -> &PointsScale {
+        &self.x_scale
+    }
+
+    pub(crate) fn set_data(
+        mut self,
+        data: &[(f64, f32)],
+        bounds: &[(f32, f32)],
+    ) -> Result<Self, RendererError> {
+        self.points = get_xy_points(
+            &self.x_scale,
+            &self.y_scale,
+            &self.points_options,
+            data,
+            true,
+        )?;
+        self.intervals = get_intervals(&self.points, &self.y_scale, bounds)?;
+
+        Ok(self)
+    }
+}
+
+impl View for ScatterView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        // Error bars are drawn under the points with their stroke color.
+        for interval in self.intervals.iter() {
+            res.append(
+                ErrorBar::new(*interval, Orientation::Vertical)
+                    .set_stroke_color(&self.points_options.stroke_color)
+                    .to_svg(),
+            );
+        }
+        for point in self.points.iter() {
+            res.append(point.to_svg());
+        }
+
+        res
+    }
+}
+
+/// Layout of bars datasets of a view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum BarLayout {
+    Stacked,
+    Grouped,
+    PercentStacked,
+}
+
+// Options of bars drawn by views with bars.
+#[derive(Clone)]
+struct BarsOptions {
+    label_visible: bool,
+    label_position: BarLabelPosition,
+    layout: BarLayout,
+}
+
+impl Default for BarsOptions {
+    fn default() -> Self {
+        BarsOptions {
+            label_visible: true,
+            label_position: BarLabelPosition::Center,
+            layout: BarLayout::Stacked,
+        }
+    }
+}
+
+impl BarsOptions {
+    // Set styles of a dataset bar.
+    fn style(&self, bar: Bar, dataset: &BarsValues) -> Bar {
+        bar.set_fill_color(dataset.fill_color())
+            .set_stroke_color(dataset.stroke_color())
+            .set_label_visible(self.label_visible)
+            .set_label_position(self.label_position)
+    }
+}
+
+// Bars of a view with error bars of datasets that have bounds.
+type Bars = (Vec<Bar>, Vec<ErrorBar>);
+
+// Get error bar in the middle of a bar with bounds that are already stacked.
+fn get_error_bar(
+    value_scale: &NumericScale,
+    orientation: Orientation,
+    dataset: &BarsValues,
+    offset: f32,
+    width: f32,
+    lower: f32,
+    upper: f32,
+) -> Result<ErrorBar, RendererError> {
+    let interval = Interval::new(
+        offset + width / 2_f32,
+        value_scale.scale_value(lower)?,
+        value_scale.scale_value(upper)?,
+    );
+
+    Ok(ErrorBar::new(interval, orientation)
+        .set_max_cap_width(width / 2_f32)
+        .set_stroke_color(dataset.stroke_color()))
+}
+
+// Get bars of every dataset with the layout from options.
+// Every dataset should have a value for every category, bounds of datasets are optional.
+fn get_bars(
+    category_scale: &BandScale,
+    value_scale: &NumericScale,
+    options: &BarsOptions,
+    orientation: Orientation,
+    bars_values: &[BarsValues],
+    bounds: &[Vec<(f32, f32)>],
+) -> Result<Bars, RendererError> {
+    if bars_values.is_empty() {
+        return Err(lc_render::Error::DataIsEmpty.into());
+    }
+    let categories = category_scale.ticks();
+    if bars_values
+        .iter()
+        .any(|bars| bars.values().len() != categories.len())
+    {
+        return Err(RendererError::BarsDatasetValuesCountIsInvalid);
+    }
+
+    match options.layout {
+        BarLayout::Stacked | BarLayout::PercentStacked => get_stacked_bars(
+            category_scale,
+            value_scale,
+            options,
+            orientation,
+            bars_values,
+            bounds,
+        ),
+        BarLayout::Grouped => get_grouped_bars(
+            category_scale,
+            value_scale,
+            options,
+            orientation,
+            bars_values,
+            bounds,
+        ),
+    }
+}
+
+// Get bars stacked from the baseline of the value scale for every category.
+// Values are replaced with their percentage of the category total for percent layout.
+// Bounds are stacked over the previous bars the same way as the values.
+fn get_stacked_bars(
+    category_scale: &BandScale,
+    value_scale: &NumericScale,
+    options: &BarsOptions,
+    orientation: Orientation,
+    bars_values: &[BarsValues],
+    bounds: &[Vec<(f32, f32)>],
+) -> Result<Bars, RendererError> {
+    let mut bars = Vec::new();
+    let mut error_bars = Vec::new();
+    for (idx, category) in category_scale.ticks().iter().enumerate() {
+        let total: f32 = bars_values.iter().map(|bars| bars.values()[idx]).sum();
+        let mut value_acc = 0_f32;
+        let mut start = value_scale.baseline();
+        let mut end = start;
+
+        for (dataset_idx, dataset) in bars_values.iter().enumerate() {
+            let value = dataset.values()[idx];
+            let (factor, label) = match options.layout {
+                BarLayout::PercentStacked if total != 0_f32 => (
+                    100_f32 / total,
+                    format!("{}%", get_percentage(value, total)),
+                ),
+                _ => (1_f32, value.to_string()),
+            };
+            if let Some((lower, upper)) = bounds.get(dataset_idx).and_then(|b| b.get(idx)) {
+                error_bars.push(get_error_bar(
+                    value_scale,
+                    orientation,
+                    dataset,
+                    category_scale.scale(category),
+                    category_scale.bandwidth(),
+                    value_acc + lower * factor,
+                    value_acc + upper * factor,
+                )?);
+            }
+            value_acc += value * factor;
+            let scaled = value_scale.scale_value(value_acc)?;
+            if value_scale.is_range_reversed() {
+                end = start;
+                start = scaled;
+            } else {
+                start = end;
+                end = scaled;
+            }
+
+            let bar = Bar::new(
+                start,
+                end,
+                &label,
+                category_scale.bandwidth(),
+                category_scale.scale(category),
+                orientation,
+            );
+            bars.push(options.style(bar, dataset));
+        }
+    }
+
+    Ok((bars, error_bars))
+}
+
+// Get bars of datasets side by side in equal parts of every category band.
+fn get_grouped_bars(
+    category_scale: &BandScale,
+    value_scale: &NumericScale,
+    options: &BarsOptions,
+    orientation: Orientation,
+    bars_values: &[BarsValues],
+    bounds: &[Vec<(f32, f32)>],
+) -> Result<Bars, RendererError> {
+    let width = category_scale.bandwidth() / bars_values.len() as f32;
+    let baseline = value_scale.baseline();
+
+    let mut bars = Vec::new();
+    let mut error_bars = Vec::new();
+    for (idx, category) in category_scale.ticks().iter().enumerate() {
+        for (dataset_idx, dataset) in bars_values.iter().enumerate() {
+            let value = dataset.values()[idx];
+            let scaled = value_scale.scale_value(value)?;
+            let offset = category_scale.scale(category) + width * dataset_idx as f32;
+
+            let bar = Bar::new(
+                baseline.min(scaled),
+                baseline.max(scaled),
+                &value.to_string(),
+                width,
+                offset,
+                orientation,
+            );
+            bars.push(options.style(bar, dataset));
+            if let Some((lower, upper)) = bounds.get(dataset_idx).and_then(|b| b.get(idx)) {
+                error_bars.push(get_error_bar(
+                    value_scale,
+                    orientation,
+                    dataset,
+                    offset,
+                    width,
+                    *lower,
+                    *upper,
+                )?);
+            }
+        }
+    }
+
+    Ok((bars, error_bars))
+}
+
+/// View that represents vertical bars on a numeric vertical scale.
+#[derive(Clone)]
+pub(crate) struct VerticalBarView {
+    x_scale: BandScale,
+    y_scale: NumericScale,
+    bars_options: BarsOptions,
+    bars: Vec<Bar>,
+    error_bars: Vec<ErrorBar>,
+}
+
+impl VerticalBarView {
+    pub(crate) fn new(x_scale: BandScale, y_scale: NumericScale) -> Self {
+        VerticalBarView {
+            x_scale,
+            y_scale,
+            bars_options: BarsOptions::default(),
+            bars: Vec::new(),
+            error_bars: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_bar_label_visible(mut self, bar_label_visible: bool) -> Self {
+        self.bars_options.label_visible = bar_label_visible;
+        self
+    }
+
+    pub(crate) fn set_bar_label_position(mut self, bar_label_position: BarLabelPosition) -> Self {
+        self.bars_options.label_position = bar_label_position;
+        self
+    }
+
+    pub(crate) fn set_bar_layout(mut self, bar_layout: BarLayout) -> Self {
+        self.bars_options.layout = bar_layout;
+        self
+    }
+
+    pub(crate) fn set_data(
+        mut self,
+        bars_values: &[BarsValues],
+        bounds: &[Vec<(f32, f32)>],
+    ) -> Result<Self, RendererError> {
+        let (bars, error_bars) = get_bars(
+            &self.x_scale,
+            &self.y_scale,
+            &self.bars_options,
+            Orientation::Vertical,
+            bars_values,
+            bounds,
+        )?;
+        self.bars = bars;
+        self.error_bars = error_bars;
+
+        Ok(self)
+    }
+}
+
+impl View for VerticalBarView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        for bar in self.bars.iter() {
+            res.append(bar.to_svg());
+        }
+        for error_bar in self.error_bars.iter() {
+            res.append(error_bar.to_svg());
+        }
+
+        res
+    }
+}
+
+/// View that represents horizontal bars on a numeric horizontal scale.
+#[derive(Clone)]
+pub(crate) struct HorizontalBarView {
+    x_scale: NumericScale,
+    y_scale: BandScale,
+    bars_options: BarsOptions,
+    bars: Vec<Bar>,
+    error_bars: Vec<ErrorBar>,
+}
+
+impl HorizontalBarView {
+    pub(crate) fn new(x_scale: NumericScale, y_scale: BandScale) -> Self {
+        HorizontalBarView {
+            x_scale,
+            y_scale,
+            bars_options: BarsOptions::default(),
+            bars: Vec::new(),
+            error_bars: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_bar_label_visible(mut self, bar_label_visible: bool) -> Self {
+        self.bars_options.label_visible = bar_label_visible;
+        self
+    }
+
+    pub(crate) fn set_bar_label_position(mut self, bar_label_position: BarLabelPosition) -> Self {
+        self.bars_options.label_position = bar_label_position;
+        self
+    }
+
+    pub(crate) fn set_bar_layout(mut self, bar_layout: BarLayout) -> Self {
+        self.bars_options.layout = bar_layout;
+        self
+    }
+
+    pub(crate) fn set_data(
+        mut self,
+        bars_values: &[BarsValues],
+        bounds: &[Vec<(f32, f32)>],
+    ) -> Result<Self, RendererError> {
+        let (bars, error_bars) = get_bars(
+            &self.y_scale,
+            &self.x_scale,
+            &self.bars_options,
+            Orientation::Horizontal,
+            bars_values,
+            bounds,
+        )?;
+        self.bars = bars;
+        self.error_bars = error_bars;
+
+        Ok(self)
+    }
+}
+
+impl View for HorizontalBarView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        for bar in self.bars.iter() {
+            res.append(bar.to_svg());
+        }
+        for error_bar in self.error_bars.iter() {
+            res.append(error_bar.to_svg());
+        }
+
+        res
+    }
+}
+
+/// Bin of a histogram view with its edges and height.
+/// Height is either a count of samples or a probability density of the bin.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct HistogramBin {
+    pub(crate) start: f32,
+    pub(crate) end: f32,
+    pub(crate) value: f32,
+}
+
+// Get bar label of a histogram bin, densities are rounded to 3 decimals.
+fn get_bin_label(value: f32) -> String {
+    ((value * 1000_f32).round() / 1000_f32).to_string()
+}
+
+/// View that represents histogram bins as adjacent vertical bars on a numeric horizontal scale.
+#[derive(Clone)]
+pub(crate) struct HistogramView {
+    x_scale: NumericScale,
+    y_scale: NumericScale,
+    fill_color: String,
+    stroke_color: String,
+    bar_label_visible: bool,
+    bar_label_position: BarLabelPosition,
+    bars: Vec<Bar>,
+}
+
+impl HistogramView {
+    pub(crate) fn new(x_scale: NumericScale, y_scale: NumericScale) -> Self {
+        HistogramView {
+            x_scale,
+            y_scale,
+            fill_color: String::new(),
+            stroke_color: String::new(),
+            bar_label_visible: true,
+            bar_label_position: BarLabelPosition::Center,
+            bars: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = fill_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_bar_label_visible(mut self, bar_label_visible: bool) -> Self {
+        self.bar_label_visible = bar_label_visible;
+        self
+    }
+
+    pub(crate) fn set_bar_label_position(mut self, bar_label_position: BarLabelPosition) -> Self {
+        self.bar_label_position = bar_label_position;
+        self
+    }
+
+    // Set bars that span edges of bins along the horizontal scale.
+    // Empty bins are not drawn, so they don't break logarithmic vertical scales.
+    pub(crate) fn set_data(mut self, bins: &[HistogramBin]) -> Result<Self, RendererError> {
+        if bins.is_empty() {
+            return Err(lc_render::Error::DataIsEmpty.into());
+        }
+        let baseline = self.y_scale.baseline();
+
+        let mut bars = Vec::with_capacity(bins.len());
+        for bin in bins.iter().filter(|bin| bin.value > 0_f32) {
+            let start = self.x_scale.scale_value(bin.start)?;
+            let end = self.x_scale.scale_value(bin.end)?;
+            let scaled = self.y_scale.scale_value(bin.value)?;
+
+            let bar = Bar::new(
+                baseline.min(scaled),
+                baseline.max(scaled),
+                &get_bin_label(bin.value),
+                (end - start).abs(),
+                start.min(end),
+                Orientation::Vertical,
+            )
+            .set_fill_color(&self.fill_color)
+            .set_stroke_color(&self.stroke_color)
+            .set_label_visible(self.bar_label_visible)
+            .set_label_position(self.bar_label_position);
+            bars.push(bar);
+        }
+        self.bars = bars;
+
+        Ok(self)
+    }
+}
+
+impl View for HistogramView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new();
+        for bar in self.bars.iter() {
+            res.append(bar.to_svg());
+        }
+
+        res
+    }
+}
+
+/// Value of a single slice of a pie or donut view.
+#[derive(Clone)]
+pub(crate) struct SliceValue {
+    label: String,
+    value: f32,
+    fill_color: String,
+}
+
+impl SliceValue {
+    pub(crate) fn new(label: &str, value: f32, fill_color: Color) -> Self {
+        SliceValue {
+            label: label.to_string(),
+            value,
+            fill_color: fill_color.to_string(),
+        }
+    }
+}
+
+// Get slice label with its percentage of the total.
+fn get_slice_label(label: &str, value: f32, total: f32) -> String {
+    let percentage = get_percentage(value, total);
+    if label.is_empty() {
+        return format!("{}%", percentage);
+    }
+
+    format!("{} {}%", label, percentage)
+}
+
+/// View that represents slices of a pie or a donut, it doesn't use scales.
+#[derive(Clone)]
+pub(crate) struct PieView {
+    center_x: f32,
+    center_y: f32,
+    radius: f32,
+    inner_radius: f32,
+    start_angle: f32,
+    stroke_color: String,
+    slice_label_visible: bool,
+    slices: Vec<Slice>,
+}
+
+impl PieView {
+    // Create a pie in the center of the views area.
+    pub(crate) fn new(view_width: i32, view_height: i32) -> Self {
+        PieView {
+            center_x: view_width as f32 / 2_f32,
+            center_y: view_height as f32 / 2_f32,
+            radius: view_width.min(view_height) as f32 / 2_f32,
+            inner_radius: 0_f32,
+            start_angle: 0_f32,
+            stroke_color: String::new(),
+            slice_label_visible: true,
+            slices: Vec::new(),
+        }
+    }
+
+    // Set inner radius as a fraction of the outer radius.
+    pub(crate) fn set_inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    // Set start angle of the first slice in degrees.
+    pub(crate) fn set_start_angle(mut self, start_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    pub(crate) fn set_stroke_color(mut self, stroke_color: Color) -> Self {
+        self.stroke_color = stroke_color.to_string();
+        self
+    }
+
+    pub(crate) fn set_slice_label_visible(mut self, slice_label_visible: bool) -> Self {
+        self.slice_label_visible = slice_label_visible;
+        self
+    }
+
+    pub(crate) fn set_data(mut self, slices_values: &[SliceValue]) -> Result<Self, RendererError> {
+        if slices_values.is_empty() {
+            return Err(lc_render::Error::DataIsEmpty.into());
+        }
+        if slices_values
+            .iter()
+            .any(|slice| !slice.value.is_finite() || slice.value < 0_f32)
+        {
+            return Err(RendererError::SlicesValuesAreInvalid);
+        }
+        let total: f32 = slices_values.iter().map(|slice| slice.value).sum();
+        if total <= 0_f32 {
+            return Err(RendererError::SlicesValuesAreInvalid);
+        }
+
+        let mut slices = Vec::with_capacity(slices_values.len());
+        let mut start_angle = self.start_angle.to_radians();
+        for slice_value in slices_values.iter().filter(|slice| slice.value > 0_f32) {
+            let end_angle = start_angle + slice_value.value / total * 2_f32 * PI;
+            let slice = Slice::new(
+                start_angle,
+                end_angle,
+                self.radius,
+                self.radius * self.inner_radius,
+                &get_slice_label(&slice_value.label, slice_value.value, total),
+            )
+            .set_fill_color(&slice_value.fill_color)
+            .set_stroke_color(&self.stroke_color)
+            .set_label_visible(self.slice_label_visible);
+            slices.push(slice);
+            start_angle = end_angle;
+        }
+        self.slices = slices;
+
+        Ok(self)
+    }
+}
+
+impl View for PieView {
+    fn to_svg(&self) -> svg::node::element::Group {
+        let mut res = svg::node::element::Group::new()
+            .set(TRANSFORM_ATTR, translate_x_y(self.center_x, self.center_y));
+        for slice in self.slices.iter() {
+            res.append(slice.to_svg());
+        }
+
+        res
+    }
+}
@@ -19,6 +19,9 @@ pub enum RendererError {
     /// Expected points values but got other kind.
     ExpectedPointsValues,
 
+    /// Expected slices values but got other kind.
+    ExpectedSlicesValues,
+
     /// Bars values are not specified.
     ColorsForBarsValuesAreNotSpecified,
 
@@ -28,9 +31,66 @@ pub enum RendererError {
     /// Bars values stroke color is not specified.
     StrokeColorForBarsValuesIsNotSpecified,
 
+    /// Count of bars dataset values doesn't equal count of categories.
+    BarsDatasetValuesCountIsInvalid,
+
+    /// Count of stacked area values doesn't equal count of values of other areas in the stack group.
+    StackedAreaValuesCountIsInvalid,
+
+    /// Stacked area view is drawn on a scale that is not band.
+    StackedAreaViewRequiresBandScale,
+
+    /// Stacked area view is drawn on a vertical scale that is not linear.
+    StackedAreaViewRequiresLinearScale,
+
+    /// Area views of the same stack group have different normalization.
+    StackNormalizationIsInconsistent,
+
+    /// Area views of the same stack group are drawn on different axes.
+    StackAxesAreInconsistent,
+
+    /// Slice fill color is not specified.
+    FillColorForSliceIsNotSpecified,
+
+    /// Slices values are negative or their sum is zero.
+    SlicesValuesAreInvalid,
+
     /// Got unknown bar label position.
     BarLabelPositionIsUnknown,
 
+    /// Got unknown bar layout.
+    BarLayoutIsUnknown,
+
+    /// Got unknown legend position.
+    LegendPositionIsUnknown,
+
+    /// Got unknown legend orientation.
+    LegendOrientationIsUnknown,
+
+    /// Legend font size is not positive.
+    LegendFontSizeIsInvalid,
+
+    /// Named view or bars dataset has no colors for the legend.
+    LegendEntryColorIsNotSpecified,
+
+    /// Legend is larger than the area it's placed at.
+    LegendDoesNotFitMargins,
+
+    /// Got unknown theme name.
+    ThemeNameIsUnknown,
+
+    /// Theme color is not a valid hex color.
+    ThemeColorIsInvalid,
+
+    /// Theme palette color is not specified.
+    ThemePaletteColorIsNotSpecified,
+
+    /// Theme font family contains unsupported characters.
+    ThemeFontFamilyIsInvalid,
+
+    /// Theme title font size is not positive.
+    ThemeTitleFontSizeIsInvalid,
+
     /// Got unknown point label position.
     PointLabelPositionIsUnknown,
 
@@ -43,6 +103,87 @@ pub enum RendererError {
     /// Left or right axis should be specified.
     LeftOrRightAxisShouldBeSpecified,
 
+    /// Got unknown horizontal axis of view.
+    ViewHorizontalAxisIsUnknown,
+
+    /// Got unknown vertical axis of view.
+    ViewVerticalAxisIsUnknown,
+
+    /// Horizontal axis of view is not set in chart axes.
+    ViewHorizontalAxisIsNotSpecified,
+
+    /// Vertical axis of view is not set in chart axes.
+    ViewVerticalAxisIsNotSpecified,
+
+    /// Scale kind of horizontal axis of view is not supported by the view kind.
+    ViewHorizontalAxisScaleKindIsInvalid,
+
+    /// Scale kind of vertical axis of view is not supported by the view kind.
+    ViewVerticalAxisScaleKindIsInvalid,
+
+    /// Axis tick count is zero or greater than the maximum count.
+    AxisTickCountIsInvalid,
+
+    /// Axis tick values are not finite or there are more of them than the maximum count.
+    AxisTickValuesAreInvalid,
+
+    /// Got unknown axis number format.
+    AxisNumberFormatIsUnknown,
+
+    /// Axis number format has too many decimals.
+    AxisNumberFormatDecimalsAreInvalid,
+
+    /// Axis tick count, tick values or number format are set for a non-numeric axis.
+    AxisNumericOptionsRequireNumericScale,
+
+    /// Got unknown annotation kind.
+    AnnotationKindIsUnknown,
+
+    /// Got unknown annotation layer.
+    AnnotationLayerIsUnknown,
+
+    /// Annotation x value is not specified.
+    AnnotationXIsNotSpecified,
+
+    /// Annotation x_end value is not specified.
+    AnnotationXEndIsNotSpecified,
+
+    /// Annotation y value is not specified.
+    AnnotationYIsNotSpecified,
+
+    /// Annotation y_end value is not specified.
+    AnnotationYEndIsNotSpecified,
+
+    /// Annotation x value doesn't match the scale of its horizontal axis.
+    AnnotationXIsInvalid,
+
+    /// Annotation x_end value doesn't match the scale of its horizontal axis.
+    AnnotationXEndIsInvalid,
+
+    /// Annotation y value doesn't match the scale of its vertical axis.
+    AnnotationYIsInvalid,
+
+    /// Annotation y_end value doesn't match the scale of its vertical axis.
+    AnnotationYEndIsInvalid,
+
+    /// Count of lower or upper bounds of view values doesn't equal count of values.
+    ValuesBoundsCountIsInvalid,
+
+    /// Bounds of view values are not finite.
+    ValuesBoundsAreInvalid,
+
+    /// Histogram binning kind is unknown.
+    HistogramBinningKindIsUnknown,
+
+    /// Histogram bin count is not specified or it's not between 1 and the maximum count.
+    HistogramBinCountIsInvalid,
+
+    /// Histogram bin width is not specified, it's not positive or it makes too many bins.
+    HistogramBinWidthIsInvalid,
+
+    /// Samples of histogram view are empty or not finite.
+    HistogramSamplesAreInvalid,
+
     /// Fill color for area view is not specified.
     FillColorForAreaViewIsNotSpecified,
 
@@ -70,6 +211,18 @@ pub enum RendererError {
     /// Point stroke color for scatter view is not specified.
     PointStrokeColorForScatterViewIsNotSpecified,
 
+    /// Stroke color for pie view is not specified.
+    StrokeColorForPieViewIsNotSpecified,
+
+    /// Stroke color for donut view is not specified.
+    StrokeColorForDonutViewIsNotSpecified,
+
+    /// Fill color for histogram view is not specified.
+    FillColorForHistogramViewIsNotSpecified,
+
+    /// Stroke color for histogram view is not specified.
+    StrokeColorForHistogramViewIsNotSpecified,
+
     /// View kind is unknown.
     ViewKindIsUnknown,
 
@@ -100,17 +253,17 @@ pub enum RendererError {
     /// Chart right margin is not specified.
     ChartRightMarginIsNotSpecified,
 
-    /// Top axis is set but it's not of band or linear kind
-    TopAxisIsSetButItsNotBandOrLinear,
+    /// Top axis is set but its scale kind is unknown.
+    TopAxisIsSetButItsKindIsUnknown,
 
-    /// Bottom axis is set but it's not of band or linear kind
-    BottomAxisIsSetButItsNotBandOrLinear,
+    /// Bottom axis is set but its scale kind is unknown.
+    BottomAxisIsSetButItsKindIsUnknown,
 
-    /// Left axis is set but it's not of band or linear kind
-    LeftAxisIsSetButItsNotBandOrLinear,
+    /// Left axis is set but its scale kind is unknown.
+    LeftAxisIsSetButItsKindIsUnknown,
 
-    /// Right axis is set but it's not of band or linear kind
-    RightAxisIsSetButItsNotBandOrLinear,
+    /// Right axis is set but its scale kind is unknown.
+    RightAxisIsSetButItsKindIsUnknown,
 
     /// Point visibility for area view is not specified.
     PointVisibilityForAreaViewIsNotSpecified,
@@ -136,6 +289,21 @@ pub enum RendererError {
     /// Bar label visibility for vertical bar is not specified.
     BarLabelVisibilityForVerticalBarViewIsNotSpecified,
 
+    /// Bar label visibility for histogram view is not specified.
+    BarLabelVisibilityForHistogramViewIsNotSpecified,
+
+    /// Slice label visibility for pie view is not specified.
+    SliceLabelVisibilityForPieViewIsNotSpecified,
+
+    /// Slice label visibility for donut view is not specified.
+    SliceLabelVisibilityForDonutViewIsNotSpecified,
+
+    /// Inner radius for donut view is not specified.
+    InnerRadiusForDonutViewIsNotSpecified,
+
+    /// Inner radius for donut view is not between zero and one.
+    InnerRadiusForDonutViewIsInvalid,
+
     /// Range start for scale is not specified.
     ScaleRangeStartIsNotSpecified,
 
@@ -151,16 +319,109 @@ pub enum RendererError {
     /// Band scale categories domain is not specified.
     BandScaleCategoriesDomainIsNotSpecified,
 
+    /// Log scale numeric domain is not specified.
+    LogScaleNumericDomainIsNotSpecified,
+
+    /// Log scale domain contains zero or negative values.
+    LogScaleDomainIsNotPositive,
+
+    /// Log scale base is not between 2 and 100.
+    LogScaleBaseIsInvalid,
+
+    /// Log scale domain spans more than 100 powers of base.
+    LogScaleDomainIsTooWide,
+
+    /// Value on log scale is zero or negative.
+    LogScaleValueIsNotPositive,
+
+    /// Time scale time domain is not specified.
+    TimeScaleTimeDomainIsNotSpecified,
+
+    /// Time scale domain has no start or end, they are equal or outside of years 1 to 9999.
+    TimeScaleDomainIsInvalid,
+
+    /// Time scale labels format contains unknown specifiers.
+    TimeScaleFormatIsInvalid,
+
+    /// Point time is not specified for a view on time scale.
+    PointTimeIsNotSpecified,
+
+    /// Point time is outside of the range of protobuf timestamps.
+    PointTimeIsInvalid,
+
     /// Band scale inner padding is not specified.
     BandScaleInnerPaddingIsNotSpecified,
 
     /// Band scale outer padding is not specified.
     BandScaleOuterPaddingIsNotSpecified,
+
+    /// There are no values of views on the scale to compute its auto domain.
+    AutoDomainValuesAreNotSpecified,
+
+    /// Chart ID has invalid format.
+    ChartIdIsInvalid,
+
+    /// Chart TTL is negative.
+    ChartTtlIsNegative,
+
+    /// Chart TTL is longer than the maximum TTL.
+    ChartTtlIsTooLong,
+
+    /// IoError contains error from filesystem operations.
+    IoError(std::io::Error),
+
+    /// ChartEncodeError contains stored chart encoding error.
+    ChartEncodeError(prost::EncodeError),
+
+    /// ChartDecodeError contains stored chart decoding error.
+    ChartDecodeError(prost::DecodeError),
+
+    /// Output format is unknown.
+    OutputFormatIsUnknown,
+
+    /// Output scale is not a positive number or it's too big.
+    OutputScaleIsInvalid,
+
+    /// Output size can't be used for a raster image.
+    OutputSizeIsInvalid,
+
+    /// Raster image of the chart has too many pixels.
+    OutputSizeIsTooLarge,
+
+    /// SvgParseError contains error from parsing of a rendered SVG document.
+    SvgParseError(resvg::usvg::Error),
+
+    /// PngEncodeError contains error from PNG encoder.
+    PngEncodeError(String),
+
+    /// PdfConvertError contains error from SVG to PDF converter.
+    PdfConvertError(svg2pdf::ConversionError),
+
+    /// JsonDecodeError contains error from decoding of a JSON request.
+    JsonDecodeError(serde_json::Error),
+
+    /// YamlDecodeError contains error from decoding of a YAML request.
+    YamlDecodeError(serde_yaml::Error),
+
+    /// HttpBodyError contains error from reading of an HTTP request body.
+    HttpBodyError(hyper::Error),
+
+    /// HTTP request body is larger than allowed.
+    RequestBodyIsTooLarge,
+
+    /// Batch request contains more charts than allowed.
+    ChartsCountIsTooLarge,
+
+    /// Count of view values doesn't match count of chart views.
+    ViewValuesCountIsInvalid,
+
+    /// Chart stream should start with a base chart.
+    ChartStreamBaseChartIsNotSpecified,
 }
 
 impl std::fmt::Display for RendererError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+        match self {
             RendererError::RenderError(e) => e.fmt(f),
             RendererError::ViewColorsAreNotSpecified => {
                 "view colors are not specified".to_string().fmt(f)
@@ -175,6 +436,9 @@ impl std::fmt::Display for RendererError {
             RendererError::ExpectedPointsValues => {
                 "expected points values for view".to_string().fmt(f)
             }
+            RendererError::ExpectedSlicesValues => {
+                "expected slices values for view".to_string().fmt(f)
+            }
             RendererError::ColorsForBarsValuesAreNotSpecified => {
                 "colors for bars values are not specified"
                     .to_string()
@@ -190,9 +454,80 @@ impl std::fmt::Display for RendererError {
                     .to_string()
                     .fmt(f)
             }
+            RendererError::BarsDatasetValuesCountIsInvalid => {
+                "count of bars dataset values should be equal to count of categories"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StackedAreaValuesCountIsInvalid => {
+                "count of stacked area values should be equal to count of other areas in the stack group"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StackedAreaViewRequiresBandScale => {
+                "stacked area view requires band horizontal scale"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StackedAreaViewRequiresLinearScale => {
+                "stacked area view requires linear vertical scale"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StackNormalizationIsInconsistent => {
+                "area views of the same stack group should have the same normalization"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StackAxesAreInconsistent => {
+                "area views of the same stack group should be drawn on the same axes"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::FillColorForSliceIsNotSpecified => {
+                "fill color for slice is not specified".to_string().fmt(f)
+            }
+            RendererError::SlicesValuesAreInvalid => {
+                "slices values should be non-negative with a positive sum"
+                    .to_string()
+                    .fmt(f)
+            }
             RendererError::BarLabelPositionIsUnknown => {
                 "view bar label position is unknown".to_string().fmt(f)
             }
+            RendererError::BarLayoutIsUnknown => "view bar layout is unknown".to_string().fmt(f),
+            RendererError::LegendPositionIsUnknown => {
+                "legend position is unknown".to_string().fmt(f)
+            }
+            RendererError::LegendOrientationIsUnknown => {
+                "legend orientation is unknown".to_string().fmt(f)
+            }
+            RendererError::LegendFontSizeIsInvalid => {
+                "legend font size should be positive".to_string().fmt(f)
+            }
+            RendererError::LegendEntryColorIsNotSpecified => {
+                "named view or bars dataset should have a color for the legend"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::LegendDoesNotFitMargins => {
+                "legend doesn't fit chart margins".to_string().fmt(f)
+            }
+            RendererError::ThemeNameIsUnknown => "theme name is unknown".to_string().fmt(f),
+            RendererError::ThemeColorIsInvalid => {
+                "theme color should be a valid hex color".to_string().fmt(f)
+            }
+            RendererError::ThemePaletteColorIsNotSpecified => {
+                "theme palette color is not specified".to_string().fmt(f)
+            }
+            RendererError::ThemeFontFamilyIsInvalid => {
+                "theme font family should contain only letters, digits, spaces, commas, dashes, underscores and quotes"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ThemeTitleFontSizeIsInvalid => {
+                "theme title font size should be positive".to_string().fmt(f)
+            }
             RendererError::PointLabelPositionIsUnknown => {
                 "view point label position is unknown".to_string().fmt(f)
             }
@@ -203,6 +538,107 @@ impl std::fmt::Display for RendererError {
             RendererError::LeftOrRightAxisShouldBeSpecified => {
                 "left or right axis should be specified".to_string().fmt(f)
             }
+            RendererError::ViewHorizontalAxisIsUnknown => {
+                "view horizontal axis is unknown".to_string().fmt(f)
+            }
+            RendererError::ViewVerticalAxisIsUnknown => {
+                "view vertical axis is unknown".to_string().fmt(f)
+            }
+            RendererError::ViewHorizontalAxisIsNotSpecified => {
+                "horizontal axis of view is not specified in chart axes"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ViewVerticalAxisIsNotSpecified => {
+                "vertical axis of view is not specified in chart axes"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ViewHorizontalAxisScaleKindIsInvalid => {
+                "scale kind of view horizontal axis is not supported by view kind"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ViewVerticalAxisScaleKindIsInvalid => {
+                "scale kind of view vertical axis is not supported by view kind"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::AxisTickCountIsInvalid => {
+                "axis tick count should be between 1 and 100".to_string().fmt(f)
+            }
+            RendererError::AxisTickValuesAreInvalid => {
+                "axis tick values should be finite and there should be no more than 100 of them"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::AxisNumberFormatIsUnknown => {
+                "axis number format is unknown".to_string().fmt(f)
+            }
+            RendererError::AxisNumberFormatDecimalsAreInvalid => {
+                "axis number format decimals should not be greater than 10"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::AxisNumericOptionsRequireNumericScale => {
+                "axis tick count, tick values and number format require linear or log scale"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::AnnotationKindIsUnknown => {
+                "annotation kind is unknown".to_string().fmt(f)
+            }
+            RendererError::AnnotationLayerIsUnknown => {
+                "annotation layer is unknown".to_string().fmt(f)
+            }
+            RendererError::AnnotationXIsNotSpecified => {
+                "annotation x value is not specified".to_string().fmt(f)
+            }
+            RendererError::AnnotationXEndIsNotSpecified => {
+                "annotation x_end value is not specified".to_string().fmt(f)
+            }
+            RendererError::AnnotationYIsNotSpecified => {
+                "annotation y value is not specified".to_string().fmt(f)
+            }
+            RendererError::AnnotationYEndIsNotSpecified => {
+                "annotation y_end value is not specified".to_string().fmt(f)
+            }
+            RendererError::AnnotationXIsInvalid => {
+                "annotation x value doesn't match scale of horizontal axis".to_string().fmt(f)
+            }
+            RendererError::AnnotationXEndIsInvalid => {
+                "annotation x_end value doesn't match scale of horizontal axis".to_string().fmt(f)
+            }
+            RendererError::AnnotationYIsInvalid => {
+                "annotation y value doesn't match scale of vertical axis".to_string().fmt(f)
+            }
+            RendererError::AnnotationYEndIsInvalid => {
+                "annotation y_end value doesn't match scale of vertical axis".to_string().fmt(f)
+            }
+            RendererError::ValuesBoundsCountIsInvalid => {
+                "count of view values bounds should be equal to count of values".to_string().fmt(f)
+            }
+            RendererError::ValuesBoundsAreInvalid => {
+                "view values bounds should be finite".to_string().fmt(f)
+            }
+            RendererError::HistogramBinningKindIsUnknown => {
+                "histogram binning kind is unknown".to_string().fmt(f)
+            }
+            RendererError::HistogramBinCountIsInvalid => {
+                "histogram bin count should be specified and be between 1 and 1000"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::HistogramBinWidthIsInvalid => {
+                "histogram bin width should be positive and make no more than 1000 bins"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::HistogramSamplesAreInvalid => {
+                "histogram view samples should be finite and not empty"
+                    .to_string()
+                    .fmt(f)
+            }
             RendererError::FillColorForAreaViewIsNotSpecified => {
                 "fill color for area view is not specified"
                     .to_string()
@@ -248,6 +684,26 @@ impl std::fmt::Display for RendererError {
                     .to_string()
                     .fmt(f)
             }
+            RendererError::StrokeColorForPieViewIsNotSpecified => {
+                "stroke color for pie view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StrokeColorForDonutViewIsNotSpecified => {
+                "stroke color for donut view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::FillColorForHistogramViewIsNotSpecified => {
+                "fill color for histogram view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::StrokeColorForHistogramViewIsNotSpecified => {
+                "stroke color for histogram view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
             RendererError::ViewKindIsUnknown => "view kind is unknown".to_string().fmt(f),
             RendererError::ChartAxesAreNotSpecified => {
                 "chart axes are not be specified".to_string().fmt(f)
@@ -276,23 +732,23 @@ impl std::fmt::Display for RendererError {
             RendererError::ChartRightMarginIsNotSpecified => {
                 "chart right margin is not specified".to_string().fmt(f)
             }
-            RendererError::TopAxisIsSetButItsNotBandOrLinear => {
-                "top axis is set but it's not of band or linear kind"
+            RendererError::TopAxisIsSetButItsKindIsUnknown => {
+                "top axis is set but its scale kind is unknown"
                     .to_string()
                     .fmt(f)
             }
-            RendererError::BottomAxisIsSetButItsNotBandOrLinear => {
-                "bottom axis is set but it's not of band or linear kind"
+            RendererError::BottomAxisIsSetButItsKindIsUnknown => {
+                "bottom axis is set but its scale kind is unknown"
                     .to_string()
                     .fmt(f)
             }
-            RendererError::LeftAxisIsSetButItsNotBandOrLinear => {
-                "left axis is set but it's not of band or linear kind"
+            RendererError::LeftAxisIsSetButItsKindIsUnknown => {
+                "left axis is set but its scale kind is unknown"
                     .to_string()
                     .fmt(f)
             }
-            RendererError::RightAxisIsSetButItsNotBandOrLinear => {
-                "right axis is set but it's not of band or linear kind"
+            RendererError::RightAxisIsSetButItsKindIsUnknown => {
+                "right axis is set but its scale kind is unknown"
                     .to_string()
                     .fmt(f)
             }
@@ -336,6 +792,31 @@ impl std::fmt::Display for RendererError {
                     .to_string()
                     .fmt(f)
             }
+            RendererError::BarLabelVisibilityForHistogramViewIsNotSpecified => {
+                "bar label visibility for histogram view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::SliceLabelVisibilityForPieViewIsNotSpecified => {
+                "slice label visibility for pie view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::SliceLabelVisibilityForDonutViewIsNotSpecified => {
+                "slice label visibility for donut view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::InnerRadiusForDonutViewIsNotSpecified => {
+                "inner radius for donut view is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::InnerRadiusForDonutViewIsInvalid => {
+                "inner radius for donut view should be greater than 0 and less than 1"
+                    .to_string()
+                    .fmt(f)
+            }
             RendererError::ScaleRangeStartIsNotSpecified => {
                 "range start for scale is not specified".to_string().fmt(f)
             }
@@ -355,6 +836,46 @@ impl std::fmt::Display for RendererError {
                     .to_string()
                     .fmt(f)
             }
+            RendererError::LogScaleNumericDomainIsNotSpecified => {
+                "numeric domain for log scale is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::LogScaleDomainIsNotPositive => {
+                "domain for log scale should be positive".to_string().fmt(f)
+            }
+            RendererError::LogScaleBaseIsInvalid => {
+                "base for log scale should be between 2 and 100"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::LogScaleDomainIsTooWide => {
+                "domain for log scale should span no more than 100 powers of base"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::LogScaleValueIsNotPositive => {
+                "values on log scale should be positive".to_string().fmt(f)
+            }
+            RendererError::TimeScaleTimeDomainIsNotSpecified => {
+                "time domain for time scale is not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::TimeScaleDomainIsInvalid => {
+                "domain for time scale should have different start and end between years 1 and 9999"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::TimeScaleFormatIsInvalid => {
+                "format for time scale is invalid".to_string().fmt(f)
+            }
+            RendererError::PointTimeIsNotSpecified => "point time is not specified for time scale"
+                .to_string()
+                .fmt(f),
+            RendererError::PointTimeIsInvalid => {
+                "point time should be between years 1 and 9999".to_string().fmt(f)
+            }
             RendererError::BandScaleInnerPaddingIsNotSpecified => {
                 "inner padding for band scale is not specified"
                     .to_string()
@@ -365,6 +886,55 @@ impl std::fmt::Display for RendererError {
                     .to_string()
                     .fmt(f)
             }
+            RendererError::AutoDomainValuesAreNotSpecified => {
+                "values of views to compute auto domain are not specified"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ChartIdIsInvalid => "chart id is invalid".to_string().fmt(f),
+            RendererError::ChartTtlIsNegative => "chart ttl is negative".to_string().fmt(f),
+            RendererError::ChartTtlIsTooLong => {
+                "chart ttl should be no longer than 366 days".to_string().fmt(f)
+            }
+            RendererError::IoError(e) => format!("io error: {}", e).fmt(f),
+            RendererError::ChartEncodeError(e) => format!("unable to encode chart: {}", e).fmt(f),
+            RendererError::ChartDecodeError(e) => format!("unable to decode chart: {}", e).fmt(f),
+            RendererError::OutputFormatIsUnknown => "output format is unknown".to_string().fmt(f),
+            RendererError::OutputScaleIsInvalid => {
+                "output scale should be greater than 0 and not greater than 10"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::OutputSizeIsInvalid => "output size is invalid for a raster image"
+                .to_string()
+                .fmt(f),
+            RendererError::OutputSizeIsTooLarge => {
+                "raster image should have at most 40000000 pixels with output scale"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::SvgParseError(e) => format!("unable to parse svg: {}", e).fmt(f),
+            RendererError::PngEncodeError(e) => format!("unable to encode png: {}", e).fmt(f),
+            RendererError::PdfConvertError(e) => {
+                format!("unable to convert svg to pdf: {}", e).fmt(f)
+            }
+            RendererError::JsonDecodeError(e) => format!("unable to decode json: {}", e).fmt(f),
+            RendererError::YamlDecodeError(e) => format!("unable to decode yaml: {}", e).fmt(f),
+            RendererError::HttpBodyError(e) => format!("unable to read body: {}", e).fmt(f),
+            RendererError::RequestBodyIsTooLarge => "request body is too large".to_string().fmt(f),
+            RendererError::ChartsCountIsTooLarge => {
+                "request should contain at most 64 charts".to_string().fmt(f)
+            }
+            RendererError::ViewValuesCountIsInvalid => {
+                "count of view values doesn't match count of chart views"
+                    .to_string()
+                    .fmt(f)
+            }
+            RendererError::ChartStreamBaseChartIsNotSpecified => {
+                "base chart should be sent before chart frames"
+                    .to_string()
+                    .fmt(f)
+            }
         }
     }
 }
@@ -376,3 +946,51 @@ impl std::convert::From<lc_render::Error> for RendererError {
         RendererError::RenderError(e)
     }
 }
+
+impl std::convert::From<resvg::usvg::Error> for RendererError {
+    fn from(e: resvg::usvg::Error) -> Self {
+        RendererError::SvgParseError(e)
+    }
+}
+
+impl std::convert::From<svg2pdf::ConversionError> for RendererError {
+    fn from(e: svg2pdf::ConversionError) -> Self {
+        RendererError::PdfConvertError(e)
+    }
+}
+
+impl std::convert::From<std::io::Error> for RendererError {
+    fn from(e: std::io::Error) -> Self {
+        RendererError::IoError(e)
+    }
+}
+
+impl std::convert::From<prost::EncodeError> for RendererError {
+    fn from(e: prost::EncodeError) -> Self {
+        RendererError::ChartEncodeError(e)
+    }
+}
+
+impl std::convert::From<prost::DecodeError> for RendererError {
+    fn from(e: prost::DecodeError) -> Self {
+        RendererError::ChartDecodeError(e)
+    }
+}
+
+impl std::convert::From<serde_json::Error> for RendererError {
+    fn from(e: serde_json::Error) -> Self {
+        RendererError::JsonDecodeError(e)
+    }
+}
+
+impl std::convert::From<serde_yaml::Error> for RendererError {
+    fn from(e: serde_yaml::Error) -> Self {
+        RendererError::YamlDecodeError(e)
+    }
+}
+
+impl std::convert::From<hyper::Error> for RendererError {
+    fn from(e: hyper::Error) -> Self {
+        RendererError::HttpBodyError(e)
+    }
+}
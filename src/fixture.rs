@@ -0,0 +1,89 @@
+use crate::proto::render::RenderChartRequest;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Render chart request with a scatter view on linear axes that is shared by tests.
+pub(crate) const RENDER_CHART_REQUEST: &str = r##"{
+    "requestId": "req-1",
+    "sizes": {"width": 800, "height": 600},
+    "margins": {"marginTop": 90, "marginBottom": 50, "marginLeft": 60, "marginRight": 40},
+    "axes": {
+        "axisBottom": {
+            "kind": "LINEAR",
+            "rangeStart": 0,
+            "rangeEnd": 700,
+            "domainNumeric": {"start": 0, "end": 100}
+        },
+        "axisLeft": {
+            "kind": "LINEAR",
+            "rangeStart": 460,
+            "rangeEnd": 0,
+            "domainNumeric": {"start": 0, "end": 100}
+        }
+    },
+    "views": [{
+        "kind": "SCATTER",
+        "colors": {
+            "pointFill": {"colorHex": "#1a888b"},
+            "pointStroke": {"colorHex": "#50c5b6"}
+        },
+        "pointVisible": true,
+        "pointType": "CIRCLE",
+        "pointLabelVisible": false,
+        "pointLabelPosition": "TOP",
+        "pointsValues": {"points": [{"x": 10, "y": 20}]}
+    }]
+}"##;
+
+// Get the shared render chart request with the request ID.
+pub(crate) fn render_chart_request(request_id: &str) -> RenderChartRequest {
+    let mut r_req: RenderChartRequest = serde_json::from_str(RENDER_CHART_REQUEST).unwrap();
+    r_req.request_id = request_id.to_string();
+
+    r_req
+}
+
+// Get the shared render chart request JSON without fields at the provided JSON pointers.
+pub(crate) fn render_chart_request_without(pointers: &[&str]) -> String {
+    let mut r_req: serde_json::Value = serde_json::from_str(RENDER_CHART_REQUEST).unwrap();
+    for pointer in pointers {
+        let (parent, field) = pointer.rsplit_once('/').unwrap();
+        r_req
+            .pointer_mut(parent)
+            .and_then(serde_json::Value::as_object_mut)
+            .unwrap()
+            .remove(field)
+            .unwrap();
+    }
+
+    r_req.to_string()
+}
+
+/// Temporary directory that is removed with all its files when it's dropped,
+/// so it's removed even if a test fails.
+pub(crate) struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub(crate) fn new() -> TempDir {
+        let path = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&path).unwrap();
+
+        TempDir { path }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn join<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::draw::view::HistogramBin;
+use crate::error::RendererError;
+use crate::proto::render::chart_view_histogram_binning::ChartViewHistogramBinningKind;
+use crate::proto::render::ChartView;
+use crate::value::get_scalar_values;
+
+// Maximum count of histogram bins, it limits fixed width bins over a wide range of samples.
+const MAX_BIN_COUNT: usize = 1000;
+
+/// Strategy that is used to split samples of histogram view into bins.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum HistogramBinning {
+    FixedCount(usize),
+    FixedWidth(f32),
+    Sturges,
+    FreedmanDiaconis,
+}
+
+// Get histogram binning from protobuf, Sturges' rule is used by default.
+pub(crate) fn get_histogram_binning(view: &ChartView) -> Result<HistogramBinning, RendererError> {
+    let binning = view.histogram_binning.clone().unwrap_or_default();
+    match ChartViewHistogramBinningKind::from_i32(binning.kind) {
+        Some(ChartViewHistogramBinningKind::UnspecifiedBinningKind)
+        | Some(ChartViewHistogramBinningKind::Sturges) => Ok(HistogramBinning::Sturges),
+        Some(ChartViewHistogramBinningKind::FreedmanDiaconis) => {
+            Ok(HistogramBinning::FreedmanDiaconis)
+        }
+        Some(ChartViewHistogramBinningKind::FixedCount) => match binning.bin_count {
+            Some(bin_count) if bin_count > 0 && bin_count as usize <= MAX_BIN_COUNT => {
+                Ok(HistogramBinning::FixedCount(bin_count as usize))
+            }
+            _ => Err(RendererError::HistogramBinCountIsInvalid),
+        },
+        Some(ChartViewHistogramBinningKind::FixedWidth) => match binning.bin_width {
+            Some(bin_width) if bin_width.is_finite() && bin_width > 0_f32 => {
+                Ok(HistogramBinning::FixedWidth(bin_width))
+            }
+            _ => Err(RendererError::HistogramBinWidthIsInvalid),
+        },
+        None => Err(RendererError::HistogramBinningKindIsUnknown),
+    }
+}
+
+// Get count of bins by Sturges' rule.
+fn get_sturges_count(samples: &[f64]) -> usize {
+    (samples.len() as f64).log2().ceil() as usize + 1
+}
+
+// Get quantile of sorted samples with linear interpolation between the closest ones.
+fn get_quantile(sorted: &[f64], quantile: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * quantile;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+// Get count of bins by Freedman–Diaconis rule with bin width of 2 IQR / n^(1/3).
+// Sturges' rule is used for samples with zero interquartile range.
+fn get_freedman_diaconis_count(sorted: &[f64], span: f64) -> usize {
+    let iqr = get_quantile(sorted, 0.75) - get_quantile(sorted, 0.25);
+    if iqr <= 0_f64 || span <= 0_f64 {
+        return get_sturges_count(sorted);
+    }
+    let bin_width = 2_f64 * iqr / (sorted.len() as f64).cbrt();
+
+    ((span / bin_width).ceil() as usize).clamp(1, MAX_BIN_COUNT)
+}
+
+// Get start and width of equal bins between the minimum and the maximum samples.
+// Samples that are all equal are placed in the middle of a range with width of one.
+fn get_count_edges(min: f64, max: f64, count: usize) -> (f64, f64, usize) {
+    let (start, end) = if min == max {
+        (min - 0.5_f64, max + 0.5_f64)
+    } else {
+        (min, max)
+    };
+
+    (start, (end - start) / count as f64, count)
+}
+
+// Get start and count of bins with the fixed width, bin edges are multiples of the width.
+fn get_width_edges(min: f64, max: f64, width: f64) -> Result<(f64, f64, usize), RendererError> {
+    let start = (min / width).floor() * width;
+    let count = ((max - start) / width).floor().min(MAX_BIN_COUNT as f64) as usize + 1;
+    // The maximum sample on the last edge belongs to the previous bin.
+    let count = match count > 1 && start + (count - 1) as f64 * width >= max {
+        true => count - 1,
+        false => count,
+    };
+    if count > MAX_BIN_COUNT {
+        return Err(RendererError::HistogramBinWidthIsInvalid);
+    }
+
+    Ok((start, width, count))
+}
+
+// Get histogram bins of samples of the view with the view binning.
+// Every bin includes its start and excludes its end except the last one that includes both.
+// Bin heights are probability densities if the view binning needs them, counts otherwise.
+pub(crate) fn get_histogram_bins(view: &ChartView) -> Result<Vec<HistogramBin>, RendererError> {
+    let binning = get_histogram_binning(view)?;
+    let samples = get_scalar_values(view)?;
+    if samples.is_empty() || samples.iter().any(|sample| !sample.is_finite()) {
+        return Err(RendererError::HistogramSamplesAreInvalid);
+    }
+    let mut sorted: Vec<f64> = samples.iter().map(|sample| *sample as f64).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+
+    let (start, width, count) = match binning {
+        HistogramBinning::FixedCount(count) => get_count_edges(min, max, count),
+        HistogramBinning::FixedWidth(width) => get_width_edges(min, max, width as f64)?,
+        HistogramBinning::Sturges => get_count_edges(min, max, get_sturges_count(&sorted)),
+        HistogramBinning::FreedmanDiaconis => {
+            get_count_edges(min, max, get_freedman_diaconis_count(&sorted, max - min))
+        }
+    };
+
+    let mut counts = vec![0_usize; count];
+    for sample in sorted.iter() {
+        let idx = ((sample - start) / width).floor() as usize;
+        counts[idx.min(count - 1)] += 1;
+    }
+
+    let density = view
+        .histogram_binning
+        .as_ref()
+        .map(|binning| binning.density)
+        .unwrap_or_default();
+    let total = sorted.len() as f64;
+
+    Ok(counts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, count)| {
+            let value = match density {
+                true => count as f64 / (total * width),
+                false => count as f64,
+            };
+            HistogramBin {
+                start: (start + idx as f64 * width) as f32,
+                end: (start + (idx + 1) as f64 * width) as f32,
+                value: value as f32,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::chart_view::Values;
+    use crate::proto::render::{ChartViewHistogramBinning, ChartViewScalarValues};
+
+    fn histogram_view(samples: &[f32], binning: ChartViewHistogramBinning) -> ChartView {
+        ChartView {
+            values: Some(Values::ScalarValues(ChartViewScalarValues {
+                values: samples.to_vec(),
+                ..ChartViewScalarValues::default()
+            })),
+            histogram_binning: Some(binning),
+            ..ChartView::default()
+        }
+    }
+
+    fn bin(start: f32, end: f32, value: f32) -> HistogramBin {
+        HistogramBin { start, end, value }
+    }
+
+    #[test]
+    fn get_histogram_bins_basic() {
+        let view = histogram_view(
+            &[1_f32, 2_f32, 2_f32, 3_f32, 5_f32],
+            ChartViewHistogramBinning {
+                kind: ChartViewHistogramBinningKind::FixedCount as i32,
+                bin_count: Some(2),
+                ..ChartViewHistogramBinning::default()
+            },
+        );
+
+        assert_eq!(
+            vec![bin(1_f32, 3_f32, 3_f32), bin(3_f32, 5_f32, 2_f32)],
+            get_histogram_bins(&view).unwrap()
+        );
+    }
+
+    #[test]
+    fn get_histogram_bins_fixed_width() {
+        let view = histogram_view(
+            &[0.5_f32, 4_f32, 5_f32, 7_f32, 10_f32],
+            ChartViewHistogramBinning {
+                kind: ChartViewHistogramBinningKind::FixedWidth as i32,
+                bin_width: Some(5_f32),
+                density: true,
+                ..ChartViewHistogramBinning::default()
+            },
+        );
+
+        assert_eq!(
+            vec![bin(0_f32, 5_f32, 0.08_f32), bin(5_f32, 10_f32, 0.12_f32)],
+            get_histogram_bins(&view).unwrap()
+        );
+    }
+
+    #[test]
+    fn get_histogram_bins_sturges() {
+        let samples: Vec<f32> = (0..8).map(|sample| sample as f32).collect();
+        let view = histogram_view(&samples, ChartViewHistogramBinning::default());
+        let bins = get_histogram_bins(&view).unwrap();
+
+        assert_eq!(4, bins.len());
+        assert_eq!(bin(5.25_f32, 7_f32, 2_f32), bins[3]);
+    }
+
+    #[test]
+    fn get_histogram_bins_freedman_diaconis() {
+        let samples: Vec<f32> = (0..8).map(|sample| sample as f32).collect();
+        let view = histogram_view(
+            &samples,
+            ChartViewHistogramBinning {
+                kind: ChartViewHistogramBinningKind::FreedmanDiaconis as i32,
+                ..ChartViewHistogramBinning::default()
+            },
+        );
+
+        // IQR is 3.5, so bins are 3.5 wide and two of them cover the samples.
+        assert_eq!(
+            vec![bin(0_f32, 3.5_f32, 4_f32), bin(3.5_f32, 7_f32, 4_f32)],
+            get_histogram_bins(&view).unwrap()
+        );
+    }
+
+    #[test]
+    fn get_histogram_bins_equal_samples() {
+        let view = histogram_view(&[3_f32, 3_f32], ChartViewHistogramBinning::default());
+
+        assert_eq!(
+            vec![bin(2.5_f32, 3_f32, 0_f32), bin(3_f32, 3.5_f32, 2_f32)],
+            get_histogram_bins(&view).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_histogram_bins_err() {
+        let view = histogram_view(
+            &[1_f32, 1000_f32],
+            ChartViewHistogramBinning {
+                kind: ChartViewHistogramBinningKind::FixedWidth as i32,
+                bin_width: Some(0.5_f32),
+                ..ChartViewHistogramBinning::default()
+            },
+        );
+
+        get_histogram_bins(&view).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_histogram_binning_err() {
+        let view = histogram_view(
+            &[1_f32],
+            ChartViewHistogramBinning {
+                kind: ChartViewHistogramBinningKind::FixedCount as i32,
+                bin_count: Some(0),
+                ..ChartViewHistogramBinning::default()
+            },
+        );
+
+        get_histogram_binning(&view).unwrap();
+    }
+}
@@ -0,0 +1,343 @@
+use crate::defaults::Defaults;
+use crate::error::RendererError;
+use crate::proto::render::RenderChartRequest;
+use crate::renderer::{render, ERR_UNABLE_TO_RENDER_CHART, LOG_KEY_ERR, LOG_KEY_REQ_ID};
+use crate::validation::get_field_violations;
+use hyper::body::HttpBody;
+use hyper::header::{HeaderValue, ALLOW, CONTENT_TYPE};
+use hyper::server::conn::AddrIncoming;
+use hyper::server::Builder;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+
+pub(crate) const RENDER_CHART_PATH: &str = "/v0/render";
+
+const CONTENT_TYPE_JSON: &str = "application/json";
+const HEADER_REQUEST_ID: &str = "x-request-id";
+const HEADER_DEFAULTED_FIELDS: &str = "x-defaulted-fields";
+const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+const ERR_PATH_IS_NOT_FOUND: &str = "path is not found";
+const ERR_METHOD_IS_NOT_ALLOWED: &str = "method is not allowed";
+const ERR_UNABLE_TO_DECODE_REQUEST: &str = "Unable to decode request";
+
+// Bind HTTP/JSON gateway to the address, it fails if the address can't be used.
+pub(crate) fn bind_http(addr: &SocketAddr) -> Result<Builder<AddrIncoming>, hyper::Error> {
+    Server::try_bind(addr)
+}
+
+// Serve HTTP/JSON gateway to the chart renderer until shutdown is completed.
+pub(crate) async fn serve_http(
+    log: slog::Logger,
+    server: Builder<AddrIncoming>,
+    defaults: Defaults,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), hyper::Error> {
+    let make_service = make_service_fn(move |_| {
+        let log = log.clone();
+        let defaults = defaults.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                handle(log.clone(), defaults.clone(), req)
+            }))
+        }
+    });
+
+    server
+        .serve(make_service)
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+// Route HTTP request to its handler.
+async fn handle(
+    log: slog::Logger,
+    defaults: Defaults,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if req.uri().path() != RENDER_CHART_PATH {
+        return Ok(error_response(
+            StatusCode::NOT_FOUND,
+            "",
+            ERR_PATH_IS_NOT_FOUND,
+        ));
+    }
+    if req.method() != Method::POST {
+        let mut res = error_response(
+            StatusCode::METHOD_NOT_ALLOWED,
+            "",
+            ERR_METHOD_IS_NOT_ALLOWED,
+        );
+        res.headers_mut()
+            .insert(ALLOW, HeaderValue::from_static("POST"));
+        return Ok(res);
+    }
+
+    Ok(render_chart(log, &defaults, req.into_body()).await)
+}
+
+// Render chart from JSON mapping of RenderChartRequest.
+async fn render_chart(log: slog::Logger, defaults: &Defaults, body: Body) -> Response<Body> {
+    let mut r_req = match get_render_chart_request(body).await {
+        Ok(r_req) => r_req,
+        Err(err) => {
+            let status = match err {
+                RendererError::RequestBodyIsTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+                _ => StatusCode::BAD_REQUEST,
+            };
+            let err = err.to_string();
+            error!(log, "{}", ERR_UNABLE_TO_DECODE_REQUEST; LOG_KEY_ERR => err.clone());
+            return error_response(status, "", &err);
+        }
+    };
+
+    // Prepare request logger with request_id set.
+    let log = log.new(o!(LOG_KEY_REQ_ID => r_req.request_id.clone()));
+
+    let defaulted_fields = defaults.apply(&mut r_req);
+
+    // Render in a blocking task so rasterization doesn't stall other requests.
+    let (r_req, res) = match tokio::task::spawn_blocking(move || {
+        let res = render(&r_req);
+        (r_req, res)
+    })
+    .await
+    {
+        Ok(rendered) => rendered,
+        Err(err) => {
+            let err = err.to_string();
+            error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, "", &err);
+        }
+    };
+    let output = match res {
+        Ok(output) => output,
+        Err(err) => {
+            error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.to_string());
+            return invalid_chart_response(&r_req, &err);
+        }
+    };
+
+    let mut res = Response::new(Body::from(output.data));
+    res.headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(output.content_type));
+    set_request_id(&mut res, &r_req.request_id);
+    if !defaulted_fields.is_empty() {
+        if let Ok(defaulted_fields) = HeaderValue::from_str(&defaulted_fields.join(",")) {
+            res.headers_mut()
+                .insert(HEADER_DEFAULTED_FIELDS, defaulted_fields);
+        }
+    }
+
+    res
+}
+
+// Read and decode RenderChartRequest from HTTP request body.
+async fn get_render_chart_request(mut body: Body) -> Result<RenderChartRequest, RendererError> {
+    let mut data = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk?;
+        if data.len() + chunk.len() > MAX_BODY_SIZE {
+            return Err(RendererError::RequestBodyIsTooLarge);
+        }
+        data.extend_from_slice(&chunk);
+    }
+
+    Ok(serde_json::from_slice(&data)?)
+}
+
+// Prepare JSON response with error message.
+fn error_response(status: StatusCode, request_id: &str, err: &str) -> Response<Body> {
+    let body = serde_json::json!({
+        "requestId": request_id,
+        "error": err,
+    });
+
+    json_response(status, request_id, body)
+}
+
+// Prepare JSON response with error message and violations of all invalid request fields.
+fn invalid_chart_response(r_req: &RenderChartRequest, err: &RendererError) -> Response<Body> {
+    let field_violations: Vec<_> = get_field_violations(r_req, err)
+        .into_iter()
+        .map(|violation| {
+            serde_json::json!({
+                "field": violation.field,
+                "description": violation.description,
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "requestId": r_req.request_id,
+        "error": err.to_string(),
+        "fieldViolations": field_violations,
+    });
+
+    json_response(StatusCode::BAD_REQUEST, &r_req.request_id, body)
+}
+
+fn json_response(status: StatusCode, request_id: &str, body: serde_json::Value) -> Response<Body> {
+    let mut res = Response::new(Body::from(body.to_string()));
+    *res.status_mut() = status;
+    res.headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_JSON));
+    set_request_id(&mut res, request_id);
+
+    res
+}
+
+// Set request ID header if it's a valid header value.
+fn set_request_id(res: &mut Response<Body>, request_id: &str) {
+    if request_id.is_empty() {
+        return;
+    }
+    if let Ok(request_id) = HeaderValue::from_str(request_id) {
+        res.headers_mut().insert(HEADER_REQUEST_ID, request_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::{render_chart_request_without, RENDER_CHART_REQUEST};
+    use crate::output::CONTENT_TYPE_SVG;
+
+    fn request(method: Method, path: &str, body: &str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(path)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn call(req: Request<Body>) -> (Response<Body>, Vec<u8>) {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let mut res = handle(log, Defaults::default(), req).await.unwrap();
+        let body = hyper::body::to_bytes(res.body_mut()).await.unwrap();
+
+        (res, body.to_vec())
+    }
+
+    fn error_message(body: &[u8]) -> String {
+        let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+
+        body["error"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn render_chart_basic() {
+        let (res, body) = call(request(
+            Method::POST,
+            RENDER_CHART_PATH,
+            RENDER_CHART_REQUEST,
+        ))
+        .await;
+
+        assert_eq!(StatusCode::OK, res.status());
+        assert_eq!(CONTENT_TYPE_SVG, res.headers()[CONTENT_TYPE]);
+        assert_eq!("req-1", res.headers()[HEADER_REQUEST_ID]);
+        assert!(String::from_utf8(body).unwrap().starts_with("<svg"));
+    }
+
+    #[tokio::test]
+    async fn render_chart_invalid_json() {
+        let (res, body) = call(request(Method::POST, RENDER_CHART_PATH, "{")).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        assert_eq!(CONTENT_TYPE_JSON, res.headers()[CONTENT_TYPE]);
+        assert!(error_message(&body).starts_with("unable to decode json"));
+    }
+
+    #[tokio::test]
+    async fn render_chart_unknown_field() {
+        let (res, body) = call(request(
+            Method::POST,
+            RENDER_CHART_PATH,
+            &RENDER_CHART_REQUEST.replace("\"pointVisible\"", "\"pointVisibility\""),
+        ))
+        .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        assert!(error_message(&body).contains("unknown field `pointVisibility`"));
+    }
+
+    #[tokio::test]
+    async fn render_chart_invalid_chart() {
+        let (res, body) = call(request(
+            Method::POST,
+            RENDER_CHART_PATH,
+            &render_chart_request_without(&["/axes", "/views/0/colors/pointFill"]),
+        ))
+        .await;
+        let violations: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+        assert_eq!(
+            RendererError::PointFillColorForScatterViewIsNotSpecified.to_string(),
+            error_message(&body)
+        );
+        assert_eq!(
+            serde_json::json!([
+                {
+                    "field": "axes",
+                    "description": RendererError::ChartAxesAreNotSpecified.to_string(),
+                },
+                {
+                    "field": "views[0].colors.point_fill",
+                    "description": RendererError::PointFillColorForScatterViewIsNotSpecified.to_string(),
+                },
+            ]),
+            violations["fieldViolations"]
+        );
+    }
+
+    #[tokio::test]
+    async fn render_chart_defaults() {
+        let (res, _) = call(request(
+            Method::POST,
+            RENDER_CHART_PATH,
+            &render_chart_request_without(&["/sizes", "/views/0/pointVisible"]),
+        ))
+        .await;
+
+        assert_eq!(StatusCode::OK, res.status());
+        assert_eq!(
+            "sizes.width,sizes.height,views[0].point_visible",
+            res.headers()[HEADER_DEFAULTED_FIELDS]
+        );
+    }
+
+    #[tokio::test]
+    async fn render_chart_too_large() {
+        let body = " ".repeat(MAX_BODY_SIZE + 1);
+        let (res, _) = call(request(Method::POST, RENDER_CHART_PATH, &body)).await;
+
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, res.status());
+    }
+
+    #[tokio::test]
+    async fn render_chart_method_not_allowed() {
+        let (res, _) = call(request(Method::GET, RENDER_CHART_PATH, "")).await;
+
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, res.status());
+        assert_eq!("POST", res.headers()[ALLOW]);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn bind_http_err() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+
+        bind_http(&listener.local_addr().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn render_chart_not_found() {
+        let (res, _) = call(request(Method::POST, "/v0/unknown", "{}")).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, res.status());
+    }
+}
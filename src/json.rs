@@ -0,0 +1,192 @@
+use chrono::DateTime;
+use prost_types::Timestamp;
+use serde::{de, Deserialize, Deserializer};
+
+// Protobuf enum value in JSON, it can be set with its name or number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EnumValue<E> {
+    Number(i32),
+    Name(E),
+}
+
+// Deserialize protobuf enum field from its value name or number.
+pub(crate) fn enumeration<'de, E, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    E: Deserialize<'de> + Into<i32>,
+    D: Deserializer<'de>,
+{
+    match EnumValue::<E>::deserialize(deserializer)? {
+        EnumValue::Number(number) => Ok(number),
+        EnumValue::Name(name) => Ok(name.into()),
+    }
+}
+
+// Deserialize protobuf timestamp field from RFC 3339 string.
+pub(crate) fn timestamp<'de, D>(deserializer: D) -> Result<Option<Timestamp>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) => Ok(Some(parse_timestamp::<D>(&value)?)),
+        None => Ok(None),
+    }
+}
+
+// Deserialize protobuf timestamp field of a oneof from RFC 3339 string.
+pub(crate) fn oneof_timestamp<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_timestamp::<D>(&String::deserialize(deserializer)?)
+}
+
+fn parse_timestamp<'de, D>(value: &str) -> Result<Timestamp, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let time = DateTime::parse_from_rfc3339(value).map_err(de::Error::custom)?;
+
+    Ok(Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proto::render::chart_element_color::ColorValue;
+    use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+    use crate::proto::render::chart_view::Values;
+    use crate::proto::render::{ChartOutputFormat, DomainNumeric, DomainTime, RenderChartRequest};
+    use prost_types::Timestamp;
+
+    #[test]
+    fn render_chart_request_basic() {
+        let r_req: RenderChartRequest = serde_json::from_str(
+            r##"{
+                "requestId": "1",
+                "outputFormat": "PNG",
+                "outputScale": 2,
+                "axes": {
+                    "axisLeft": {
+                        "kind": "LINEAR",
+                        "rangeStart": 100,
+                        "domainNumeric": {"start": 0, "end": 10}
+                    }
+                },
+                "views": [{
+                    "kind": 3,
+                    "colors": {"stroke": {"colorHex": "#ffffff"}},
+                    "scalarValues": {"values": [1, 2.5]}
+                }]
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!("1", r_req.request_id);
+        assert_eq!(ChartOutputFormat::Png as i32, r_req.output_format);
+        assert_eq!(Some(2_f32), r_req.output_scale);
+        assert_eq!(None, r_req.sizes);
+
+        let axis_left = r_req.axes.unwrap().axis_left.unwrap();
+        assert_eq!(ChartScaleKind::Linear as i32, axis_left.kind);
+        assert_eq!(Some(100), axis_left.range_start);
+        assert_eq!(None, axis_left.range_end);
+        assert_eq!(
+            Some(Domain::DomainNumeric(DomainNumeric {
+                start: 0_f32,
+                end: 10_f32
+            })),
+            axis_left.domain
+        );
+
+        assert_eq!(3, r_req.views[0].kind);
+        assert_eq!(
+            Some(ColorValue::ColorHex("#ffffff".to_string())),
+            r_req.views[0]
+                .colors
+                .clone()
+                .unwrap()
+                .stroke
+                .unwrap()
+                .color_value
+        );
+        match &r_req.views[0].values {
+            Some(Values::ScalarValues(scalar_values)) => {
+                assert_eq!(vec![1_f32, 2.5_f32], scalar_values.values)
+            }
+            _ => panic!("expected scalar values"),
+        }
+    }
+
+    #[test]
+    fn render_chart_request_time() {
+        let r_req: RenderChartRequest = serde_json::from_str(
+            r#"{
+                "axes": {
+                    "axisBottom": {
+                        "kind": "TIME",
+                        "domainTime": {
+                            "start": "2021-03-01T00:00:00Z",
+                            "end": "2021-03-02T00:00:00.5+01:00"
+                        },
+                        "timeFormat": "%H:%M"
+                    }
+                },
+                "views": [{
+                    "pointsValues": {"points": [{"time": "2021-03-01T12:00:00Z", "y": 1}]}
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let axis_bottom = r_req.axes.unwrap().axis_bottom.unwrap();
+        assert_eq!(ChartScaleKind::Time as i32, axis_bottom.kind);
+        assert_eq!("%H:%M", axis_bottom.time_format);
+        assert_eq!(
+            Some(Domain::DomainTime(DomainTime {
+                start: Some(Timestamp {
+                    seconds: 1614556800,
+                    nanos: 0
+                }),
+                end: Some(Timestamp {
+                    seconds: 1614639600,
+                    nanos: 500_000_000
+                }),
+            })),
+            axis_bottom.domain
+        );
+        match &r_req.views[0].values {
+            Some(Values::PointsValues(points_values)) => assert_eq!(
+                Some(Timestamp {
+                    seconds: 1614600000,
+                    nanos: 0
+                }),
+                points_values.points[0].time
+            ),
+            _ => panic!("expected points values"),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn timestamp_err() {
+        super::timestamp(&mut serde_json::Deserializer::from_str(r#""yesterday""#)).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn render_chart_request_err() {
+        serde_json::from_str::<RenderChartRequest>(r#"{"outputFormat": "GIF"}"#).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn render_chart_request_unknown_field_err() {
+        serde_json::from_str::<RenderChartRequest>(
+            r#"{"axes": {"axisLeft": {"kind": "LINEAR", "domainNumric": {"end": 10}}}}"#,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,305 @@
+use crate::color::{get_color, get_view_colors};
+use crate::draw::legend::{LegendEntry, LegendOrientation, LegendView};
+use crate::error::RendererError;
+use crate::margin::Margins;
+use crate::proto::render::chart_legend::{ChartLegendOrientation, ChartLegendPosition};
+use crate::proto::render::chart_view::Values;
+use crate::proto::render::{ChartLegend, ChartView};
+use lc_render::Color;
+
+const DEFAULT_LEGEND_FONT_SIZE: i32 = 14;
+const LEGEND_PADDING: f32 = 10_f32;
+
+// Get legend entry colors, the first specified color is used for a missing one.
+fn get_entry_colors(
+    fill_color: Option<Color>,
+    stroke_color: Option<Color>,
+) -> Option<(String, String)> {
+    let fill_color = fill_color.map(|color| color.to_string());
+    let stroke_color = stroke_color.map(|color| color.to_string());
+    match (fill_color, stroke_color) {
+        (Some(fill_color), Some(stroke_color)) => Some((fill_color, stroke_color)),
+        (Some(color), None) | (None, Some(color)) => Some((color.clone(), color)),
+        (None, None) => None,
+    }
+}
+
+// Get legend entries of named views and bars datasets in order of views.
+fn get_legend_entries(views: &[ChartView]) -> Result<Vec<LegendEntry>, RendererError> {
+    let mut entries = Vec::new();
+    for view in views.iter() {
+        if !view.name.is_empty() {
+            let view_colors = get_view_colors(view.colors.clone())?;
+            // Views without fill or stroke colors are shown with their point colors.
+            let colors = match (view_colors.fill_color, view_colors.stroke_color) {
+                (None, None) => {
+                    get_entry_colors(view_colors.point_fill_color, view_colors.point_stroke_color)
+                }
+                (fill_color, stroke_color) => get_entry_colors(fill_color, stroke_color),
+            };
+            match colors {
+                Some((fill_color, stroke_color)) => {
+                    entries.push(LegendEntry::new(&view.name, &fill_color, &stroke_color))
+                }
+                None => return Err(RendererError::LegendEntryColorIsNotSpecified),
+            }
+        }
+
+        if let Some(Values::BarsValues(bars_values)) = &view.values {
+            for dataset in bars_values.bars_datasets.iter() {
+                if dataset.name.is_empty() {
+                    continue;
+                }
+                let colors = match dataset.colors.clone() {
+                    Some(colors) => {
+                        get_entry_colors(get_color(colors.fill), get_color(colors.stroke))
+                    }
+                    None => None,
+                };
+                match colors {
+                    Some((fill_color, stroke_color)) => {
+                        entries.push(LegendEntry::new(&dataset.name, &fill_color, &stroke_color))
+                    }
+                    None => return Err(RendererError::LegendEntryColorIsNotSpecified),
+                }
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+// Get legend view from protobuf that is placed within margins or inside chart views.
+// Legend is not drawn if it's not set or there are no named views.
+pub(crate) fn get_legend_view(
+    legend: Option<ChartLegend>,
+    views: &[ChartView],
+    margins: &Margins,
+    view_width: i32,
+    view_height: i32,
+) -> Result<Option<LegendView>, RendererError> {
+    let legend = match legend {
+        Some(legend) => legend,
+        None => return Ok(None),
+    };
+    // Legend is placed at the top by default.
+    let position = match ChartLegendPosition::from_i32(legend.position) {
+        Some(position) => position,
+        None => return Err(RendererError::LegendPositionIsUnknown),
+    };
+    let orientation = match ChartLegendOrientation::from_i32(legend.orientation) {
+        Some(ChartLegendOrientation::UnspecifiedLegendOrientation) => match position {
+            ChartLegendPosition::UnspecifiedLegendPosition
+            | ChartLegendPosition::Top
+            | ChartLegendPosition::Bottom => LegendOrientation::Horizontal,
+            _ => LegendOrientation::Vertical,
+        },
+        Some(ChartLegendOrientation::Horizontal) => LegendOrientation::Horizontal,
+        Some(ChartLegendOrientation::Vertical) => LegendOrientation::Vertical,
+        None => return Err(RendererError::LegendOrientationIsUnknown),
+    };
+    let font_size = match legend.font_size {
+        Some(font_size) if font_size > 0 => font_size,
+        Some(_) => return Err(RendererError::LegendFontSizeIsInvalid),
+        None => DEFAULT_LEGEND_FONT_SIZE,
+    };
+
+    let entries = get_legend_entries(views)?;
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    let legend_view = LegendView::new(entries, orientation, font_size);
+
+    // Legend is centered along the chart side it's placed at.
+    let (width, height) = (legend_view.width(), legend_view.height());
+    let (view_width, view_height) = (view_width as f32, view_height as f32);
+    let (available_width, available_height, x, y) = match position {
+        ChartLegendPosition::UnspecifiedLegendPosition | ChartLegendPosition::Top => (
+            view_width,
+            margins.top as f32 - LEGEND_PADDING,
+            (view_width - width) / 2_f32,
+            -LEGEND_PADDING - height,
+        ),
+        ChartLegendPosition::Bottom => (
+            view_width,
+            margins.bottom as f32 - LEGEND_PADDING,
+            (view_width - width) / 2_f32,
+            view_height + margins.bottom as f32 - LEGEND_PADDING - height,
+        ),
+        ChartLegendPosition::Left => (
+            margins.left as f32 - LEGEND_PADDING,
+            view_height,
+            -(margins.left as f32) + LEGEND_PADDING,
+            (view_height - height) / 2_f32,
+        ),
+        ChartLegendPosition::Right => (
+            margins.right as f32 - LEGEND_PADDING,
+            view_height,
+            view_width + margins.right as f32 - LEGEND_PADDING - width,
+            (view_height - height) / 2_f32,
+        ),
+        ChartLegendPosition::InsideTopLeft => (
+            view_width - LEGEND_PADDING * 2_f32,
+            view_height - LEGEND_PADDING * 2_f32,
+            LEGEND_PADDING,
+            LEGEND_PADDING,
+        ),
+        ChartLegendPosition::InsideTopRight => (
+            view_width - LEGEND_PADDING * 2_f32,
+            view_height - LEGEND_PADDING * 2_f32,
+            view_width - LEGEND_PADDING - width,
+            LEGEND_PADDING,
+        ),
+        ChartLegendPosition::InsideBottomLeft => (
+            view_width - LEGEND_PADDING * 2_f32,
+            view_height - LEGEND_PADDING * 2_f32,
+            LEGEND_PADDING,
+            view_height - LEGEND_PADDING - height,
+        ),
+        ChartLegendPosition::InsideBottomRight => (
+            view_width - LEGEND_PADDING * 2_f32,
+            view_height - LEGEND_PADDING * 2_f32,
+            view_width - LEGEND_PADDING - width,
+            view_height - LEGEND_PADDING - height,
+        ),
+    };
+    if width > available_width || height > available_height {
+        return Err(RendererError::LegendDoesNotFitMargins);
+    }
+
+    Ok(Some(legend_view.set_position(x, y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::chart_element_color::ColorValue;
+    use crate::proto::render::chart_view_bars_values::{BarsDataset, ChartViewBarsColors};
+    use crate::proto::render::{ChartElementColor, ChartViewBarsValues, ChartViewColors};
+    use lc_render::View;
+
+    fn color(hex: &str) -> Option<ChartElementColor> {
+        Some(ChartElementColor {
+            color_value: Some(ColorValue::ColorHex(hex.to_string())),
+        })
+    }
+
+    fn margins() -> Margins {
+        Margins {
+            top: 40,
+            bottom: 40,
+            left: 80,
+            right: 20,
+        }
+    }
+
+    fn chart_views() -> Vec<ChartView> {
+        vec![
+            ChartView {
+                name: "line".to_string(),
+                colors: Some(ChartViewColors {
+                    fill: None,
+                    stroke: color("#226974"),
+                    point_fill: color("#1a888b"),
+                    point_stroke: color("#50c5b6"),
+                }),
+                ..ChartView::default()
+            },
+            ChartView {
+                colors: Some(ChartViewColors::default()),
+                values: Some(Values::BarsValues(ChartViewBarsValues {
+                    bars_datasets: vec![
+                        BarsDataset {
+                            values: vec![1_f32],
+                            colors: Some(ChartViewBarsColors {
+                                fill: color("#cc6633"),
+                                stroke: color("#ff9933"),
+                            }),
+                            name: "bars".to_string(),
+                            lower_bounds: Vec::new(),
+                            upper_bounds: Vec::new(),
+                        },
+                        BarsDataset {
+                            values: vec![2_f32],
+                            colors: None,
+                            name: String::new(),
+                            lower_bounds: Vec::new(),
+                            upper_bounds: Vec::new(),
+                        },
+                    ],
+                    categories: Vec::new(),
+                })),
+                ..ChartView::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn get_legend_view_basic() {
+        let legend_view = get_legend_view(
+            Some(ChartLegend {
+                position: ChartLegendPosition::Top as i32,
+                orientation: 0,
+                font_size: Some(10),
+            }),
+            &chart_views(),
+            &margins(),
+            200,
+            100,
+        )
+        .unwrap()
+        .unwrap();
+        let legend_svg = legend_view.to_svg().to_string();
+
+        assert!(legend_svg.contains(r#"transform="translate(56,-20)""#));
+        assert!(legend_svg.contains(r##"fill="#226974" height="10""##));
+        assert!(legend_svg.contains(r##"fill="#cc6633" height="10""##));
+        assert_eq!(2, legend_svg.matches("<rect").count());
+    }
+
+    #[test]
+    fn get_legend_view_inside() {
+        let legend_view = get_legend_view(
+            Some(ChartLegend {
+                position: ChartLegendPosition::InsideBottomRight as i32,
+                orientation: 0,
+                font_size: None,
+            }),
+            &chart_views(),
+            &margins(),
+            200,
+            100,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(legend_view
+            .to_svg()
+            .to_string()
+            .contains(r#"transform="translate(135.4,55)""#));
+    }
+
+    #[test]
+    fn get_legend_view_empty() {
+        assert!(get_legend_view(None, &chart_views(), &margins(), 200, 100)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_legend_view_err() {
+        get_legend_view(
+            Some(ChartLegend {
+                position: ChartLegendPosition::Right as i32,
+                orientation: 0,
+                font_size: None,
+            }),
+            &chart_views(),
+            &margins(),
+            200,
+            100,
+        )
+        .unwrap();
+    }
+}
@@ -3,33 +3,69 @@ extern crate slog;
 extern crate slog_async;
 extern crate slog_json;
 
+use crate::api::{ApiServer, MAX_CHART_TTL};
+use crate::cli::{run_render, CLI_RENDER_COMMAND};
+use crate::defaults::Defaults;
+use crate::http::{bind_http, serve_http};
+use crate::proto::render::chart_api_server::ChartApiServer;
 use crate::proto::render::chart_renderer_server::ChartRendererServer;
 use crate::renderer::RendererServer;
+use crate::storage::fs::FsStorage;
+use crate::storage::memory::MemoryStorage;
+use crate::storage::{evict_expired, ChartStorage};
 use slog::{Drain, FnValue, PushFnValue, Record};
 use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::signal;
-use tokio::sync::oneshot::{self, Receiver, Sender};
+use tokio::sync::watch::{self, Receiver, Sender};
 use tonic::transport::Server;
 
+mod annotation;
+mod api;
+mod axis;
 mod bar;
+mod cli;
 mod color;
+mod defaults;
+mod domain;
+mod draw;
 mod error;
+#[cfg(test)]
+mod fixture;
+mod histogram;
+mod http;
+mod json;
+mod legend;
 mod margin;
+mod output;
 mod point;
 mod proto;
 mod renderer;
 mod scale;
 mod size;
+mod storage;
+mod template;
+mod theme;
+mod validation;
 mod value;
 mod view;
 
 const ENV_LC_RENDERER_ADDR: &str = "LC_RENDERER_ADDR";
+const ENV_LC_RENDERER_HTTP_ADDR: &str = "LC_RENDERER_HTTP_ADDR";
+const ENV_LC_RENDERER_STORAGE_DIR: &str = "LC_RENDERER_STORAGE_DIR";
+const ENV_LC_RENDERER_CHART_TTL: &str = "LC_RENDERER_CHART_TTL";
+pub(crate) const ENV_LC_RENDERER_DEFAULTS_FILE: &str = "LC_RENDERER_DEFAULTS_FILE";
+
+const DEFAULT_CHART_TTL_SECS: u64 = 24 * 60 * 60;
+const EVICTION_PERIOD: Duration = Duration::from_secs(10);
 
 pub fn signal_channel() -> (Sender<()>, Receiver<()>) {
-    oneshot::channel()
+    watch::channel(())
 }
 
-pub async fn wait_for_signal(log: slog::Logger, tx: oneshot::Sender<()>) {
+pub async fn wait_for_signal(log: slog::Logger, tx: Sender<()>) {
     let _ = signal::ctrl_c().await;
     info!(log, "Got signal, exiting");
     let _ = tx.send(());
@@ -37,6 +73,12 @@ pub async fn wait_for_signal(log: slog::Logger, tx: oneshot::Sender<()>) {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Render a chart from file and exit if render command is used.
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some(CLI_RENDER_COMMAND) {
+        std::process::exit(run_render(&args[2..]));
+    }
+
     // Prepare logger.
     let drain = slog_json::Json::new(std::io::stdout())
         .set_pretty(false)
@@ -57,32 +99,86 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let log = slog::Logger::root(drain, o!("version" => env!("CARGO_PKG_VERSION")));
 
     // Catch signals.
-    let (signal_tx, singal_rx) = signal_channel();
-    let _ = tokio::spawn(wait_for_signal(log.clone(), signal_tx));
+    let (signal_tx, signal_rx) = signal_channel();
+    tokio::spawn(wait_for_signal(log.clone(), signal_tx));
 
     // Configure server address from env.
-    let addr = std::env::var(ENV_LC_RENDERER_ADDR).expect(&*format!(
-        "unable to read {} env variable",
-        ENV_LC_RENDERER_ADDR
-    ));
+    let addr = std::env::var(ENV_LC_RENDERER_ADDR)
+        .unwrap_or_else(|_| panic!("unable to read {} env variable", ENV_LC_RENDERER_ADDR));
     let socket_addr: SocketAddr = addr
         .parse()
-        .expect(&*format!("unable to use {} as socket address", addr));
+        .unwrap_or_else(|_| panic!("unable to use {} as socket address", addr));
+
+    // Configure chart storage from env.
+    // Charts are kept in memory if storage directory isn't set.
+    let storage: Arc<dyn ChartStorage> = match std::env::var(ENV_LC_RENDERER_STORAGE_DIR) {
+        Ok(dir) => {
+            info!(log, "Using filesystem chart storage"; "dir" => dir.clone());
+            Arc::new(FsStorage::new(log.clone(), dir).await?)
+        }
+        Err(_) => Arc::new(MemoryStorage::new()),
+    };
+    let chart_ttl = match std::env::var(ENV_LC_RENDERER_CHART_TTL) {
+        Ok(chart_ttl) => chart_ttl
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+            .filter(|ttl| *ttl <= MAX_CHART_TTL)
+            .unwrap_or_else(|| panic!("unable to use {} as chart ttl seconds", chart_ttl)),
+        Err(_) => Duration::from_secs(DEFAULT_CHART_TTL_SECS),
+    };
+    tokio::spawn(evict_expired(log.clone(), storage.clone(), EVICTION_PERIOD));
+
+    // Configure defaults of omitted request fields from env.
+    // Built-in defaults are used if defaults file isn't set.
+    let defaults = match std::env::var(ENV_LC_RENDERER_DEFAULTS_FILE) {
+        Ok(path) => Defaults::from_file(Path::new(&path))
+            .unwrap_or_else(|err| panic!("unable to use {} as defaults file: {}", path, err)),
+        Err(_) => Defaults::default(),
+    };
+
+    // Start optional HTTP/JSON gateway if its address is set.
+    if let Ok(http_addr) = std::env::var(ENV_LC_RENDERER_HTTP_ADDR) {
+        let http_socket_addr: SocketAddr = http_addr
+            .parse()
+            .unwrap_or_else(|_| panic!("unable to use {} as socket address", http_addr));
+        let http_server = bind_http(&http_socket_addr)?;
+        let http_log = log.clone();
+        let http_defaults = defaults.clone();
+        let mut http_signal_rx = signal_rx.clone();
+        info!(log, "HTTP server is started"; "addr" => http_addr);
+        tokio::spawn(async move {
+            let shutdown = async move {
+                http_signal_rx.changed().await.ok();
+            };
+            if let Err(err) =
+                serve_http(http_log.clone(), http_server, http_defaults, shutdown).await
+            {
+                error!(http_log, "HTTP server is stopped"; "err" => err.to_string());
+            }
+        });
+    }
 
     // Prepare health reporter service.
     let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
     health_reporter
         .set_serving::<ChartRendererServer<RendererServer>>()
         .await;
+    health_reporter
+        .set_serving::<ChartApiServer<ApiServer>>()
+        .await;
 
     // Start GRPC server.
     info!(log, "Server is started"; "addr" => addr);
-    let renderer_server = RendererServer::new(log);
+    let renderer_server = RendererServer::new(log.clone(), defaults.clone());
+    let api_server = ApiServer::new(log, storage, chart_ttl, defaults);
     Server::builder()
         .add_service(health_service)
         .add_service(ChartRendererServer::new(renderer_server))
-        .serve_with_shutdown(socket_addr, async {
-            singal_rx.await.ok();
+        .add_service(ChartApiServer::new(api_server))
+        .serve_with_shutdown(socket_addr, async move {
+            let mut signal_rx = signal_rx;
+            signal_rx.changed().await.ok();
         })
         .await?;
 
@@ -0,0 +1,255 @@
+use crate::error::RendererError;
+use crate::proto::render::ChartOutputFormat;
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{fontdb, Options, Tree};
+use std::sync::{Arc, OnceLock};
+use svg2pdf::{ConversionOptions, PageOptions};
+
+pub(crate) const CONTENT_TYPE_SVG: &str = "image/svg+xml";
+pub(crate) const CONTENT_TYPE_PNG: &str = "image/png";
+pub(crate) const CONTENT_TYPE_PDF: &str = "application/pdf";
+
+const DEFAULT_OUTPUT_SCALE: f32 = 1_f32;
+const MAX_OUTPUT_SCALE: f32 = 10_f32;
+
+// Maximum pixel count of a raster image, it limits memory that is allocated for a single chart.
+const MAX_OUTPUT_PIXELS: f64 = 40_000_000_f64;
+
+// PDF points are used as SVG pixels so the page has the same sizes as the chart.
+const PDF_DPI: f32 = 72_f32;
+
+const FONT_FAMILY: &str = "DejaVu Sans";
+const FONT_DATA: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+
+static FONT_DB: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+
+/// Output contains rendered chart bytes with their content type.
+pub(crate) struct Output {
+    pub(crate) data: Vec<u8>,
+    pub(crate) content_type: &'static str,
+}
+
+// Get output format from protobuf.
+pub(crate) fn get_output_format(output_format: i32) -> Result<ChartOutputFormat, RendererError> {
+    match ChartOutputFormat::from_i32(output_format) {
+        Some(ChartOutputFormat::UnspecifiedOutputFormat) => Ok(ChartOutputFormat::Svg),
+        Some(output_format) => Ok(output_format),
+        None => Err(RendererError::OutputFormatIsUnknown),
+    }
+}
+
+// Get output scale from protobuf.
+pub(crate) fn get_output_scale(output_scale: Option<f32>) -> Result<f32, RendererError> {
+    match output_scale {
+        Some(output_scale) if output_scale > 0_f32 && output_scale <= MAX_OUTPUT_SCALE => {
+            Ok(output_scale)
+        }
+        Some(_) => Err(RendererError::OutputScaleIsInvalid),
+        None => Ok(DEFAULT_OUTPUT_SCALE),
+    }
+}
+
+// Check that raster image of the chart with the output scale doesn't exceed the maximum pixel count.
+pub(crate) fn check_output_size(
+    output_format: ChartOutputFormat,
+    width: i32,
+    height: i32,
+    output_scale: f32,
+) -> Result<(), RendererError> {
+    if output_format != ChartOutputFormat::Png {
+        return Ok(());
+    }
+    let scale = output_scale as f64;
+    if width as f64 * scale * height as f64 * scale > MAX_OUTPUT_PIXELS {
+        return Err(RendererError::OutputSizeIsTooLarge);
+    }
+
+    Ok(())
+}
+
+// Convert chart SVG document into the requested output format.
+pub(crate) fn get_output(
+    svg: String,
+    output_format: ChartOutputFormat,
+    output_scale: f32,
+) -> Result<Output, RendererError> {
+    match output_format {
+        ChartOutputFormat::Png => Ok(Output {
+            data: svg_to_png(&svg, output_scale)?,
+            content_type: CONTENT_TYPE_PNG,
+        }),
+        ChartOutputFormat::Pdf => Ok(Output {
+            data: svg_to_pdf(&svg)?,
+            content_type: CONTENT_TYPE_PDF,
+        }),
+        _ => Ok(Output {
+            data: svg.into_bytes(),
+            content_type: CONTENT_TYPE_SVG,
+        }),
+    }
+}
+
+// Get fonts database with embedded fonts.
+// It's used instead of system fonts so rendered charts look the same everywhere.
+fn font_db() -> Arc<fontdb::Database> {
+    FONT_DB
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_font_data(FONT_DATA.to_vec());
+            db.set_sans_serif_family(FONT_FAMILY);
+            db.set_serif_family(FONT_FAMILY);
+            db.set_monospace_family(FONT_FAMILY);
+            Arc::new(db)
+        })
+        .clone()
+}
+
+// Parse SVG document with embedded fonts.
+pub(crate) fn parse_svg(svg: &str) -> Result<Tree, RendererError> {
+    let options = Options {
+        font_family: FONT_FAMILY.to_string(),
+        fontdb: font_db(),
+        ..Options::default()
+    };
+
+    Ok(Tree::from_str(svg, &options)?)
+}
+
+// Rasterize SVG document into PNG image.
+fn svg_to_png(svg: &str, scale: f32) -> Result<Vec<u8>, RendererError> {
+    let tree = parse_svg(svg)?;
+    let size = match tree.size().to_int_size().scale_by(scale) {
+        Some(size) => size,
+        None => return Err(RendererError::OutputSizeIsInvalid),
+    };
+    let mut pixmap = match Pixmap::new(size.width(), size.height()) {
+        Some(pixmap) => pixmap,
+        None => return Err(RendererError::OutputSizeIsInvalid),
+    };
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    match pixmap.encode_png() {
+        Ok(png) => Ok(png),
+        Err(err) => Err(RendererError::PngEncodeError(err.to_string())),
+    }
+}
+
+// Convert SVG document into a single page PDF document.
+// Text is embedded with its font so it stays selectable.
+fn svg_to_pdf(svg: &str) -> Result<Vec<u8>, RendererError> {
+    let tree = parse_svg(svg)?;
+    let conversion_options = ConversionOptions {
+        embed_text: true,
+        ..ConversionOptions::default()
+    };
+    let page_options = PageOptions { dpi: PDF_DPI };
+
+    Ok(svg2pdf::to_pdf(&tree, conversion_options, page_options)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+    fn svg() -> String {
+        r##"<svg height="20" viewBox="0 0 40 20" width="40" xmlns="http://www.w3.org/2000/svg">
+<rect fill="#5095e5" height="10" width="10" x="0" y="0"/>
+<text font-family="sans-serif" font-size="14px" x="10" y="10">Chart</text>
+</svg>"##
+            .to_string()
+    }
+
+    fn find(data: &[u8], pattern: &[u8]) -> bool {
+        data.windows(pattern.len()).any(|window| window == pattern)
+    }
+
+    #[test]
+    fn get_output_format_unspecified() {
+        let output_format =
+            get_output_format(ChartOutputFormat::UnspecifiedOutputFormat as i32).unwrap();
+
+        assert_eq!(ChartOutputFormat::Svg, output_format);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_output_format_unknown() {
+        get_output_format(100).unwrap();
+    }
+
+    #[test]
+    fn get_output_scale_basic() {
+        assert!((get_output_scale(None).unwrap() - 1_f32).abs() < f32::EPSILON);
+        assert!((get_output_scale(Some(2_f32)).unwrap() - 2_f32).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_output_scale_err() {
+        get_output_scale(Some(0_f32)).unwrap();
+    }
+
+    #[test]
+    fn check_output_size_basic() {
+        check_output_size(ChartOutputFormat::Png, 800, 600, 5_f32).unwrap();
+        check_output_size(ChartOutputFormat::Svg, 100000, 100000, 10_f32).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_output_size_err() {
+        check_output_size(ChartOutputFormat::Png, 10000, 10000, 1_f32).unwrap();
+    }
+
+    #[test]
+    fn get_output_svg() {
+        let output = get_output(svg(), ChartOutputFormat::Svg, 1_f32).unwrap();
+
+        assert_eq!(CONTENT_TYPE_SVG, output.content_type);
+        assert_eq!(svg().into_bytes(), output.data);
+    }
+
+    #[test]
+    fn get_output_png() {
+        let output = get_output(svg(), ChartOutputFormat::Png, 2_f32).unwrap();
+
+        assert_eq!(CONTENT_TYPE_PNG, output.content_type);
+        assert_eq!(PNG_SIGNATURE, &output.data[..PNG_SIGNATURE.len()]);
+
+        // Check image width and height from the IHDR chunk.
+        assert_eq!(
+            80,
+            u32::from_be_bytes([
+                output.data[16],
+                output.data[17],
+                output.data[18],
+                output.data[19]
+            ])
+        );
+        assert_eq!(
+            40,
+            u32::from_be_bytes([
+                output.data[20],
+                output.data[21],
+                output.data[22],
+                output.data[23]
+            ])
+        );
+    }
+
+    #[test]
+    fn get_output_pdf() {
+        let output = get_output(svg(), ChartOutputFormat::Pdf, 2_f32).unwrap();
+
+        assert_eq!(CONTENT_TYPE_PDF, output.content_type);
+        assert!(output.data.starts_with(b"%PDF-"));
+        assert!(find(&output.data, b"/MediaBox [0 0 40 20]"));
+        assert!(find(&output.data, b"/FontFile2"));
+    }
+}
@@ -45,6 +45,16 @@ mod tests {
             point_label_visible: Some(false),
             point_label_position: ChartViewPointLabelPosition::UnspecifiedPointLabelPosition as i32,
             values: None,
+            inner_radius: None,
+            start_angle: None,
+            slice_label_visible: None,
+            bar_layout: 0,
+            stack_group: String::new(),
+            stack_normalized: false,
+            name: String::new(),
+            horizontal_axis: 0,
+            vertical_axis: 0,
+            histogram_binning: None,
         }
     }
 
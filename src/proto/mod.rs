@@ -1 +1,8 @@
+#[allow(clippy::enum_variant_names, clippy::large_enum_variant)]
 pub(crate) mod render;
+
+pub(crate) mod google {
+    pub(crate) mod rpc {
+        include!("google.rpc.rs");
+    }
+}
@@ -1,131 +1,19 @@
-/// ChartScale represents options to configure chart scale.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ChartScale {
-    /// One of the available scale kinds.
-    #[prost(enumeration = "chart_scale::ChartScaleKind", tag = "1")]
-    pub kind: i32,
-    /// Start of the scale range.
-    #[prost(message, optional, tag = "2")]
-    pub range_start: ::core::option::Option<i32>,
-    /// End of the scale range.
-    #[prost(message, optional, tag = "3")]
-    pub range_end: ::core::option::Option<i32>,
-    /// Does this scale needs an offset from the start and end of an axis.
-    /// This is usually need for an area or line views.
-    #[prost(bool, tag = "7")]
-    pub no_boundaries_offset: bool,
-    /// Inner padding for categories.
-    #[prost(message, optional, tag = "8")]
-    pub inner_padding: ::core::option::Option<f32>,
-    /// Outer padding for categories.
-    #[prost(message, optional, tag = "9")]
-    pub outer_padding: ::core::option::Option<f32>,
-    /// Scale domain with one of available kind.
-    #[prost(oneof = "chart_scale::Domain", tags = "4, 5")]
-    pub domain: ::core::option::Option<chart_scale::Domain>,
-}
-/// Nested message and enum types in `ChartScale`.
-pub mod chart_scale {
-    /// ChartScaleKind contains available scale kinds.
-    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-    #[repr(i32)]
-    pub enum ChartScaleKind {
-        UnspecifiedScale = 0,
-        Linear = 1,
-        Band = 2,
-    }
-    /// Scale domain with one of available kind.
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Domain {
-        /// Numeric scale domain.
-        #[prost(message, tag = "4")]
-        DomainNumeric(super::DomainNumeric),
-        /// String scale domain categories.
-        #[prost(message, tag = "5")]
-        DomainCategories(super::DomainCategories),
-    }
-}
-/// DomainNumeric represents numeric scale domain.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DomainNumeric {
-    /// Start of the numeric scale domain.
-    #[prost(float, tag = "1")]
-    pub start: f32,
-    /// End of the numeric scale domain.
-    #[prost(float, tag = "2")]
-    pub end: f32,
-}
-/// DomainCategories represents string categorical scale domain.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DomainCategories {
-    #[prost(string, repeated, tag = "1")]
-    pub categories: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-}
-/// ChartSizes represents options to configure chart sizes.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ChartSizes {
-    /// Chart width.
-    #[prost(message, optional, tag = "1")]
-    pub width: ::core::option::Option<i32>,
-    /// Chart height.
-    #[prost(message, optional, tag = "2")]
-    pub height: ::core::option::Option<i32>,
-}
-/// ChartMargins represents options to configure chart margins.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ChartMargins {
-    /// Top margin.
-    #[prost(message, optional, tag = "1")]
-    pub margin_top: ::core::option::Option<i32>,
-    /// Bottom margin.
-    #[prost(message, optional, tag = "2")]
-    pub margin_bottom: ::core::option::Option<i32>,
-    /// Left margin.
-    #[prost(message, optional, tag = "3")]
-    pub margin_left: ::core::option::Option<i32>,
-    /// Right margin.
-    #[prost(message, optional, tag = "4")]
-    pub margin_right: ::core::option::Option<i32>,
-}
-/// ChartAxes represents options to configure chart axes.
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ChartAxes {
-    /// Configured scale for top axis.
-    #[prost(message, optional, tag = "1")]
-    pub axis_top: ::core::option::Option<ChartScale>,
-    /// Label for top axis.
-    #[prost(string, tag = "2")]
-    pub axis_top_label: ::prost::alloc::string::String,
-    /// Configured scale for bottom axis.
-    #[prost(message, optional, tag = "3")]
-    pub axis_bottom: ::core::option::Option<ChartScale>,
-    /// Label for bottom axis.
-    #[prost(string, tag = "4")]
-    pub axis_bottom_label: ::prost::alloc::string::String,
-    /// Configured scale for left axis.
-    #[prost(message, optional, tag = "5")]
-    pub axis_left: ::core::option::Option<ChartScale>,
-    /// Label for left axis.
-    #[prost(string, tag = "6")]
-    pub axis_left_label: ::prost::alloc::string::String,
-    /// Configured scale for right axis.
-    #[prost(message, optional, tag = "7")]
-    pub axis_right: ::core::option::Option<ChartScale>,
-    /// Label for right axis.
-    #[prost(string, tag = "8")]
-    pub axis_right_label: ::prost::alloc::string::String,
-}
 /// ChartElementColor represents options to configure color for chart elements.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartElementColor {
     /// Value of color.
     /// Can contain HEX or RGB value.
     #[prost(oneof = "chart_element_color::ColorValue", tags = "1, 2")]
+    #[serde(flatten)]
     pub color_value: ::core::option::Option<chart_element_color::ColorValue>,
 }
 /// Nested message and enum types in `ChartElementColor`.
 pub mod chart_element_color {
     /// RGB contains values for RGB color.
+    #[derive(serde::Deserialize)]
+    #[serde(default, deny_unknown_fields, rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Rgb {
         #[prost(uint32, tag = "1")]
@@ -137,6 +25,8 @@ pub mod chart_element_color {
     }
     /// Value of color.
     /// Can contain HEX or RGB value.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum ColorValue {
         #[prost(string, tag = "1")]
@@ -146,15 +36,22 @@ pub mod chart_element_color {
     }
 }
 /// ChartViewBarsValues represents options for bars values.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartViewBarsValues {
     /// Array of configured bars datasets.
     #[prost(message, repeated, tag = "1")]
     pub bars_datasets: ::prost::alloc::vec::Vec<chart_view_bars_values::BarsDataset>,
+    /// Categories of bars, they're used for auto domains of band scales.
+    #[prost(string, repeated, tag = "2")]
+    pub categories: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// Nested message and enum types in `ChartViewBarsValues`.
 pub mod chart_view_bars_values {
     /// ChartViewBarsColors represents options to configure bars values colors.
+    #[derive(serde::Deserialize)]
+    #[serde(default, deny_unknown_fields, rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ChartViewBarsColors {
         #[prost(message, optional, tag = "1")]
@@ -163,44 +60,131 @@ pub mod chart_view_bars_values {
         pub stroke: ::core::option::Option<super::ChartElementColor>,
     }
     /// BarsDataset represents a single dataset with several bars.
+    #[derive(serde::Deserialize)]
+    #[serde(default, deny_unknown_fields, rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct BarsDataset {
         #[prost(float, repeated, tag = "1")]
         pub values: ::prost::alloc::vec::Vec<f32>,
         #[prost(message, optional, tag = "2")]
         pub colors: ::core::option::Option<ChartViewBarsColors>,
+        /// Name of dataset series that is shown in the chart legend.
+        #[prost(string, tag = "3")]
+        pub name: ::prost::alloc::string::String,
+        /// Lower bounds of values that are drawn as error bars.
+        #[prost(float, repeated, tag = "4")]
+        pub lower_bounds: ::prost::alloc::vec::Vec<f32>,
+        /// Upper bounds of values that are drawn as error bars.
+        #[prost(float, repeated, tag = "5")]
+        pub upper_bounds: ::prost::alloc::vec::Vec<f32>,
     }
 }
 /// ChartViewPointsValues represents options for point values.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartViewPointsValues {
     /// Array of configured points.
     #[prost(message, repeated, tag = "1")]
     pub points: ::prost::alloc::vec::Vec<chart_view_points_values::Point>,
+    /// Lower bounds of y values of points, they're drawn as an interval band for line and area views
+    /// and as error bars for scatter views.
+    #[prost(float, repeated, tag = "2")]
+    pub lower_bounds: ::prost::alloc::vec::Vec<f32>,
+    /// Upper bounds of y values of points, they're drawn the same way as lower bounds.
+    #[prost(float, repeated, tag = "3")]
+    pub upper_bounds: ::prost::alloc::vec::Vec<f32>,
 }
 /// Nested message and enum types in `ChartViewPointsValues`.
 pub mod chart_view_points_values {
     /// Point represents parameters for a single point.
+    #[derive(serde::Deserialize)]
+    #[serde(default, deny_unknown_fields, rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Point {
         #[prost(float, tag = "1")]
         pub x: f32,
         #[prost(float, tag = "2")]
         pub y: f32,
+        /// Point time, it's used instead of x on time scales.
+        /// It should be between 0001-01-01T00:00:00Z and 9999-12-31T23:59:59Z.
+        #[prost(message, optional, tag = "3")]
+        #[serde(deserialize_with = "crate::json::timestamp")]
+        pub time: ::core::option::Option<::prost_types::Timestamp>,
+    }
+}
+/// ChartViewSlicesValues represents options for slices values of pie and donut views.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartViewSlicesValues {
+    /// Array of configured slices.
+    #[prost(message, repeated, tag = "1")]
+    pub slices: ::prost::alloc::vec::Vec<chart_view_slices_values::Slice>,
+}
+/// Nested message and enum types in `ChartViewSlicesValues`.
+pub mod chart_view_slices_values {
+    /// Slice represents a single category with its value.
+    #[derive(serde::Deserialize)]
+    #[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Slice {
+        #[prost(string, tag = "1")]
+        pub label: ::prost::alloc::string::String,
+        #[prost(float, tag = "2")]
+        pub value: f32,
+        #[prost(message, optional, tag = "3")]
+        pub fill: ::core::option::Option<super::ChartElementColor>,
     }
 }
 /// ChartViewScalarValues represents options for scalar values.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartViewScalarValues {
     /// Array of scalar values.
     #[prost(float, repeated, tag = "1")]
     pub values: ::prost::alloc::vec::Vec<f32>,
+    /// Categories of values, they're used for auto domains of band scales.
+    #[prost(string, repeated, tag = "2")]
+    pub categories: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Lower bounds of values, they're drawn as an interval band for line and area views.
+    #[prost(float, repeated, tag = "3")]
+    pub lower_bounds: ::prost::alloc::vec::Vec<f32>,
+    /// Upper bounds of values, they're drawn the same way as lower bounds.
+    #[prost(float, repeated, tag = "4")]
+    pub upper_bounds: ::prost::alloc::vec::Vec<f32>,
+}
+/// ChartViewValues represents values of a single view.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartViewValues {
+    /// View values with one of available kind of values.
+    #[prost(oneof = "chart_view_values::Values", tags = "1, 2, 3, 4")]
+    pub values: ::core::option::Option<chart_view_values::Values>,
+}
+/// Nested message and enum types in `ChartViewValues`.
+pub mod chart_view_values {
+    /// View values with one of available kind of values.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Values {
+        #[prost(message, tag = "1")]
+        ScalarValues(super::ChartViewScalarValues),
+        #[prost(message, tag = "2")]
+        PointsValues(super::ChartViewPointsValues),
+        #[prost(message, tag = "3")]
+        BarsValues(super::ChartViewBarsValues),
+        #[prost(message, tag = "4")]
+        SlicesValues(super::ChartViewSlicesValues),
+    }
 }
 /// ChartView represents options to configure chart view.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartView {
     /// One of the available view kinds.
     #[prost(enumeration = "chart_view::ChartViewKind", tag = "1")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_view::ChartViewKind, _>")]
     pub kind: i32,
     /// Configured colors for view.
     #[prost(message, optional, tag = "5")]
@@ -210,26 +194,80 @@ pub struct ChartView {
     pub bar_label_visible: ::core::option::Option<bool>,
     /// One of the available bar label positions for view with bars.
     #[prost(enumeration = "chart_view::ChartViewBarLabelPosition", tag = "7")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_view::ChartViewBarLabelPosition, _>"
+    )]
     pub bar_label_position: i32,
     /// Set point visibility for view with points.
     #[prost(message, optional, tag = "8")]
     pub point_visible: ::core::option::Option<bool>,
     /// One of the available point types for view with points.
     #[prost(enumeration = "chart_view::ChartViewPointType", tag = "9")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_view::ChartViewPointType, _>")]
     pub point_type: i32,
     /// Set point label visibility for view with points.
     #[prost(message, optional, tag = "10")]
     pub point_label_visible: ::core::option::Option<bool>,
     /// One of the available point label positions for view with points.
     #[prost(enumeration = "chart_view::ChartViewPointLabelPosition", tag = "11")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_view::ChartViewPointLabelPosition, _>"
+    )]
     pub point_label_position: i32,
+    /// Inner radius of donut view as a fraction of its outer radius.
+    #[prost(message, optional, tag = "13")]
+    pub inner_radius: ::core::option::Option<f32>,
+    /// Angle in degrees that the first slice of pie or donut view starts from.
+    /// Angles are counted clockwise from the top, 0 is used by default.
+    #[prost(message, optional, tag = "14")]
+    pub start_angle: ::core::option::Option<f32>,
+    /// Set slice label visibility for pie or donut view.
+    /// Slice labels contain slice label with its percentage of the total.
+    #[prost(message, optional, tag = "15")]
+    pub slice_label_visible: ::core::option::Option<bool>,
+    /// One of the available layouts of bars datasets for view with bars.
+    /// Datasets are stacked if it's not specified.
+    #[prost(enumeration = "chart_view::ChartViewBarLayout", tag = "16")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_view::ChartViewBarLayout, _>")]
+    pub bar_layout: i32,
+    /// Identifier of a stack group for area view.
+    /// Area views with the same stack group are stacked in order of views on a band scale.
+    #[prost(string, tag = "17")]
+    pub stack_group: ::prost::alloc::string::String,
+    /// Set values of stacked area views to percentages of the stack group totals.
+    /// All area views of a stack group should have the same normalization.
+    #[prost(bool, tag = "18")]
+    pub stack_normalized: bool,
+    /// Name of view series that is shown in the chart legend.
+    /// View is not shown in the legend if it's not set.
+    #[prost(string, tag = "19")]
+    pub name: ::prost::alloc::string::String,
+    /// Horizontal axis with the scale of view.
+    /// Top axis is used if it's not specified and it's set, bottom axis is used otherwise.
+    #[prost(enumeration = "chart_view::ChartViewHorizontalAxis", tag = "20")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_view::ChartViewHorizontalAxis, _>"
+    )]
+    pub horizontal_axis: i32,
+    /// Vertical axis with the scale of view.
+    /// Left axis is used if it's not specified and it's set, right axis is used otherwise.
+    #[prost(enumeration = "chart_view::ChartViewVerticalAxis", tag = "21")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_view::ChartViewVerticalAxis, _>")]
+    pub vertical_axis: i32,
+    /// Binning of scalar values of histogram view, they're treated as raw samples.
+    /// Sturges' rule is used if it's not specified.
+    #[prost(message, optional, tag = "22")]
+    pub histogram_binning: ::core::option::Option<ChartViewHistogramBinning>,
     /// View values with one of available kind of values.
-    #[prost(oneof = "chart_view::Values", tags = "2, 3, 4")]
+    #[prost(oneof = "chart_view::Values", tags = "2, 3, 4, 12")]
+    #[serde(flatten)]
     pub values: ::core::option::Option<chart_view::Values>,
 }
 /// Nested message and enum types in `ChartView`.
 pub mod chart_view {
     /// ChartViewKind contains available view kinds.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
     #[repr(i32)]
     pub enum ChartViewKind {
@@ -239,8 +277,13 @@ pub mod chart_view {
         Line = 3,
         Scatter = 4,
         VerticalBar = 5,
+        Pie = 6,
+        Donut = 7,
+        Histogram = 8,
     }
     /// ChartViewBarLabelPosition contains available view label positions.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
     #[repr(i32)]
     pub enum ChartViewBarLabelPosition {
@@ -251,7 +294,20 @@ pub mod chart_view {
         EndInside = 4,
         EndOutside = 5,
     }
+    /// ChartViewBarLayout contains available layouts of bars datasets.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartViewBarLayout {
+        UnspecifiedBarLayout = 0,
+        Stacked = 1,
+        Grouped = 2,
+        PercentStacked = 3,
+    }
     /// ChartViewPointType contains available view point types.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
     #[repr(i32)]
     pub enum ChartViewPointType {
@@ -261,6 +317,8 @@ pub mod chart_view {
         X = 3,
     }
     /// ChartViewPointLabelPosition contains available view point label positions.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
     #[repr(i32)]
     pub enum ChartViewPointLabelPosition {
@@ -274,7 +332,29 @@ pub mod chart_view {
         BottomLeft = 7,
         BottomRight = 8,
     }
+    /// ChartViewHorizontalAxis contains available horizontal axes that view can be bound to.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartViewHorizontalAxis {
+        UnspecifiedHorizontalAxis = 0,
+        AxisTop = 1,
+        AxisBottom = 2,
+    }
+    /// ChartViewVerticalAxis contains available vertical axes that view can be bound to.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartViewVerticalAxis {
+        UnspecifiedVerticalAxis = 0,
+        AxisLeft = 1,
+        AxisRight = 2,
+    }
     /// View values with one of available kind of values.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Values {
         #[prost(message, tag = "2")]
@@ -283,9 +363,53 @@ pub mod chart_view {
         PointsValues(super::ChartViewPointsValues),
         #[prost(message, tag = "4")]
         BarsValues(super::ChartViewBarsValues),
+        #[prost(message, tag = "12")]
+        SlicesValues(super::ChartViewSlicesValues),
+    }
+}
+/// ChartViewHistogramBinning represents options to split samples of histogram view into bins.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartViewHistogramBinning {
+    /// One of the available binning strategies, Sturges' rule is used if it's not specified.
+    #[prost(
+        enumeration = "chart_view_histogram_binning::ChartViewHistogramBinningKind",
+        tag = "1"
+    )]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_view_histogram_binning::ChartViewHistogramBinningKind, _>"
+    )]
+    pub kind: i32,
+    /// Count of equal bins between the minimum and the maximum samples for fixed count binning.
+    #[prost(message, optional, tag = "2")]
+    pub bin_count: ::core::option::Option<u32>,
+    /// Width of bins for fixed width binning, bin edges are multiples of the width.
+    #[prost(message, optional, tag = "3")]
+    pub bin_width: ::core::option::Option<f32>,
+    /// Set bin heights to probability densities instead of sample counts.
+    /// Areas of density bins sum up to one.
+    #[prost(bool, tag = "4")]
+    pub density: bool,
+}
+/// Nested message and enum types in `ChartViewHistogramBinning`.
+pub mod chart_view_histogram_binning {
+    /// ChartViewHistogramBinningKind contains available binning strategies.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartViewHistogramBinningKind {
+        UnspecifiedBinningKind = 0,
+        FixedCount = 1,
+        FixedWidth = 2,
+        Sturges = 3,
+        FreedmanDiaconis = 4,
     }
 }
 /// ChartViewColors represents options to configure view colors.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChartViewColors {
     /// View fill color.
@@ -301,59 +425,1132 @@ pub struct ChartViewColors {
     #[prost(message, optional, tag = "4")]
     pub point_stroke: ::core::option::Option<ChartElementColor>,
 }
-/// RenderChartRequest represents chart rendering request.
+/// ChartAnnotation represents a reference line, a filled range or a text label
+/// that is placed in data coordinates of chart axes.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RenderChartRequest {
-    /// ID of the request.
-    #[prost(string, tag = "1")]
-    pub request_id: ::prost::alloc::string::String,
-    /// Chart title.
-    #[prost(string, tag = "2")]
-    pub title: ::prost::alloc::string::String,
-    /// Configured chart sizes.
+pub struct ChartAnnotation {
+    /// One of the available annotation kinds.
+    #[prost(enumeration = "chart_annotation::ChartAnnotationKind", tag = "1")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_annotation::ChartAnnotationKind, _>"
+    )]
+    pub kind: i32,
+    /// Value on the horizontal axis.
+    #[prost(message, optional, tag = "2")]
+    pub x: ::core::option::Option<ChartAnnotationValue>,
+    /// End value of a vertical range on the horizontal axis.
     #[prost(message, optional, tag = "3")]
-    pub sizes: ::core::option::Option<ChartSizes>,
-    /// Configured chart margins.
+    pub x_end: ::core::option::Option<ChartAnnotationValue>,
+    /// Value on the vertical axis.
     #[prost(message, optional, tag = "4")]
-    pub margins: ::core::option::Option<ChartMargins>,
-    /// Configured chart axes.
+    pub y: ::core::option::Option<ChartAnnotationValue>,
+    /// End value of a horizontal range on the vertical axis.
     #[prost(message, optional, tag = "5")]
-    pub axes: ::core::option::Option<ChartAxes>,
-    /// Configured chart views.
-    #[prost(message, repeated, tag = "6")]
-    pub views: ::prost::alloc::vec::Vec<ChartView>,
+    pub y_end: ::core::option::Option<ChartAnnotationValue>,
+    /// Text of a text label, lines and ranges are labeled with it if it's set.
+    #[prost(string, tag = "6")]
+    pub text: ::prost::alloc::string::String,
+    /// Color of a line or a range border.
+    #[prost(message, optional, tag = "7")]
+    pub stroke: ::core::option::Option<ChartElementColor>,
+    /// Color of a range or a text label.
+    #[prost(message, optional, tag = "8")]
+    pub fill: ::core::option::Option<ChartElementColor>,
+    /// One of the available layers, annotations are drawn above views if it's not specified.
+    #[prost(enumeration = "chart_annotation::ChartAnnotationLayer", tag = "9")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_annotation::ChartAnnotationLayer, _>"
+    )]
+    pub layer: i32,
+    /// Horizontal axis with the scale of annotation, it's picked the same way as for views.
+    #[prost(enumeration = "chart_view::ChartViewHorizontalAxis", tag = "10")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_view::ChartViewHorizontalAxis, _>"
+    )]
+    pub horizontal_axis: i32,
+    /// Vertical axis with the scale of annotation, it's picked the same way as for views.
+    #[prost(enumeration = "chart_view::ChartViewVerticalAxis", tag = "11")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_view::ChartViewVerticalAxis, _>")]
+    pub vertical_axis: i32,
 }
-/// RenderChartReply represents chart rendering reply.
+/// Nested message and enum types in `ChartAnnotation`.
+pub mod chart_annotation {
+    /// ChartAnnotationKind contains available annotation kinds.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartAnnotationKind {
+        UnspecifiedAnnotationKind = 0,
+        /// Line across the views at `y` value.
+        HorizontalLine = 1,
+        /// Line across the views at `x` value.
+        VerticalLine = 2,
+        /// Filled range across the views from `y` to `y_end` value.
+        HorizontalRange = 3,
+        /// Filled range across the views from `x` to `x_end` value.
+        VerticalRange = 4,
+        /// Text label at `x` and `y` values.
+        Text = 5,
+    }
+    /// ChartAnnotationLayer contains available layers to draw annotations on.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartAnnotationLayer {
+        UnspecifiedAnnotationLayer = 0,
+        AboveViews = 1,
+        BelowViews = 2,
+    }
+}
+/// ChartAnnotationValue represents a value of annotation in data coordinates.
+/// Numbers are used on linear and log scales, categories on band scales and times on time scales.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct RenderChartReply {
-    /// ID of the request.
-    #[prost(string, tag = "1")]
-    pub request_id: ::prost::alloc::string::String,
-    /// Chart raw bytes representation.
-    #[prost(bytes = "vec", tag = "2")]
-    pub chart_data: ::prost::alloc::vec::Vec<u8>,
+pub struct ChartAnnotationValue {
+    #[prost(oneof = "chart_annotation_value::Value", tags = "1, 2, 3")]
+    #[serde(flatten)]
+    pub value: ::core::option::Option<chart_annotation_value::Value>,
 }
-#[doc = r" Generated client implementations."]
-pub mod chart_renderer_client {
-    #![allow(unused_variables, dead_code, missing_docs)]
-    use tonic::codegen::*;
-    #[doc = " ChartRenderer represents a service that uses lc-render library to create charts."]
-    pub struct ChartRendererClient<T> {
-        inner: tonic::client::Grpc<T>,
+/// Nested message and enum types in `ChartAnnotationValue`.
+pub mod chart_annotation_value {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(float, tag = "1")]
+        Number(f32),
+        #[prost(string, tag = "2")]
+        Category(::prost::alloc::string::String),
+        #[prost(message, tag = "3")]
+        #[serde(deserialize_with = "crate::json::oneof_timestamp")]
+        Time(::prost_types::Timestamp),
     }
-    impl ChartRendererClient<tonic::transport::Channel> {
-        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: std::convert::TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+}
+/// ChartScale represents options to configure chart scale.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartScale {
+    /// One of the available scale kinds.
+    #[prost(enumeration = "chart_scale::ChartScaleKind", tag = "1")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_scale::ChartScaleKind, _>")]
+    pub kind: i32,
+    /// Start of the scale range.
+    #[prost(message, optional, tag = "2")]
+    pub range_start: ::core::option::Option<i32>,
+    /// End of the scale range.
+    #[prost(message, optional, tag = "3")]
+    pub range_end: ::core::option::Option<i32>,
+    /// Does this scale needs an offset from the start and end of an axis.
+    /// This is usually need for an area or line views.
+    #[prost(bool, tag = "7")]
+    pub no_boundaries_offset: bool,
+    /// Inner padding for categories.
+    #[prost(message, optional, tag = "8")]
+    pub inner_padding: ::core::option::Option<f32>,
+    /// Outer padding for categories.
+    #[prost(message, optional, tag = "9")]
+    pub outer_padding: ::core::option::Option<f32>,
+    /// Base of the logarithmic scale between 2 and 100, 10 is used by default.
+    /// Domain of the logarithmic scale can span up to 100 powers of base.
+    #[prost(message, optional, tag = "10")]
+    pub log_base: ::core::option::Option<f32>,
+    /// Format of time scale labels with strftime specifiers, e.g. "%Y-%m-%d".
+    /// Format is chosen by the ticks interval if it's not set.
+    #[prost(string, tag = "12")]
+    pub time_format: ::prost::alloc::string::String,
+    /// Scale domain with one of available kind.
+    #[prost(oneof = "chart_scale::Domain", tags = "4, 5, 11, 13")]
+    #[serde(flatten)]
+    pub domain: ::core::option::Option<chart_scale::Domain>,
+}
+/// Nested message and enum types in `ChartScale`.
+pub mod chart_scale {
+    /// ChartScaleKind contains available scale kinds.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartScaleKind {
+        UnspecifiedScale = 0,
+        Linear = 1,
+        Band = 2,
+        Log = 3,
+        Time = 4,
     }
-    impl<T> ChartRendererClient<T>
-    where
+    /// Scale domain with one of available kind.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Domain {
+        /// Numeric scale domain.
+        #[prost(message, tag = "4")]
+        DomainNumeric(super::DomainNumeric),
+        /// String scale domain categories.
+        #[prost(message, tag = "5")]
+        DomainCategories(super::DomainCategories),
+        /// Time scale domain.
+        #[prost(message, tag = "11")]
+        DomainTime(super::DomainTime),
+        /// Domain that is computed from values of views on the scale.
+        #[prost(message, tag = "13")]
+        DomainAuto(super::DomainAuto),
+    }
+}
+/// DomainNumeric represents numeric scale domain.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DomainNumeric {
+    /// Start of the numeric scale domain.
+    #[prost(float, tag = "1")]
+    pub start: f32,
+    /// End of the numeric scale domain.
+    #[prost(float, tag = "2")]
+    pub end: f32,
+}
+/// DomainTime represents time scale domain.
+/// Times should be between 0001-01-01T00:00:00Z and 9999-12-31T23:59:59Z.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DomainTime {
+    /// Start of the time scale domain.
+    #[prost(message, optional, tag = "1")]
+    #[serde(deserialize_with = "crate::json::timestamp")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    /// End of the time scale domain.
+    #[prost(message, optional, tag = "2")]
+    #[serde(deserialize_with = "crate::json::timestamp")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// DomainCategories represents string categorical scale domain.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DomainCategories {
+    #[prost(string, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// DomainAuto represents scale domain that is computed from values of views on the scale.
+/// Numeric and time scales use the minimum and maximum of values, band scales use categories
+/// of scalar and bars values in order of their appearance.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DomainAuto {
+    /// Round numeric domain to nice values of ticks.
+    #[prost(bool, tag = "1")]
+    pub nice: bool,
+    /// Extend numeric domain to include zero.
+    #[prost(bool, tag = "2")]
+    pub include_zero: bool,
+}
+/// ChartSizes represents options to configure chart sizes.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartSizes {
+    /// Chart width.
+    #[prost(message, optional, tag = "1")]
+    pub width: ::core::option::Option<i32>,
+    /// Chart height.
+    #[prost(message, optional, tag = "2")]
+    pub height: ::core::option::Option<i32>,
+}
+/// ChartMargins represents options to configure chart margins.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartMargins {
+    /// Top margin.
+    #[prost(message, optional, tag = "1")]
+    pub margin_top: ::core::option::Option<i32>,
+    /// Bottom margin.
+    #[prost(message, optional, tag = "2")]
+    pub margin_bottom: ::core::option::Option<i32>,
+    /// Left margin.
+    #[prost(message, optional, tag = "3")]
+    pub margin_left: ::core::option::Option<i32>,
+    /// Right margin.
+    #[prost(message, optional, tag = "4")]
+    pub margin_right: ::core::option::Option<i32>,
+}
+/// ChartAxes represents options to configure chart axes.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartAxes {
+    /// Configured scale for top axis.
+    #[prost(message, optional, tag = "1")]
+    pub axis_top: ::core::option::Option<ChartScale>,
+    /// Label for top axis.
+    #[prost(string, tag = "2")]
+    pub axis_top_label: ::prost::alloc::string::String,
+    /// Configured scale for bottom axis.
+    #[prost(message, optional, tag = "3")]
+    pub axis_bottom: ::core::option::Option<ChartScale>,
+    /// Label for bottom axis.
+    #[prost(string, tag = "4")]
+    pub axis_bottom_label: ::prost::alloc::string::String,
+    /// Configured scale for left axis.
+    #[prost(message, optional, tag = "5")]
+    pub axis_left: ::core::option::Option<ChartScale>,
+    /// Label for left axis.
+    #[prost(string, tag = "6")]
+    pub axis_left_label: ::prost::alloc::string::String,
+    /// Configured scale for right axis.
+    #[prost(message, optional, tag = "7")]
+    pub axis_right: ::core::option::Option<ChartScale>,
+    /// Label for right axis.
+    #[prost(string, tag = "8")]
+    pub axis_right_label: ::prost::alloc::string::String,
+    /// Options for top axis.
+    #[prost(message, optional, tag = "9")]
+    pub axis_top_options: ::core::option::Option<ChartAxisOptions>,
+    /// Options for bottom axis.
+    #[prost(message, optional, tag = "10")]
+    pub axis_bottom_options: ::core::option::Option<ChartAxisOptions>,
+    /// Options for left axis.
+    #[prost(message, optional, tag = "11")]
+    pub axis_left_options: ::core::option::Option<ChartAxisOptions>,
+    /// Options for right axis.
+    #[prost(message, optional, tag = "12")]
+    pub axis_right_options: ::core::option::Option<ChartAxisOptions>,
+}
+/// ChartAxisOptions represents options to configure ticks, line and gridlines of an axis.
+/// Tick count, tick values and number format can be set only for linear and log axes.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartAxisOptions {
+    /// Approximate count of ticks, ticks are placed on round values.
+    /// It should be between 1 and 100.
+    #[prost(message, optional, tag = "1")]
+    pub tick_count: ::core::option::Option<u32>,
+    /// Values of ticks that are used instead of computed ones, there can be up to 100 of them.
+    #[prost(float, repeated, tag = "2")]
+    pub tick_values: ::prost::alloc::vec::Vec<f32>,
+    /// Format of tick labels, values are formatted without rounding if it's not set.
+    #[prost(message, optional, tag = "3")]
+    pub number_format: ::core::option::Option<ChartAxisNumberFormat>,
+    /// Rotation of tick labels in degrees clockwise.
+    #[prost(int32, tag = "4")]
+    pub tick_label_rotation: i32,
+    /// Hide the axis line, ticks are still drawn.
+    #[prost(bool, tag = "5")]
+    pub line_hidden: bool,
+    /// Draw gridlines across the chart views at every tick.
+    #[prost(bool, tag = "6")]
+    pub major_gridlines: bool,
+    /// Draw gridlines across the chart views between ticks.
+    #[prost(bool, tag = "7")]
+    pub minor_gridlines: bool,
+}
+/// ChartAxisNumberFormat represents options to format numeric tick labels.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartAxisNumberFormat {
+    /// One of the available number formats.
+    #[prost(
+        enumeration = "chart_axis_number_format::ChartAxisNumberFormatKind",
+        tag = "1"
+    )]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_axis_number_format::ChartAxisNumberFormatKind, _>"
+    )]
+    pub kind: i32,
+    /// Count of decimals after the point.
+    #[prost(uint32, tag = "2")]
+    pub decimals: u32,
+    /// Prefix of currency numbers, e.g. $.
+    #[prost(string, tag = "3")]
+    pub currency_prefix: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `ChartAxisNumberFormat`.
+pub mod chart_axis_number_format {
+    /// ChartAxisNumberFormatKind contains available number formats.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartAxisNumberFormatKind {
+        UnspecifiedNumberFormat = 0,
+        /// Number with fixed decimals, e.g. 1234.50.
+        Fixed = 1,
+        /// Number with SI suffix, e.g. 1.2k or 3M.
+        Si = 2,
+        /// Number multiplied by 100 with percent sign, e.g. 0.25 is 25%.
+        Percent = 3,
+        /// Number with fixed decimals and currency prefix, e.g. $1234.50.
+        Currency = 4,
+    }
+}
+/// ChartLegend represents options to configure chart legend.
+/// Legend contains named views and bars datasets.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartLegend {
+    /// One of the available legend positions.
+    /// Legend is placed at the top if it's not specified.
+    #[prost(enumeration = "chart_legend::ChartLegendPosition", tag = "1")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_legend::ChartLegendPosition, _>")]
+    pub position: i32,
+    /// One of the available legend orientations.
+    /// Legend is horizontal at the top or bottom and vertical at other positions if it's not specified.
+    #[prost(enumeration = "chart_legend::ChartLegendOrientation", tag = "2")]
+    #[serde(
+        deserialize_with = "crate::json::enumeration::<chart_legend::ChartLegendOrientation, _>"
+    )]
+    pub orientation: i32,
+    /// Font size of legend entries.
+    /// 14 is used if it's not specified.
+    #[prost(message, optional, tag = "3")]
+    pub font_size: ::core::option::Option<i32>,
+}
+/// Nested message and enum types in `ChartLegend`.
+pub mod chart_legend {
+    /// ChartLegendPosition contains available legend positions.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartLegendPosition {
+        UnspecifiedLegendPosition = 0,
+        Top = 1,
+        Bottom = 2,
+        Left = 3,
+        Right = 4,
+        InsideTopLeft = 5,
+        InsideTopRight = 6,
+        InsideBottomLeft = 7,
+        InsideBottomRight = 8,
+    }
+    /// ChartLegendOrientation contains available legend orientations.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartLegendOrientation {
+        UnspecifiedLegendOrientation = 0,
+        Horizontal = 1,
+        Vertical = 2,
+    }
+}
+/// ChartTheme represents options to configure default chart colors and fonts.
+/// Options of a named theme are overridden by set custom options.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartTheme {
+    /// One of the available named themes.
+    /// Light theme is used if it's not specified.
+    #[prost(enumeration = "chart_theme::ChartThemeName", tag = "1")]
+    #[serde(deserialize_with = "crate::json::enumeration::<chart_theme::ChartThemeName, _>")]
+    pub name: i32,
+    /// Categorical colors that are assigned to views, bars datasets and slices in order.
+    #[prost(message, repeated, tag = "2")]
+    pub palette: ::prost::alloc::vec::Vec<ChartElementColor>,
+    /// Chart background color.
+    #[prost(message, optional, tag = "3")]
+    pub background: ::core::option::Option<ChartElementColor>,
+    /// Font family of all chart texts.
+    #[prost(string, tag = "4")]
+    pub font_family: ::prost::alloc::string::String,
+    /// Color of all chart texts.
+    #[prost(message, optional, tag = "5")]
+    pub font_color: ::core::option::Option<ChartElementColor>,
+    /// Color of axis lines and ticks.
+    #[prost(message, optional, tag = "6")]
+    pub axis_color: ::core::option::Option<ChartElementColor>,
+    /// Color of grid lines.
+    #[prost(message, optional, tag = "7")]
+    pub grid_color: ::core::option::Option<ChartElementColor>,
+    /// Chart title color.
+    #[prost(message, optional, tag = "8")]
+    pub title_color: ::core::option::Option<ChartElementColor>,
+    /// Chart title font size.
+    #[prost(message, optional, tag = "9")]
+    pub title_font_size: ::core::option::Option<i32>,
+}
+/// Nested message and enum types in `ChartTheme`.
+pub mod chart_theme {
+    /// ChartThemeName contains available named themes.
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ChartThemeName {
+        UnspecifiedThemeName = 0,
+        Light = 1,
+        Dark = 2,
+        HighContrast = 3,
+    }
+}
+/// RenderChartRequest represents chart rendering request.
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartRequest {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Chart title.
+    #[prost(string, tag = "2")]
+    pub title: ::prost::alloc::string::String,
+    /// Configured chart sizes.
+    #[prost(message, optional, tag = "3")]
+    pub sizes: ::core::option::Option<ChartSizes>,
+    /// Configured chart margins.
+    #[prost(message, optional, tag = "4")]
+    pub margins: ::core::option::Option<ChartMargins>,
+    /// Configured chart axes.
+    #[prost(message, optional, tag = "5")]
+    pub axes: ::core::option::Option<ChartAxes>,
+    /// Configured chart views.
+    #[prost(message, repeated, tag = "6")]
+    pub views: ::prost::alloc::vec::Vec<ChartView>,
+    /// One of the available output formats.
+    /// SVG is used if it's not specified.
+    #[prost(enumeration = "ChartOutputFormat", tag = "7")]
+    #[serde(deserialize_with = "crate::json::enumeration::<ChartOutputFormat, _>")]
+    pub output_format: i32,
+    /// Scale factor for raster output formats.
+    /// Chart sizes are used as is if it's not specified.
+    #[prost(message, optional, tag = "8")]
+    pub output_scale: ::core::option::Option<f32>,
+    /// Configured chart legend.
+    /// Legend is not drawn if it's not set.
+    #[prost(message, optional, tag = "9")]
+    pub legend: ::core::option::Option<ChartLegend>,
+    /// Configured chart theme.
+    /// Colors that are not set in views are taken from the theme if it's set.
+    #[prost(message, optional, tag = "10")]
+    pub theme: ::core::option::Option<ChartTheme>,
+    /// Configured chart annotations.
+    #[prost(message, repeated, tag = "11")]
+    pub annotations: ::prost::alloc::vec::Vec<ChartAnnotation>,
+}
+/// RenderChartReply represents chart rendering reply.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartReply {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Chart raw bytes representation.
+    #[prost(bytes = "vec", tag = "2")]
+    pub chart_data: ::prost::alloc::vec::Vec<u8>,
+    /// Content type of the chart raw bytes, e.g. `image/svg+xml` or `image/png`.
+    #[prost(string, tag = "3")]
+    pub content_type: ::prost::alloc::string::String,
+    /// Paths of omitted request fields that were set with server defaults, e.g. `views[0].point_visible`.
+    #[prost(string, repeated, tag = "4")]
+    pub defaulted_fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RenderChartsRequest represents batch chart rendering request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartsRequest {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Charts to render.
+    #[prost(message, repeated, tag = "2")]
+    pub charts: ::prost::alloc::vec::Vec<RenderChartRequest>,
+}
+/// RenderChartsReply represents batch chart rendering reply.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartsReply {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Rendering results in the same order as requested charts.
+    #[prost(message, repeated, tag = "2")]
+    pub results: ::prost::alloc::vec::Vec<RenderChartResult>,
+}
+/// RenderChartResult represents rendering result of a single chart from the batch.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartResult {
+    /// ID of the chart request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Rendered chart or rendering error.
+    #[prost(oneof = "render_chart_result::Outcome", tags = "2, 3")]
+    pub outcome: ::core::option::Option<render_chart_result::Outcome>,
+}
+/// Nested message and enum types in `RenderChartResult`.
+pub mod render_chart_result {
+    /// Rendered chart or rendering error.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        /// Rendered chart.
+        #[prost(message, tag = "2")]
+        Reply(super::RenderChartReply),
+        /// Error message if the chart can't be rendered.
+        #[prost(string, tag = "3")]
+        Error(::prost::alloc::string::String),
+    }
+}
+/// RenderChartStreamRequest represents a message of chart frames rendering stream.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartStreamRequest {
+    /// Base chart or values of its views.
+    #[prost(oneof = "render_chart_stream_request::Payload", tags = "1, 2")]
+    pub payload: ::core::option::Option<render_chart_stream_request::Payload>,
+}
+/// Nested message and enum types in `RenderChartStreamRequest`.
+pub mod render_chart_stream_request {
+    /// Base chart or values of its views.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        /// Base chart with sizes, margins, axes and views.
+        /// It should be sent in the first message of the stream, values of its views are not used.
+        /// Auto domains of its axes are computed from values of every frame.
+        #[prost(message, tag = "1")]
+        Chart(super::RenderChartRequest),
+        /// Values of the base chart views to render the next frame.
+        #[prost(message, tag = "2")]
+        Frame(super::RenderChartFrame),
+    }
+}
+/// RenderChartFrame represents values of the chart views for a single frame.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenderChartFrame {
+    /// ID of the frame request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Values for every view in the same order as views of the base chart.
+    #[prost(message, repeated, tag = "2")]
+    pub views_values: ::prost::alloc::vec::Vec<ChartViewValues>,
+}
+/// ValidateChartReply represents chart validation reply.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateChartReply {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Violations of invalid chart fields.
+    /// Chart is valid if there are no violations.
+    #[prost(message, repeated, tag = "2")]
+    pub field_violations: ::prost::alloc::vec::Vec<super::google::rpc::bad_request::FieldViolation>,
+    /// Layout of the chart, it's set only if the chart is valid.
+    #[prost(message, optional, tag = "3")]
+    pub layout: ::core::option::Option<ChartLayout>,
+    /// Paths of omitted request fields that were set with server defaults, e.g. `views[0].point_visible`.
+    #[prost(string, repeated, tag = "4")]
+    pub defaulted_fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// ChartLayout represents computed layout of a chart.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartLayout {
+    /// Width of the plot area within chart margins.
+    #[prost(int32, tag = "1")]
+    pub plot_width: i32,
+    /// Height of the plot area within chart margins.
+    #[prost(int32, tag = "2")]
+    pub plot_height: i32,
+    /// Resolved scales of the chart axes, scales of axes that are not set are empty.
+    #[prost(message, optional, tag = "3")]
+    pub axis_top: ::core::option::Option<ChartLayoutScale>,
+    #[prost(message, optional, tag = "4")]
+    pub axis_bottom: ::core::option::Option<ChartLayoutScale>,
+    #[prost(message, optional, tag = "5")]
+    pub axis_left: ::core::option::Option<ChartLayoutScale>,
+    #[prost(message, optional, tag = "6")]
+    pub axis_right: ::core::option::Option<ChartLayoutScale>,
+}
+/// ChartLayoutScale represents resolved scale of a chart axis.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartLayoutScale {
+    /// Kind of the scale.
+    #[prost(enumeration = "chart_scale::ChartScaleKind", tag = "1")]
+    pub kind: i32,
+    /// Start of the scale range.
+    #[prost(int32, tag = "2")]
+    pub range_start: i32,
+    /// End of the scale range.
+    #[prost(int32, tag = "3")]
+    pub range_end: i32,
+    /// Domain of the scale.
+    #[prost(oneof = "chart_layout_scale::Domain", tags = "4, 5, 6")]
+    pub domain: ::core::option::Option<chart_layout_scale::Domain>,
+}
+/// Nested message and enum types in `ChartLayoutScale`.
+pub mod chart_layout_scale {
+    /// Domain of the scale.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Domain {
+        /// Numeric scale domain.
+        #[prost(message, tag = "4")]
+        DomainNumeric(super::DomainNumeric),
+        /// Band scale domain.
+        #[prost(message, tag = "5")]
+        DomainCategories(super::DomainCategories),
+        /// Time scale domain.
+        #[prost(message, tag = "6")]
+        DomainTime(super::DomainTime),
+    }
+}
+/// ChartOutputFormat contains available formats of rendered charts.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChartOutputFormat {
+    UnspecifiedOutputFormat = 0,
+    Svg = 1,
+    Png = 2,
+    Pdf = 3,
+}
+#[doc = r" Generated client implementations."]
+pub mod chart_renderer_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = " ChartRenderer represents a service that uses lc-render library to create charts."]
+    pub struct ChartRendererClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ChartRendererClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ChartRendererClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        #[doc = " Render chart and return its raw bytes representation."]
+        pub async fn render_chart(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenderChartRequest>,
+        ) -> Result<tonic::Response<super::RenderChartReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/render.ChartRenderer/RenderChart");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Render charts in parallel and return result of every chart."]
+        pub async fn render_charts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenderChartsRequest>,
+        ) -> Result<tonic::Response<super::RenderChartsReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/render.ChartRenderer/RenderCharts");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Render a frame for every set of view values using the same base chart."]
+        pub async fn render_chart_stream(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RenderChartStreamRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::RenderChartReply>>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/render.ChartRenderer/RenderChartStream");
+            self.inner
+                .streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " Validate chart without rendering it and return its layout or violations of invalid fields."]
+        pub async fn validate_chart(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenderChartRequest>,
+        ) -> Result<tonic::Response<super::ValidateChartReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/render.ChartRenderer/ValidateChart");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for ChartRendererClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for ChartRendererClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ChartRendererClient {{ ... }}")
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod chart_renderer_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ChartRendererServer."]
+    #[async_trait]
+    pub trait ChartRenderer: Send + Sync + 'static {
+        #[doc = " Render chart and return its raw bytes representation."]
+        async fn render_chart(
+            &self,
+            request: tonic::Request<super::RenderChartRequest>,
+        ) -> Result<tonic::Response<super::RenderChartReply>, tonic::Status>;
+        #[doc = " Render charts in parallel and return result of every chart."]
+        async fn render_charts(
+            &self,
+            request: tonic::Request<super::RenderChartsRequest>,
+        ) -> Result<tonic::Response<super::RenderChartsReply>, tonic::Status>;
+        #[doc = "Server streaming response type for the RenderChartStream method."]
+        type RenderChartStreamStream: futures_core::Stream<Item = Result<super::RenderChartReply, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " Render a frame for every set of view values using the same base chart."]
+        async fn render_chart_stream(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RenderChartStreamRequest>>,
+        ) -> Result<tonic::Response<Self::RenderChartStreamStream>, tonic::Status>;
+        #[doc = " Validate chart without rendering it and return its layout or violations of invalid fields."]
+        async fn validate_chart(
+            &self,
+            request: tonic::Request<super::RenderChartRequest>,
+        ) -> Result<tonic::Response<super::ValidateChartReply>, tonic::Status>;
+    }
+    #[doc = " ChartRenderer represents a service that uses lc-render library to create charts."]
+    #[derive(Debug)]
+    pub struct ChartRendererServer<T: ChartRenderer> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: ChartRenderer> ChartRendererServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ChartRendererServer<T>
+    where
+        T: ChartRenderer,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/render.ChartRenderer/RenderChart" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenderChartSvc<T: ChartRenderer>(pub Arc<T>);
+                    impl<T: ChartRenderer> tonic::server::UnaryService<super::RenderChartRequest>
+                        for RenderChartSvc<T>
+                    {
+                        type Response = super::RenderChartReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenderChartRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).render_chart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RenderChartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/render.ChartRenderer/RenderCharts" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenderChartsSvc<T: ChartRenderer>(pub Arc<T>);
+                    impl<T: ChartRenderer> tonic::server::UnaryService<super::RenderChartsRequest>
+                        for RenderChartsSvc<T>
+                    {
+                        type Response = super::RenderChartsReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenderChartsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).render_charts(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RenderChartsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/render.ChartRenderer/RenderChartStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct RenderChartStreamSvc<T: ChartRenderer>(pub Arc<T>);
+                    impl<T: ChartRenderer>
+                        tonic::server::StreamingService<super::RenderChartStreamRequest>
+                        for RenderChartStreamSvc<T>
+                    {
+                        type Response = super::RenderChartReply;
+                        type ResponseStream = T::RenderChartStreamStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::RenderChartStreamRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).render_chart_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1;
+                        let inner = inner.0;
+                        let method = RenderChartStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/render.ChartRenderer/ValidateChart" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidateChartSvc<T: ChartRenderer>(pub Arc<T>);
+                    impl<T: ChartRenderer> tonic::server::UnaryService<super::RenderChartRequest>
+                        for ValidateChartSvc<T>
+                    {
+                        type Response = super::ValidateChartReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RenderChartRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).validate_chart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ValidateChartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: ChartRenderer> Clone for ChartRendererServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: ChartRenderer> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ChartRenderer> tonic::transport::NamedService for ChartRendererServer<T> {
+        const NAME: &'static str = "render.ChartRenderer";
+    }
+}
+/// CreateChartRequest represents chart creation request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateChartRequest {
+    /// Chart title.
+    #[prost(string, tag = "1")]
+    pub title: ::prost::alloc::string::String,
+    /// Configured chart sizes.
+    #[prost(message, optional, tag = "2")]
+    pub sizes: ::core::option::Option<ChartSizes>,
+    /// Configured chart margins.
+    #[prost(message, optional, tag = "3")]
+    pub margins: ::core::option::Option<ChartMargins>,
+    /// Configured chart axes.
+    #[prost(message, optional, tag = "4")]
+    pub axes: ::core::option::Option<ChartAxes>,
+    /// Configured chart views.
+    #[prost(message, repeated, tag = "5")]
+    pub views: ::prost::alloc::vec::Vec<ChartView>,
+    /// How long the created chart will be available.
+    /// Server default value is used if it's not set, it can't be longer than 366 days.
+    #[prost(message, optional, tag = "6")]
+    pub ttl: ::core::option::Option<::prost_types::Duration>,
+    /// Configured chart legend.
+    /// Legend is not drawn if it's not set.
+    #[prost(message, optional, tag = "7")]
+    pub legend: ::core::option::Option<ChartLegend>,
+    /// Configured chart theme.
+    /// Colors that are not set in views are taken from the theme if it's set.
+    #[prost(message, optional, tag = "8")]
+    pub theme: ::core::option::Option<ChartTheme>,
+    /// Configured chart annotations.
+    #[prost(message, repeated, tag = "9")]
+    pub annotations: ::prost::alloc::vec::Vec<ChartAnnotation>,
+}
+/// GetChartRequest represents chart get request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetChartRequest {
+    /// ID of the chart.
+    #[prost(string, tag = "1")]
+    pub chart_id: ::prost::alloc::string::String,
+}
+/// ChartReply represents chart reply from create or get requests.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChartReply {
+    /// ID of the request.
+    #[prost(string, tag = "1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// ID of the chart.
+    #[prost(string, tag = "2")]
+    pub chart_id: ::prost::alloc::string::String,
+    /// One of the available chart statuses.
+    #[prost(enumeration = "ChartStatus", tag = "3")]
+    pub chart_status: i32,
+    /// Chart creation timestamp.
+    #[prost(message, optional, tag = "4")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// When the chart will become unavailable.
+    /// It depends of `ttl` value that was provided in chart create request.
+    /// It equals to `created_at` value if `ttl` was set zero.
+    #[prost(message, optional, tag = "5")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// Chart raw bytes representation.
+    #[prost(bytes = "vec", tag = "6")]
+    pub chart_data: ::prost::alloc::vec::Vec<u8>,
+    /// Paths of omitted request fields that were set with server defaults, e.g. `views[0].point_visible`.
+    #[prost(string, repeated, tag = "7")]
+    pub defaulted_fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// ChartStatus contains available chart statuses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChartStatus {
+    UnspecifiedStatus = 0,
+    Created = 1,
+    Error = 2,
+}
+#[doc = r" Generated client implementations."]
+pub mod chart_api_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = " ChartAPI represents a service that provides public API for Limpidchart."]
+    pub struct ChartApiClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ChartApiClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ChartApiClient<T>
+    where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::ResponseBody: Body + HttpBody + Send + 'static,
         T::Error: Into<StdError>,
@@ -367,11 +1564,11 @@ pub mod chart_renderer_client {
             let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
             Self { inner }
         }
-        #[doc = " Render chart and return its raw bytes representation."]
-        pub async fn render_chart(
+        #[doc = " Create chart and return its raw bytes representation with additional metadata."]
+        pub async fn create_chart(
             &mut self,
-            request: impl tonic::IntoRequest<super::RenderChartRequest>,
-        ) -> Result<tonic::Response<super::RenderChartReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::CreateChartRequest>,
+        ) -> Result<tonic::Response<super::ChartReply>, tonic::Status> {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::new(
                     tonic::Code::Unknown,
@@ -379,43 +1576,63 @@ pub mod chart_renderer_client {
                 )
             })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/render.ChartRenderer/RenderChart");
+            let path = http::uri::PathAndQuery::from_static("/render.ChartAPI/CreateChart");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Get a created chart raw bytes representation with additional metadata."]
+        pub async fn get_chart(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetChartRequest>,
+        ) -> Result<tonic::Response<super::ChartReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/render.ChartAPI/GetChart");
             self.inner.unary(request.into_request(), path, codec).await
         }
     }
-    impl<T: Clone> Clone for ChartRendererClient<T> {
+    impl<T: Clone> Clone for ChartApiClient<T> {
         fn clone(&self) -> Self {
             Self {
                 inner: self.inner.clone(),
             }
         }
     }
-    impl<T> std::fmt::Debug for ChartRendererClient<T> {
+    impl<T> std::fmt::Debug for ChartApiClient<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "ChartRendererClient {{ ... }}")
+            write!(f, "ChartApiClient {{ ... }}")
         }
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod chart_renderer_server {
+pub mod chart_api_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ChartRendererServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ChartApiServer."]
     #[async_trait]
-    pub trait ChartRenderer: Send + Sync + 'static {
-        #[doc = " Render chart and return its raw bytes representation."]
-        async fn render_chart(
+    pub trait ChartApi: Send + Sync + 'static {
+        #[doc = " Create chart and return its raw bytes representation with additional metadata."]
+        async fn create_chart(
             &self,
-            request: tonic::Request<super::RenderChartRequest>,
-        ) -> Result<tonic::Response<super::RenderChartReply>, tonic::Status>;
+            request: tonic::Request<super::CreateChartRequest>,
+        ) -> Result<tonic::Response<super::ChartReply>, tonic::Status>;
+        #[doc = " Get a created chart raw bytes representation with additional metadata."]
+        async fn get_chart(
+            &self,
+            request: tonic::Request<super::GetChartRequest>,
+        ) -> Result<tonic::Response<super::ChartReply>, tonic::Status>;
     }
-    #[doc = " ChartRenderer represents a service that uses lc-render library to create charts."]
+    #[doc = " ChartAPI represents a service that provides public API for Limpidchart."]
     #[derive(Debug)]
-    pub struct ChartRendererServer<T: ChartRenderer> {
+    pub struct ChartApiServer<T: ChartApi> {
         inner: _Inner<T>,
     }
     struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
-    impl<T: ChartRenderer> ChartRendererServer<T> {
+    impl<T: ChartApi> ChartApiServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner, None);
@@ -427,9 +1644,9 @@ pub mod chart_renderer_server {
             Self { inner }
         }
     }
-    impl<T, B> Service<http::Request<B>> for ChartRendererServer<T>
+    impl<T, B> Service<http::Request<B>> for ChartApiServer<T>
     where
-        T: ChartRenderer,
+        T: ChartApi,
         B: HttpBody + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -442,20 +1659,18 @@ pub mod chart_renderer_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/render.ChartRenderer/RenderChart" => {
+                "/render.ChartAPI/CreateChart" => {
                     #[allow(non_camel_case_types)]
-                    struct RenderChartSvc<T: ChartRenderer>(pub Arc<T>);
-                    impl<T: ChartRenderer> tonic::server::UnaryService<super::RenderChartRequest>
-                        for RenderChartSvc<T>
-                    {
-                        type Response = super::RenderChartReply;
+                    struct CreateChartSvc<T: ChartApi>(pub Arc<T>);
+                    impl<T: ChartApi> tonic::server::UnaryService<super::CreateChartRequest> for CreateChartSvc<T> {
+                        type Response = super::ChartReply;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RenderChartRequest>,
+                            request: tonic::Request<super::CreateChartRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).render_chart(request).await };
+                            let fut = async move { (*inner).create_chart(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -463,7 +1678,38 @@ pub mod chart_renderer_server {
                     let fut = async move {
                         let interceptor = inner.1.clone();
                         let inner = inner.0;
-                        let method = RenderChartSvc(inner);
+                        let method = CreateChartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/render.ChartAPI/GetChart" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetChartSvc<T: ChartApi>(pub Arc<T>);
+                    impl<T: ChartApi> tonic::server::UnaryService<super::GetChartRequest> for GetChartSvc<T> {
+                        type Response = super::ChartReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetChartRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_chart(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetChartSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = if let Some(interceptor) = interceptor {
                             tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -486,13 +1732,13 @@ pub mod chart_renderer_server {
             }
         }
     }
-    impl<T: ChartRenderer> Clone for ChartRendererServer<T> {
+    impl<T: ChartApi> Clone for ChartApiServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self { inner }
         }
     }
-    impl<T: ChartRenderer> Clone for _Inner<T> {
+    impl<T: ChartApi> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone(), self.1.clone())
         }
@@ -502,7 +1748,7 @@ pub mod chart_renderer_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ChartRenderer> tonic::transport::NamedService for ChartRendererServer<T> {
-        const NAME: &'static str = "render.ChartRenderer";
+    impl<T: ChartApi> tonic::transport::NamedService for ChartApiServer<T> {
+        const NAME: &'static str = "render.ChartAPI";
     }
 }
@@ -1,154 +1,593 @@
+use crate::defaults::Defaults;
+use crate::domain::has_auto_domains;
 use crate::error::RendererError;
-use crate::margin::get_margins;
+use crate::output::Output;
 use crate::proto::render::chart_renderer_server::ChartRenderer;
-use crate::proto::render::{RenderChartReply, RenderChartRequest};
-use crate::scale::{
-    get_h_scale, get_v_scale, set_chart_bottom_axis, set_chart_left_axis, set_chart_right_axis,
-    set_chart_top_axis,
+use crate::proto::render::render_chart_result::Outcome;
+use crate::proto::render::render_chart_stream_request::Payload;
+use crate::proto::render::{
+    ChartLayout, ChartViewValues, RenderChartReply, RenderChartRequest, RenderChartResult,
+    RenderChartStreamRequest, RenderChartsReply, RenderChartsRequest, ValidateChartReply,
 };
-use crate::size::get_sizes;
-use crate::view::get_views;
-use lc_render::Chart;
-use tonic::{Request, Response, Status};
+use crate::template::ChartTemplate;
+use crate::validation::{get_field_violations, get_invalid_argument_status};
+use crate::value::get_frame_views;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
 
-const ERR_UNABLE_TO_RENDER_CHART: &str = "Unable to render chart";
+pub(crate) const ERR_UNABLE_TO_RENDER_CHART: &str = "Unable to render chart";
 
-const LOG_KEY_ERR: &str = "err";
-const LOG_KEY_REQ_ID: &str = "request_id";
+pub(crate) const LOG_KEY_ERR: &str = "err";
+pub(crate) const LOG_KEY_REQ_ID: &str = "request_id";
+
+const ERR_UNABLE_TO_READ_CHART_STREAM: &str = "Unable to read chart stream";
+
+const LOG_KEY_CHART_REQ_ID: &str = "chart_request_id";
+
+// Maximum amount of charts in a single RenderCharts request.
+const MAX_CHARTS_PER_REQUEST: usize = 64;
+
+// Amount of rendered frames that can wait to be sent to the client.
+const CHART_STREAM_BUFFER_SIZE: usize = 16;
 
 #[derive(Debug)]
 pub struct RendererServer {
     log: slog::Logger,
+    defaults: Defaults,
 }
 
 impl RendererServer {
-    pub(crate) fn new(log: slog::Logger) -> RendererServer {
-        RendererServer { log }
+    pub(crate) fn new(log: slog::Logger, defaults: Defaults) -> RendererServer {
+        RendererServer { log, defaults }
     }
 }
 
 #[tonic::async_trait]
 impl ChartRenderer for RendererServer {
+    type RenderChartStreamStream = ReceiverStream<Result<RenderChartReply, Status>>;
+
     async fn render_chart(
         &self,
         request: Request<RenderChartRequest>,
     ) -> Result<Response<RenderChartReply>, Status> {
-        // Convert a request into RenderChartRequest.
-        let r_req = request.into_inner();
+        // Convert a request into RenderChartRequest and fill its omitted fields.
+        let mut r_req = request.into_inner();
+        let defaulted_fields = self.defaults.apply(&mut r_req);
 
         // Prepare request logger with request_id set.
         let log = self.log.new(o!(LOG_KEY_REQ_ID => r_req.request_id.clone()));
 
-        // Get chart scales.
-        let axes = match r_req.axes {
-            Some(axes) => axes,
-            None => {
-                let err = RendererError::ChartAxesAreNotSpecified.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
-            }
-        };
-        let h_scale = match get_h_scale(&axes) {
-            Ok(h_scale) => h_scale,
+        let output = match render(&r_req) {
+            Ok(output) => output,
             Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
-            }
-        };
-        let v_scale = match get_v_scale(&axes) {
-            Ok(v_scale) => v_scale,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.to_string());
+                return Err(get_invalid_argument_status(&r_req, &err));
             }
         };
 
-        // Get chart sizes.
-        let sizes = match get_sizes(r_req.sizes) {
-            Ok(sizes) => sizes,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+        Ok(Response::new(RenderChartReply {
+            request_id: r_req.request_id,
+            chart_data: output.data,
+            content_type: output.content_type.to_string(),
+            defaulted_fields,
+        }))
+    }
+
+    async fn render_charts(
+        &self,
+        request: Request<RenderChartsRequest>,
+    ) -> Result<Response<RenderChartsReply>, Status> {
+        // Convert a request into RenderChartsRequest.
+        let rs_req = request.into_inner();
+
+        // Prepare request logger with request_id set.
+        let log = self
+            .log
+            .new(o!(LOG_KEY_REQ_ID => rs_req.request_id.clone()));
+
+        if rs_req.charts.len() > MAX_CHARTS_PER_REQUEST {
+            let err = RendererError::ChartsCountIsTooLarge.to_string();
+            error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
+            return Err(Status::invalid_argument(err));
+        }
+
+        // Render every chart in a separate blocking task so charts are rendered in parallel.
+        let tasks: Vec<_> = rs_req
+            .charts
+            .into_iter()
+            .map(|mut r_req| {
+                let defaulted_fields = self.defaults.apply(&mut r_req);
+                (
+                    r_req.request_id.clone(),
+                    defaulted_fields,
+                    tokio::task::spawn_blocking(move || render(&r_req)),
+                )
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(tasks.len());
+        for (request_id, defaulted_fields, task) in tasks {
+            let outcome = match task.await {
+                Ok(Ok(output)) => Outcome::Reply(RenderChartReply {
+                    request_id: request_id.clone(),
+                    chart_data: output.data,
+                    content_type: output.content_type.to_string(),
+                    defaulted_fields,
+                }),
+                Ok(Err(err)) => Outcome::Error(err.to_string()),
+                Err(err) => Outcome::Error(err.to_string()),
+            };
+            if let Outcome::Error(err) = &outcome {
+                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART;
+                    LOG_KEY_CHART_REQ_ID => request_id.clone(),
+                    LOG_KEY_ERR => err.clone(),
+                );
             }
+            results.push(RenderChartResult {
+                request_id,
+                outcome: Some(outcome),
+            });
+        }
+
+        Ok(Response::new(RenderChartsReply {
+            request_id: rs_req.request_id,
+            results,
+        }))
+    }
+
+    async fn render_chart_stream(
+        &self,
+        request: Request<Streaming<RenderChartStreamRequest>>,
+    ) -> Result<Response<Self::RenderChartStreamStream>, Status> {
+        let (tx, rx) = mpsc::channel(CHART_STREAM_BUFFER_SIZE);
+        tokio::spawn(render_chart_frames(
+            self.log.clone(),
+            self.defaults.clone(),
+            request.into_inner(),
+            tx,
+        ));
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn validate_chart(
+        &self,
+        request: Request<RenderChartRequest>,
+    ) -> Result<Response<ValidateChartReply>, Status> {
+        // Convert a request into RenderChartRequest and fill its omitted fields.
+        let mut r_req = request.into_inner();
+        let defaulted_fields = self.defaults.apply(&mut r_req);
+
+        // Invalid charts are reported in the reply instead of an error status.
+        let (field_violations, layout) = match layout(&r_req) {
+            Ok(layout) => (Vec::new(), Some(layout)),
+            Err(err) => (get_field_violations(&r_req, &err), None),
         };
 
-        // Get chart margins.
-        let margins = match get_margins(r_req.margins) {
-            Ok(margins) => margins,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
-            }
+        Ok(Response::new(ValidateChartReply {
+            request_id: r_req.request_id,
+            field_violations,
+            layout,
+            defaulted_fields,
+        }))
+    }
+}
+
+// Base chart of the chart stream.
+// Charts with auto domains are prepared for every frame since their domains depend on
+// values of the frame, other charts are prepared once.
+enum StreamChart {
+    Template(Box<ChartTemplate>),
+    AutoDomains(Box<RenderChartRequest>),
+}
+
+impl StreamChart {
+    fn new(r_req: RenderChartRequest) -> Result<StreamChart, RendererError> {
+        if has_auto_domains(r_req.axes.as_ref()) {
+            return Ok(StreamChart::AutoDomains(Box::new(r_req)));
+        }
+
+        Ok(StreamChart::Template(Box::new(ChartTemplate::new(&r_req)?)))
+    }
+
+    // Render the base chart with values of the frame views.
+    fn render(&self, views_values: Vec<ChartViewValues>) -> Result<Output, RendererError> {
+        let frame_views = get_frame_views(views_values);
+        let r_req = match self {
+            StreamChart::Template(template) => return template.render(&frame_views),
+            StreamChart::AutoDomains(r_req) => r_req,
         };
+        if r_req.views.len() != frame_views.len() {
+            return Err(RendererError::ViewValuesCountIsInvalid);
+        }
 
-        // Get chart views.
-        let chart_views = match get_views(&r_req.views, &h_scale, &v_scale) {
-            Ok(chart_views) => chart_views,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+        let mut frame_req = r_req.as_ref().clone();
+        for (view, frame_view) in frame_req.views.iter_mut().zip(frame_views) {
+            view.values = frame_view.values;
+        }
+
+        render(&frame_req)
+    }
+}
+
+// Render chart frames from the stream and send them into the channel.
+// Base chart is reused for every frame until a new base chart is received.
+// Every frame reply contains fields that are defaulted in the base chart.
+async fn render_chart_frames<S>(
+    log: slog::Logger,
+    defaults: Defaults,
+    mut stream: S,
+    tx: mpsc::Sender<Result<RenderChartReply, Status>>,
+) where
+    S: Stream<Item = Result<RenderChartStreamRequest, Status>> + Unpin,
+{
+    let mut base_chart: Option<(StreamChart, Vec<String>)> = None;
+
+    while let Some(rs_req) = stream.next().await {
+        let rs_req = match rs_req {
+            Ok(rs_req) => rs_req,
+            Err(status) => {
+                error!(log, "{}", ERR_UNABLE_TO_READ_CHART_STREAM; LOG_KEY_ERR => status.to_string());
+                return;
             }
         };
 
-        // Prepare a chart.
-        let mut chart = Chart::new()
-            .set_width(sizes.width)
-            .set_height(sizes.height)
-            .set_margin_top(margins.top)
-            .set_margin_bottom(margins.bottom)
-            .set_margin_left(margins.left)
-            .set_margin_right(margins.right)
-            .set_title(&r_req.title)
-            .set_views(chart_views.iter().map(Box::as_ref).collect());
-
-        // Set the needed top axis.
-        chart = match set_chart_top_axis(chart, axes.axis_top, axes.axis_top_label) {
-            Ok(chart) => chart,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+        let (request_id, res) = match rs_req.payload {
+            Some(Payload::Chart(mut r_req)) => {
+                let defaulted_fields = defaults.apply(&mut r_req);
+                let request_id = r_req.request_id.clone();
+                match StreamChart::new(r_req) {
+                    Ok(stream_chart) => {
+                        base_chart = Some((stream_chart, defaulted_fields));
+                        continue;
+                    }
+                    Err(err) => (request_id, Err(err)),
+                }
+            }
+            Some(Payload::Frame(frame)) => {
+                let res = match &base_chart {
+                    Some((stream_chart, defaulted_fields)) => stream_chart
+                        .render(frame.views_values)
+                        .map(|output| (output, defaulted_fields.clone())),
+                    None => Err(RendererError::ChartStreamBaseChartIsNotSpecified),
+                };
+                (frame.request_id, res)
             }
+            None => continue,
         };
 
-        // Set the needed bottom axis.
-        chart = match set_chart_bottom_axis(chart, axes.axis_bottom, axes.axis_bottom_label) {
-            Ok(chart) => chart,
+        let reply = match res {
+            Ok((output, defaulted_fields)) => Ok(RenderChartReply {
+                request_id,
+                chart_data: output.data,
+                content_type: output.content_type.to_string(),
+                defaulted_fields,
+            }),
             Err(err) => {
                 let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART;
+                    LOG_KEY_REQ_ID => request_id,
+                    LOG_KEY_ERR => err.clone(),
+                );
+                Err(Status::invalid_argument(err))
             }
         };
 
-        // Set the needed left axis.
-        chart = match set_chart_left_axis(chart, axes.axis_left, axes.axis_left_label) {
-            Ok(chart) => chart,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+        // Stop rendering if the client is gone or the stream is failed.
+        let failed = reply.is_err();
+        if tx.send(reply).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+// Render chart from protobuf and return its raw bytes representation.
+pub(crate) fn render(r_req: &RenderChartRequest) -> Result<Output, RendererError> {
+    ChartTemplate::new(r_req)?.render(&r_req.views)
+}
+
+// Prepare chart from protobuf without rendering it and return its layout.
+pub(crate) fn layout(r_req: &RenderChartRequest) -> Result<ChartLayout, RendererError> {
+    ChartTemplate::new(r_req)?.layout(&r_req.views)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::render_chart_request;
+    use crate::proto::render::chart_annotation::ChartAnnotationKind;
+    use crate::proto::render::chart_element_color::ColorValue;
+    use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+    use crate::proto::render::chart_theme::ChartThemeName;
+    use crate::proto::render::chart_view_points_values::Point;
+    use crate::proto::render::{
+        chart_annotation_value, chart_layout_scale, chart_view_values, ChartAnnotation,
+        ChartAnnotationValue, ChartLayoutScale, ChartTheme, ChartViewValues, RenderChartFrame,
+    };
+    use crate::proto::render::{
+        ChartElementColor, ChartViewPointsValues, DomainAuto, DomainNumeric,
+    };
+
+    fn annotation_number(value: f32) -> ChartAnnotationValue {
+        ChartAnnotationValue {
+            value: Some(chart_annotation_value::Value::Number(value)),
+        }
+    }
+
+    #[tokio::test]
+    async fn render_charts_basic() {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let server = RendererServer::new(log, Defaults::default());
+        let mut defaulted_chart = render_chart_request("c");
+        defaulted_chart.margins = None;
+        let mut invalid_chart = render_chart_request("b");
+        invalid_chart.axes = None;
+
+        let reply = server
+            .render_charts(Request::new(RenderChartsRequest {
+                request_id: "batch".to_string(),
+                charts: vec![render_chart_request("a"), invalid_chart, defaulted_chart],
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!("batch", reply.request_id);
+        assert_eq!(3, reply.results.len());
+        assert_eq!("a", reply.results[0].request_id);
+        match &reply.results[0].outcome {
+            Some(Outcome::Reply(r_reply)) => {
+                assert_eq!("a", r_reply.request_id);
+                assert!(!r_reply.chart_data.is_empty());
+                assert!(r_reply.defaulted_fields.is_empty());
             }
-        };
+            _ => panic!("expected rendered chart"),
+        }
+        assert_eq!("b", reply.results[1].request_id);
+        assert_eq!(
+            Some(Outcome::Error(
+                RendererError::ChartAxesAreNotSpecified.to_string()
+            )),
+            reply.results[1].outcome
+        );
+        match &reply.results[2].outcome {
+            Some(Outcome::Reply(r_reply)) => assert_eq!(
+                vec![
+                    "margins.margin_top",
+                    "margins.margin_bottom",
+                    "margins.margin_left",
+                    "margins.margin_right",
+                ],
+                r_reply.defaulted_fields
+            ),
+            _ => panic!("expected rendered chart"),
+        }
+    }
 
-        // Set the needed right axis.
-        chart = match set_chart_right_axis(chart, axes.axis_right, axes.axis_right_label) {
-            Ok(chart) => chart,
-            Err(err) => {
-                let err = err.to_string();
-                error!(log, "{}", ERR_UNABLE_TO_RENDER_CHART; LOG_KEY_ERR => err.clone());
-                return Err(Status::invalid_argument(err));
+    #[tokio::test]
+    async fn render_charts_too_many_err() {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let server = RendererServer::new(log, Defaults::default());
+
+        let status = server
+            .render_charts(Request::new(RenderChartsRequest {
+                request_id: "batch".to_string(),
+                charts: vec![render_chart_request("a"); MAX_CHARTS_PER_REQUEST + 1],
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(tonic::Code::InvalidArgument, status.code());
+        assert_eq!(
+            RendererError::ChartsCountIsTooLarge.to_string(),
+            status.message()
+        );
+    }
+
+    #[test]
+    fn render_themed_annotation_basic() {
+        let mut r_req = render_chart_request("themed");
+        r_req.theme = Some(ChartTheme {
+            name: ChartThemeName::Dark as i32,
+            ..ChartTheme::default()
+        });
+        r_req.annotations = vec![
+            ChartAnnotation {
+                kind: ChartAnnotationKind::Text as i32,
+                x: Some(annotation_number(50_f32)),
+                y: Some(annotation_number(50_f32)),
+                text: "explicit".to_string(),
+                fill: Some(ChartElementColor {
+                    color_value: Some(ColorValue::ColorHex("#ff0000".to_string())),
+                }),
+                ..ChartAnnotation::default()
+            },
+            ChartAnnotation {
+                kind: ChartAnnotationKind::Text as i32,
+                x: Some(annotation_number(20_f32)),
+                y: Some(annotation_number(20_f32)),
+                text: "default".to_string(),
+                ..ChartAnnotation::default()
+            },
+        ];
+
+        let svg = String::from_utf8(render(&r_req).unwrap().data).unwrap();
+        let explicit_text = svg
+            .lines()
+            .rev()
+            .skip_while(|line| !line.contains("explicit"))
+            .nth(1)
+            .unwrap();
+        let default_text = svg
+            .lines()
+            .rev()
+            .skip_while(|line| !line.contains("default") || line.contains("default-color {"))
+            .nth(1)
+            .unwrap();
+
+        assert!(explicit_text.contains(r##"fill="#ff0000""##));
+        assert!(!explicit_text.contains("default-color"));
+        assert!(default_text.contains(r#"class="default-color""#));
+        assert!(svg.contains(".annotation text.default-color { fill: #e6e6e6; }"));
+        assert!(!svg.contains("\ntext {"));
+    }
+
+    #[tokio::test]
+    async fn validate_chart_basic() {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let server = RendererServer::new(log, Defaults::default());
+
+        let reply = server
+            .validate_chart(Request::new(render_chart_request("a")))
+            .await
+            .unwrap()
+            .into_inner();
+        let layout = reply.layout.unwrap();
+
+        assert_eq!("a", reply.request_id);
+        assert!(reply.field_violations.is_empty());
+        assert_eq!((700, 460), (layout.plot_width, layout.plot_height));
+        assert_eq!(None, layout.axis_top);
+        assert_eq!(
+            Some(ChartLayoutScale {
+                kind: ChartScaleKind::Linear as i32,
+                range_start: 0,
+                range_end: 700,
+                domain: Some(chart_layout_scale::Domain::DomainNumeric(DomainNumeric {
+                    start: 0_f32,
+                    end: 100_f32,
+                })),
+            }),
+            layout.axis_bottom
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_chart_err() {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let server = RendererServer::new(log, Defaults::default());
+        let mut r_req = render_chart_request("a");
+        r_req.axes = None;
+        r_req.views[0].point_label_position = 100;
+
+        let reply = server
+            .validate_chart(Request::new(r_req))
+            .await
+            .unwrap()
+            .into_inner();
+        let fields: Vec<_> = reply
+            .field_violations
+            .iter()
+            .map(|violation| violation.field.as_str())
+            .collect();
+
+        assert_eq!(None, reply.layout);
+        assert_eq!(vec!["axes", "views[0].point_label_position"], fields);
+    }
+
+    fn render_chart_frame(request_id: &str, x: f32) -> RenderChartStreamRequest {
+        RenderChartStreamRequest {
+            payload: Some(Payload::Frame(RenderChartFrame {
+                request_id: request_id.to_string(),
+                views_values: vec![ChartViewValues {
+                    values: Some(chart_view_values::Values::PointsValues(
+                        ChartViewPointsValues {
+                            points: vec![Point {
+                                x,
+                                y: 20_f32,
+                                time: None,
+                            }],
+                            lower_bounds: Vec::new(),
+                            upper_bounds: Vec::new(),
+                        },
+                    )),
+                }],
+            })),
+        }
+    }
+
+    async fn render_chart_stream(
+        messages: Vec<RenderChartStreamRequest>,
+    ) -> Vec<Result<RenderChartReply, Status>> {
+        let log = slog::Logger::root(slog::Discard, o!());
+        let (tx, rx) = mpsc::channel(CHART_STREAM_BUFFER_SIZE);
+
+        render_chart_frames(
+            log,
+            Defaults::default(),
+            tokio_stream::iter(messages.into_iter().map(Ok)),
+            tx,
+        )
+        .await;
+
+        ReceiverStream::new(rx).collect().await
+    }
+
+    #[tokio::test]
+    async fn render_chart_stream_basic() {
+        let mut base_chart = render_chart_request("base");
+        base_chart.views[0].values = None;
+
+        let replies = render_chart_stream(vec![
+            RenderChartStreamRequest {
+                payload: Some(Payload::Chart(base_chart)),
+            },
+            render_chart_frame("a", 10_f32),
+            render_chart_frame("b", 90_f32),
+        ])
+        .await;
+
+        assert_eq!(2, replies.len());
+        let a = replies[0].as_ref().unwrap();
+        let b = replies[1].as_ref().unwrap();
+        assert_eq!("a", a.request_id);
+        assert_eq!("b", b.request_id);
+        assert_ne!(a.chart_data, b.chart_data);
+    }
+
+    #[tokio::test]
+    async fn render_chart_stream_auto_domains() {
+        let mut base_chart = render_chart_request("base");
+        base_chart.views[0].values = None;
+        if let Some(axes) = base_chart.axes.as_mut() {
+            if let Some(axis_bottom) = axes.axis_bottom.as_mut() {
+                axis_bottom.domain = Some(Domain::DomainAuto(DomainAuto::default()));
             }
-        };
+        }
 
-        Ok(Response::new(RenderChartReply {
-            request_id: r_req.request_id,
-            chart_data: chart.to_svg().to_string().into_bytes(),
-        }))
+        let replies = render_chart_stream(vec![
+            RenderChartStreamRequest {
+                payload: Some(Payload::Chart(base_chart)),
+            },
+            render_chart_frame("a", 10_f32),
+            render_chart_frame("b", 90_f32),
+        ])
+        .await;
+
+        assert_eq!(2, replies.len());
+        let a = String::from_utf8(replies[0].as_ref().unwrap().chart_data.clone()).unwrap();
+        let b = String::from_utf8(replies[1].as_ref().unwrap().chart_data.clone()).unwrap();
+        assert!(a.contains(">\n11\n</text>"));
+        assert!(!a.contains(">\n91\n</text>"));
+        assert!(b.contains(">\n91\n</text>"));
+    }
+
+    #[tokio::test]
+    async fn render_chart_stream_err() {
+        let replies = render_chart_stream(vec![
+            render_chart_frame("a", 10_f32),
+            render_chart_frame("b", 90_f32),
+        ])
+        .await;
+
+        assert_eq!(1, replies.len());
+        let status = replies[0].as_ref().unwrap_err();
+        assert_eq!(tonic::Code::InvalidArgument, status.code());
+        assert_eq!(
+            RendererError::ChartStreamBaseChartIsNotSpecified.to_string(),
+            status.message()
+        );
     }
 }
@@ -1,26 +1,94 @@
+use crate::axis::AxisOptions;
+use crate::draw::axis::{AxisPosition, AxisView};
+use crate::draw::scale::{
+    is_time_format_valid, LogScale, NumericScale, PointsScale, TimeScale, DEFAULT_LOG_BASE,
+};
 use crate::error::RendererError;
 use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
-use crate::proto::render::{ChartAxes, ChartScale};
-use lc_render::{BandScale, Chart, LinearScale};
+use crate::proto::render::chart_view::{ChartViewHorizontalAxis, ChartViewVerticalAxis};
+use crate::proto::render::{
+    chart_layout_scale, ChartAxes, ChartLayoutScale, ChartScale, ChartView,
+};
+use lc_render::{BandScale, Chart, LinearScale, Scale};
+use prost_types::Timestamp;
 
-// Get horizontal scale from protobuf.
-pub(crate) fn get_h_scale(axes: &ChartAxes) -> Result<ChartScale, RendererError> {
-    match (axes.axis_top.clone(), axes.axis_bottom.clone()) {
-        (Some(axis_top), _) => Ok(axis_top),
-        (_, Some(axis_bottom)) => Ok(axis_bottom),
-        _ => Err(RendererError::TopOrBottomAxisShouldBeSpecified),
+// Supported bases of logarithmic scales.
+const MIN_LOG_BASE: f32 = 2_f32;
+const MAX_LOG_BASE: f32 = 100_f32;
+
+// Range of protobuf timestamps from 0001-01-01T00:00:00Z to 9999-12-31T23:59:59Z in seconds.
+const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+
+// Maximum count of powers of base that a logarithmic scale domain can span.
+const MAX_LOG_DOMAIN_POWERS: f32 = 100_f32;
+
+// Get horizontal axis position and scale that a view or an annotation is bound to.
+// Elements that are not bound use the top axis if it's set and the bottom one otherwise.
+pub(crate) fn get_h_axis(
+    axes: &ChartAxes,
+    horizontal_axis: i32,
+) -> Result<(AxisPosition, &ChartScale), RendererError> {
+    let position = match ChartViewHorizontalAxis::from_i32(horizontal_axis) {
+        Some(ChartViewHorizontalAxis::UnspecifiedHorizontalAxis) => {
+            match (&axes.axis_top, &axes.axis_bottom) {
+                (Some(_), _) => AxisPosition::Top,
+                (_, Some(_)) => AxisPosition::Bottom,
+                _ => return Err(RendererError::TopOrBottomAxisShouldBeSpecified),
+            }
+        }
+        Some(ChartViewHorizontalAxis::AxisTop) => AxisPosition::Top,
+        Some(ChartViewHorizontalAxis::AxisBottom) => AxisPosition::Bottom,
+        None => return Err(RendererError::ViewHorizontalAxisIsUnknown),
+    };
+    let h_scale = match position {
+        AxisPosition::Top => &axes.axis_top,
+        _ => &axes.axis_bottom,
+    };
+    match h_scale {
+        Some(h_scale) => Ok((position, h_scale)),
+        None => Err(RendererError::ViewHorizontalAxisIsNotSpecified),
     }
 }
 
-// Get vertical scale from protobuf.
-pub(crate) fn get_v_scale(axes: &ChartAxes) -> Result<ChartScale, RendererError> {
-    match (axes.axis_left.clone(), axes.axis_right.clone()) {
-        (Some(axis_left), _) => Ok(axis_left),
-        (_, Some(axis_right)) => Ok(axis_right),
-        _ => Err(RendererError::LeftOrRightAxisShouldBeSpecified),
+// Get vertical axis position and scale that a view or an annotation is bound to.
+// Elements that are not bound use the left axis if it's set and the right one otherwise.
+pub(crate) fn get_v_axis(
+    axes: &ChartAxes,
+    vertical_axis: i32,
+) -> Result<(AxisPosition, &ChartScale), RendererError> {
+    let position = match ChartViewVerticalAxis::from_i32(vertical_axis) {
+        Some(ChartViewVerticalAxis::UnspecifiedVerticalAxis) => {
+            match (&axes.axis_left, &axes.axis_right) {
+                (Some(_), _) => AxisPosition::Left,
+                (_, Some(_)) => AxisPosition::Right,
+                _ => return Err(RendererError::LeftOrRightAxisShouldBeSpecified),
+            }
+        }
+        Some(ChartViewVerticalAxis::AxisLeft) => AxisPosition::Left,
+        Some(ChartViewVerticalAxis::AxisRight) => AxisPosition::Right,
+        None => return Err(RendererError::ViewVerticalAxisIsUnknown),
+    };
+    let v_scale = match position {
+        AxisPosition::Left => &axes.axis_left,
+        _ => &axes.axis_right,
+    };
+    match v_scale {
+        Some(v_scale) => Ok((position, v_scale)),
+        None => Err(RendererError::ViewVerticalAxisIsNotSpecified),
     }
 }
 
+// Get horizontal scale of the view from protobuf.
+pub(crate) fn get_h_scale(axes: &ChartAxes, view: &ChartView) -> Result<ChartScale, RendererError> {
+    Ok(get_h_axis(axes, view.horizontal_axis)?.1.clone())
+}
+
+// Get vertical scale of the view from protobuf.
+pub(crate) fn get_v_scale(axes: &ChartAxes, view: &ChartView) -> Result<ChartScale, RendererError> {
+    Ok(get_v_axis(axes, view.vertical_axis)?.1.clone())
+}
+
 // Get linear horizontal scale from protobuf.
 pub(crate) fn get_linear_h_scale(h_scale: &ChartScale) -> Result<LinearScale, RendererError> {
     let range_start = match h_scale.range_start {
@@ -73,6 +141,141 @@ pub(crate) fn get_linear_v_scale(v_scale: &ChartScale) -> Result<LinearScale, Re
     ))
 }
 
+// Get logarithmic scale of any direction from protobuf.
+pub(crate) fn get_log_scale(scale: &ChartScale) -> Result<LogScale, RendererError> {
+    let range_start = match scale.range_start {
+        Some(range_start) => range_start,
+        None => return Err(RendererError::ScaleRangeStartIsNotSpecified),
+    };
+    let range_end = match scale.range_end {
+        Some(range_end) => range_end,
+        None => return Err(RendererError::ScaleRangeEndIsNotSpecified),
+    };
+    let domain = match &scale.domain {
+        Some(domain) => domain,
+        None => return Err(RendererError::ScaleDomainIsNotSpecified),
+    };
+    let domain_numeric = match domain {
+        Domain::DomainNumeric(domain_numeric) => domain_numeric,
+        _ => return Err(RendererError::LogScaleNumericDomainIsNotSpecified),
+    };
+    if domain_numeric.start <= 0_f32 || domain_numeric.end <= 0_f32 {
+        return Err(RendererError::LogScaleDomainIsNotPositive);
+    }
+    let log_base = get_log_base(scale.log_base)?;
+    check_log_domain_powers(domain_numeric.start, domain_numeric.end, log_base)?;
+    Ok(LogScale::new(
+        log_base,
+        domain_numeric.start,
+        domain_numeric.end,
+        range_start,
+        range_end,
+    ))
+}
+
+// Get logarithmic scale base from protobuf, base 10 is used by default.
+fn get_log_base(log_base: Option<f32>) -> Result<f32, RendererError> {
+    match log_base {
+        Some(log_base) if (MIN_LOG_BASE..=MAX_LOG_BASE).contains(&log_base) => Ok(log_base),
+        Some(_) => Err(RendererError::LogScaleBaseIsInvalid),
+        None => Ok(DEFAULT_LOG_BASE),
+    }
+}
+
+// Check that a positive logarithmic domain doesn't span too many powers of base,
+// every power of base in the domain gets a tick.
+fn check_log_domain_powers(start: f32, end: f32, base: f32) -> Result<(), RendererError> {
+    let powers = (end.log(base) - start.log(base)).abs();
+    if powers.is_nan() || powers > MAX_LOG_DOMAIN_POWERS {
+        return Err(RendererError::LogScaleDomainIsTooWide);
+    }
+
+    Ok(())
+}
+
+// Get time scale of any direction from protobuf.
+pub(crate) fn get_time_scale(scale: &ChartScale) -> Result<TimeScale, RendererError> {
+    let range_start = match scale.range_start {
+        Some(range_start) => range_start,
+        None => return Err(RendererError::ScaleRangeStartIsNotSpecified),
+    };
+    let range_end = match scale.range_end {
+        Some(range_end) => range_end,
+        None => return Err(RendererError::ScaleRangeEndIsNotSpecified),
+    };
+    let domain = match &scale.domain {
+        Some(domain) => domain,
+        None => return Err(RendererError::ScaleDomainIsNotSpecified),
+    };
+    let domain_time = match domain {
+        Domain::DomainTime(domain_time) => domain_time,
+        _ => return Err(RendererError::TimeScaleTimeDomainIsNotSpecified),
+    };
+    let (domain_start, domain_end) = match (&domain_time.start, &domain_time.end) {
+        (Some(start), Some(end)) if is_time_valid(start) && is_time_valid(end) => {
+            (get_time(start), get_time(end))
+        }
+        _ => return Err(RendererError::TimeScaleDomainIsInvalid),
+    };
+    if domain_start == domain_end {
+        return Err(RendererError::TimeScaleDomainIsInvalid);
+    }
+    if !is_time_format_valid(&scale.time_format) {
+        return Err(RendererError::TimeScaleFormatIsInvalid);
+    }
+    Ok(
+        TimeScale::new(domain_start, domain_end, range_start, range_end)
+            .set_format(&scale.time_format),
+    )
+}
+
+// Check that protobuf timestamp is within the range of valid timestamps.
+pub(crate) fn is_time_valid(timestamp: &Timestamp) -> bool {
+    (MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&timestamp.seconds)
+        && (0..1_000_000_000).contains(&timestamp.nanos)
+}
+
+// Get time value of the time scale from protobuf timestamp.
+pub(crate) fn get_time(timestamp: &Timestamp) -> f64 {
+    timestamp.seconds as f64 + timestamp.nanos as f64 / 1e9_f64
+}
+
+// Get numeric horizontal scale of linear or logarithmic kind from protobuf.
+pub(crate) fn get_numeric_h_scale(h_scale: &ChartScale) -> Result<NumericScale, RendererError> {
+    match ChartScaleKind::from_i32(h_scale.kind) {
+        Some(ChartScaleKind::Log) => Ok(NumericScale::Log(get_log_scale(h_scale)?)),
+        _ => Ok(NumericScale::Linear(get_linear_h_scale(h_scale)?)),
+    }
+}
+
+// Get numeric vertical scale of linear or logarithmic kind from protobuf.
+pub(crate) fn get_numeric_v_scale(v_scale: &ChartScale) -> Result<NumericScale, RendererError> {
+    match ChartScaleKind::from_i32(v_scale.kind) {
+        Some(ChartScaleKind::Log) => Ok(NumericScale::Log(get_log_scale(v_scale)?)),
+        _ => Ok(NumericScale::Linear(get_linear_v_scale(v_scale)?)),
+    }
+}
+
+// Get horizontal scale of line and area views from protobuf.
+// Scalar values are set on band scale and points values are set on other scale kinds.
+pub(crate) fn get_line_h_scale(h_scale: &ChartScale) -> Result<PointsScale, RendererError> {
+    match ChartScaleKind::from_i32(h_scale.kind) {
+        Some(ChartScaleKind::Linear) | Some(ChartScaleKind::Log) => {
+            Ok(PointsScale::Numeric(get_numeric_h_scale(h_scale)?))
+        }
+        Some(ChartScaleKind::Time) => Ok(PointsScale::Time(get_time_scale(h_scale)?)),
+        _ => Ok(PointsScale::Band(get_band_h_scale(h_scale)?)),
+    }
+}
+
+// Get horizontal scale of scatter view from protobuf.
+pub(crate) fn get_scatter_h_scale(h_scale: &ChartScale) -> Result<PointsScale, RendererError> {
+    match ChartScaleKind::from_i32(h_scale.kind) {
+        Some(ChartScaleKind::Time) => Ok(PointsScale::Time(get_time_scale(h_scale)?)),
+        _ => Ok(PointsScale::Numeric(get_numeric_h_scale(h_scale)?)),
+    }
+}
+
 // Get band horizontal scale from protobuf.
 pub(crate) fn get_band_h_scale(h_scale: &ChartScale) -> Result<BandScale, RendererError> {
     let range_start = match h_scale.range_start {
@@ -141,134 +344,327 @@ pub(crate) fn get_band_v_scale(v_scale: &ChartScale) -> Result<BandScale, Render
     )
 }
 
-pub(crate) fn set_chart_top_axis(
-    chart: Chart,
+/// Chart axis with its scale and label prepared from protobuf.
+#[derive(Clone)]
+pub(crate) struct Axis {
+    scale: AxisScale,
+    label: String,
+    options: AxisOptions,
+}
+
+// Scale of the chart axis.
+#[derive(Clone)]
+enum AxisScale {
+    Band(BandScale),
+    Linear(LinearScale),
+    Log(LogScale),
+    Time(TimeScale),
+}
+
+// Get top axis from protobuf.
+pub(crate) fn get_top_axis(
     scale: Option<ChartScale>,
     label: String,
-) -> Result<Chart, RendererError> {
+    options: AxisOptions,
+) -> Result<Option<Axis>, RendererError> {
     match scale {
         Some(scale) => match ChartScaleKind::from_i32(scale.kind) {
-            Some(ChartScaleKind::Band) => {
-                let h_scale = match get_band_h_scale(&scale) {
-                    Ok(h_scale) => h_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart.set_axis_top_band(h_scale).set_axis_top_label(&label))
-            }
-            Some(ChartScaleKind::Linear) => {
-                let h_scale = match get_linear_h_scale(&scale) {
-                    Ok(h_scale) => h_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_top_linear(h_scale)
-                    .set_axis_top_label(&label))
-            }
-            _ => Err(RendererError::TopAxisIsSetButItsNotBandOrLinear),
+            Some(ChartScaleKind::Band) => Ok(Some(Axis {
+                scale: AxisScale::Band(get_band_h_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Linear) => Ok(Some(Axis {
+                scale: AxisScale::Linear(get_linear_h_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Log) => Ok(Some(Axis {
+                scale: AxisScale::Log(get_log_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Time) => Ok(Some(Axis {
+                scale: AxisScale::Time(get_time_scale(&scale)?),
+                label,
+                options,
+            })),
+            _ => Err(RendererError::TopAxisIsSetButItsKindIsUnknown),
         },
-        _ => Ok(chart),
+        _ => Ok(None),
     }
 }
 
-pub(crate) fn set_chart_bottom_axis(
-    chart: Chart,
+// Get bottom axis from protobuf.
+pub(crate) fn get_bottom_axis(
     scale: Option<ChartScale>,
     label: String,
-) -> Result<Chart, RendererError> {
+    options: AxisOptions,
+) -> Result<Option<Axis>, RendererError> {
     match scale {
         Some(scale) => match ChartScaleKind::from_i32(scale.kind) {
-            Some(ChartScaleKind::Band) => {
-                let h_scale = match get_band_h_scale(&scale) {
-                    Ok(h_scale) => h_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_bottom_band(h_scale)
-                    .set_axis_bottom_label(&label))
-            }
-            Some(ChartScaleKind::Linear) => {
-                let h_scale = match get_linear_h_scale(&scale) {
-                    Ok(h_scale) => h_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_bottom_linear(h_scale)
-                    .set_axis_bottom_label(&label))
-            }
-            _ => Err(RendererError::BottomAxisIsSetButItsNotBandOrLinear),
+            Some(ChartScaleKind::Band) => Ok(Some(Axis {
+                scale: AxisScale::Band(get_band_h_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Linear) => Ok(Some(Axis {
+                scale: AxisScale::Linear(get_linear_h_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Log) => Ok(Some(Axis {
+                scale: AxisScale::Log(get_log_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Time) => Ok(Some(Axis {
+                scale: AxisScale::Time(get_time_scale(&scale)?),
+                label,
+                options,
+            })),
+            _ => Err(RendererError::BottomAxisIsSetButItsKindIsUnknown),
         },
-        _ => Ok(chart),
+        _ => Ok(None),
     }
 }
 
-pub(crate) fn set_chart_left_axis(
-    chart: Chart,
+// Get left axis from protobuf.
+pub(crate) fn get_left_axis(
     scale: Option<ChartScale>,
     label: String,
-) -> Result<Chart, RendererError> {
+    options: AxisOptions,
+) -> Result<Option<Axis>, RendererError> {
     match scale {
         Some(scale) => match ChartScaleKind::from_i32(scale.kind) {
-            Some(ChartScaleKind::Band) => {
-                let v_scale = match get_band_v_scale(&scale) {
-                    Ok(v_scale) => v_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_left_band(v_scale)
-                    .set_axis_left_label(&label))
-            }
-            Some(ChartScaleKind::Linear) => {
-                let v_scale = match get_linear_v_scale(&scale) {
-                    Ok(v_scale) => v_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_left_linear(v_scale)
-                    .set_axis_left_label(&label))
-            }
-            _ => Err(RendererError::LeftAxisIsSetButItsNotBandOrLinear),
+            Some(ChartScaleKind::Band) => Ok(Some(Axis {
+                scale: AxisScale::Band(get_band_v_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Linear) => Ok(Some(Axis {
+                scale: AxisScale::Linear(get_linear_v_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Log) => Ok(Some(Axis {
+                scale: AxisScale::Log(get_log_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Time) => Ok(Some(Axis {
+                scale: AxisScale::Time(get_time_scale(&scale)?),
+                label,
+                options,
+            })),
+            _ => Err(RendererError::LeftAxisIsSetButItsKindIsUnknown),
         },
-        _ => Ok(chart),
+        _ => Ok(None),
     }
 }
 
-pub(crate) fn set_chart_right_axis(
-    chart: Chart,
+// Get right axis from protobuf.
+pub(crate) fn get_right_axis(
     scale: Option<ChartScale>,
     label: String,
-) -> Result<Chart, RendererError> {
+    options: AxisOptions,
+) -> Result<Option<Axis>, RendererError> {
     match scale {
         Some(scale) => match ChartScaleKind::from_i32(scale.kind) {
-            Some(ChartScaleKind::Band) => {
-                let v_scale = match get_band_v_scale(&scale) {
-                    Ok(v_scale) => v_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_right_band(v_scale)
-                    .set_axis_right_label(&label))
-            }
-            Some(ChartScaleKind::Linear) => {
-                let v_scale = match get_linear_v_scale(&scale) {
-                    Ok(v_scale) => v_scale,
-                    Err(err) => return Err(err),
-                };
-                Ok(chart
-                    .set_axis_right_linear(v_scale)
-                    .set_axis_right_label(&label))
-            }
-            _ => Err(RendererError::RightAxisIsSetButItsNotBandOrLinear),
+            Some(ChartScaleKind::Band) => Ok(Some(Axis {
+                scale: AxisScale::Band(get_band_v_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Linear) => Ok(Some(Axis {
+                scale: AxisScale::Linear(get_linear_v_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Log) => Ok(Some(Axis {
+                scale: AxisScale::Log(get_log_scale(&scale)?),
+                label,
+                options,
+            })),
+            Some(ChartScaleKind::Time) => Ok(Some(Axis {
+                scale: AxisScale::Time(get_time_scale(&scale)?),
+                label,
+                options,
+            })),
+            _ => Err(RendererError::RightAxisIsSetButItsKindIsUnknown),
         },
-        _ => Ok(chart),
+        _ => Ok(None),
+    }
+}
+
+pub(crate) fn set_chart_top_axis<'a>(chart: Chart<'a>, axis: &Option<Axis>) -> Chart<'a> {
+    match axis {
+        Some(Axis {
+            scale: AxisScale::Band(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_top_band(scale.clone())
+            .set_axis_top_label(label),
+        Some(Axis {
+            scale: AxisScale::Linear(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_top_linear(scale.clone())
+            .set_axis_top_label(label),
+        _ => chart,
+    }
+}
+
+pub(crate) fn set_chart_bottom_axis<'a>(chart: Chart<'a>, axis: &Option<Axis>) -> Chart<'a> {
+    match axis {
+        Some(Axis {
+            scale: AxisScale::Band(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_bottom_band(scale.clone())
+            .set_axis_bottom_label(label),
+        Some(Axis {
+            scale: AxisScale::Linear(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_bottom_linear(scale.clone())
+            .set_axis_bottom_label(label),
+        _ => chart,
+    }
+}
+
+pub(crate) fn set_chart_left_axis<'a>(chart: Chart<'a>, axis: &Option<Axis>) -> Chart<'a> {
+    match axis {
+        Some(Axis {
+            scale: AxisScale::Band(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_left_band(scale.clone())
+            .set_axis_left_label(label),
+        Some(Axis {
+            scale: AxisScale::Linear(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_left_linear(scale.clone())
+            .set_axis_left_label(label),
+        _ => chart,
+    }
+}
+
+pub(crate) fn set_chart_right_axis<'a>(chart: Chart<'a>, axis: &Option<Axis>) -> Chart<'a> {
+    match axis {
+        Some(Axis {
+            scale: AxisScale::Band(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_right_band(scale.clone())
+            .set_axis_right_label(label),
+        Some(Axis {
+            scale: AxisScale::Linear(scale),
+            label,
+            options,
+        }) if options.is_default() => chart
+            .set_axis_right_linear(scale.clone())
+            .set_axis_right_label(label),
+        _ => chart,
     }
 }
 
+// Get axis view for the axis that can't be set on lc-render chart.
+// Band and linear axes with options are drawn as views too.
+pub(crate) fn get_axis_view(
+    axis: &Option<Axis>,
+    position: AxisPosition,
+    view_width: i32,
+    view_height: i32,
+) -> Option<AxisView> {
+    let axis = match axis {
+        Some(axis) => axis,
+        None => return None,
+    };
+    let options = &axis.options;
+    let axis_view = match &axis.scale {
+        AxisScale::Band(scale) if !options.is_default() => {
+            AxisView::new(scale, position, view_width, view_height)
+        }
+        AxisScale::Linear(scale) if !options.is_default() => {
+            let ticks = get_numeric_ticks(NumericScale::Linear(scale.clone()), options);
+            AxisView::from_ticks(ticks, position, view_width, view_height)
+        }
+        AxisScale::Log(scale) => {
+            let ticks = get_numeric_ticks(NumericScale::Log(scale.clone()), options);
+            AxisView::from_ticks(ticks, position, view_width, view_height)
+        }
+        AxisScale::Time(scale) => {
+            let ticks = scale
+                .ticks()
+                .iter()
+                .map(|tick| (scale.scale(tick), scale.format_time(*tick)))
+                .collect();
+            AxisView::from_ticks(ticks, position, view_width, view_height)
+        }
+        _ => return None,
+    };
+
+    Some(
+        axis_view
+            .set_label(&axis.label)
+            .set_tick_label_rotation(options.tick_label_rotation)
+            .set_line_hidden(options.line_hidden)
+            .set_gridlines(options.major_gridlines, options.minor_gridlines),
+    )
+}
+
+// Get scaled offsets and formatted labels of numeric axis ticks.
+// Ticks that are out of the scale range are skipped.
+fn get_numeric_ticks(scale: NumericScale, options: &AxisOptions) -> Vec<(f32, String)> {
+    let range_start = scale.range_start().min(scale.range_end()) as f32;
+    let range_end = scale.range_start().max(scale.range_end()) as f32;
+
+    options
+        .get_ticks(scale.ticks())
+        .into_iter()
+        .map(|tick| (scale.scale(&tick), options.number_format.format(tick)))
+        .filter(|(offset, _)| *offset >= range_start && *offset <= range_end)
+        .collect()
+}
+
+// Get resolved scale of the chart layout from protobuf scale that is already validated.
+pub(crate) fn get_layout_scale(scale: Option<ChartScale>) -> Option<ChartLayoutScale> {
+    scale.map(|scale| ChartLayoutScale {
+        kind: scale.kind,
+        range_start: scale.range_start.unwrap_or_default(),
+        range_end: scale.range_end.unwrap_or_default(),
+        // Auto domains are already computed from values of views.
+        domain: scale.domain.and_then(|domain| match domain {
+            Domain::DomainNumeric(domain_numeric) => {
+                Some(chart_layout_scale::Domain::DomainNumeric(domain_numeric))
+            }
+            Domain::DomainCategories(domain_categories) => Some(
+                chart_layout_scale::Domain::DomainCategories(domain_categories),
+            ),
+            Domain::DomainTime(domain_time) => {
+                Some(chart_layout_scale::Domain::DomainTime(domain_time))
+            }
+            Domain::DomainAuto(_) => None,
+        }),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::axis::NumberFormat;
     use crate::proto::render::chart_scale::ChartScaleKind;
-    use crate::proto::render::{DomainCategories, DomainNumeric};
-    use lc_render::{Scale, ScaleKind};
+    use crate::proto::render::{DomainCategories, DomainNumeric, DomainTime};
+    use lc_render::{Scale, ScaleKind, View};
 
     fn chart_scale_linear() -> ChartScale {
         ChartScale {
@@ -282,6 +678,8 @@ mod tests {
             no_boundaries_offset: true,
             inner_padding: Some(0_f32),
             outer_padding: Some(0_f32),
+            log_base: None,
+            time_format: String::new(),
         }
     }
 
@@ -296,6 +694,48 @@ mod tests {
             no_boundaries_offset: false,
             inner_padding: Some(10_f32),
             outer_padding: Some(20_f32),
+            log_base: None,
+            time_format: String::new(),
+        }
+    }
+
+    fn chart_scale_log() -> ChartScale {
+        ChartScale {
+            kind: ChartScaleKind::Log as i32,
+            range_start: Some(500),
+            range_end: Some(0),
+            domain: Some(Domain::DomainNumeric(DomainNumeric {
+                start: 1_f32,
+                end: 10000_f32,
+            })),
+            no_boundaries_offset: false,
+            inner_padding: None,
+            outer_padding: None,
+            log_base: Some(10_f32),
+            time_format: String::new(),
+        }
+    }
+
+    fn chart_scale_time() -> ChartScale {
+        ChartScale {
+            kind: ChartScaleKind::Time as i32,
+            range_start: Some(0),
+            range_end: Some(800),
+            domain: Some(Domain::DomainTime(DomainTime {
+                start: Some(Timestamp {
+                    seconds: 1614556800,
+                    nanos: 0,
+                }),
+                end: Some(Timestamp {
+                    seconds: 1615161600,
+                    nanos: 0,
+                }),
+            })),
+            no_boundaries_offset: false,
+            inner_padding: None,
+            outer_padding: None,
+            log_base: None,
+            time_format: "%d.%m".to_string(),
         }
     }
 
@@ -309,6 +749,10 @@ mod tests {
             axis_left_label: String::new(),
             axis_right: None,
             axis_right_label: String::new(),
+            axis_top_options: None,
+            axis_bottom_options: None,
+            axis_left_options: None,
+            axis_right_options: None,
         }
     }
 
@@ -317,7 +761,7 @@ mod tests {
         let mut axes = chart_axes_empty();
         axes.axis_top = Some(chart_scale_band());
 
-        let h_scale = get_h_scale(&axes).unwrap();
+        let h_scale = get_h_scale(&axes, &ChartView::default()).unwrap();
 
         assert_eq!(ChartScaleKind::Band, h_scale.kind());
     }
@@ -327,7 +771,7 @@ mod tests {
         let mut axes = chart_axes_empty();
         axes.axis_bottom = Some(chart_scale_band());
 
-        let h_scale = get_h_scale(&axes).unwrap();
+        let h_scale = get_h_scale(&axes, &ChartView::default()).unwrap();
 
         assert_eq!(ChartScaleKind::Band, h_scale.kind());
     }
@@ -337,7 +781,7 @@ mod tests {
     fn get_h_scale_err() {
         let axes = chart_axes_empty();
 
-        get_h_scale(&axes).unwrap();
+        get_h_scale(&axes, &ChartView::default()).unwrap();
     }
 
     #[test]
@@ -345,7 +789,7 @@ mod tests {
         let mut axes = chart_axes_empty();
         axes.axis_left = Some(chart_scale_linear());
 
-        let v_scale = get_v_scale(&axes).unwrap();
+        let v_scale = get_v_scale(&axes, &ChartView::default()).unwrap();
 
         assert_eq!(ChartScaleKind::Linear, v_scale.kind());
     }
@@ -355,7 +799,7 @@ mod tests {
         let mut axes = chart_axes_empty();
         axes.axis_right = Some(chart_scale_linear());
 
-        let v_scale = get_v_scale(&axes).unwrap();
+        let v_scale = get_v_scale(&axes, &ChartView::default()).unwrap();
 
         assert_eq!(ChartScaleKind::Linear, v_scale.kind());
     }
@@ -365,7 +809,52 @@ mod tests {
     fn get_v_scale_err() {
         let axes = chart_axes_empty();
 
-        get_v_scale(&axes).unwrap();
+        get_v_scale(&axes, &ChartView::default()).unwrap();
+    }
+
+    #[test]
+    fn get_h_axis_bound() {
+        let mut axes = chart_axes_empty();
+        axes.axis_top = Some(chart_scale_band());
+        axes.axis_bottom = Some(chart_scale_linear());
+        let view = ChartView {
+            horizontal_axis: ChartViewHorizontalAxis::AxisBottom as i32,
+            ..ChartView::default()
+        };
+
+        let (position, h_scale) = get_h_axis(&axes, view.horizontal_axis).unwrap();
+
+        assert_eq!(AxisPosition::Bottom, position);
+        assert_eq!(ChartScaleKind::Linear, h_scale.kind());
+    }
+
+    #[test]
+    fn get_v_axis_bound() {
+        let mut axes = chart_axes_empty();
+        axes.axis_left = Some(chart_scale_linear());
+        axes.axis_right = Some(chart_scale_log());
+        let view = ChartView {
+            vertical_axis: ChartViewVerticalAxis::AxisRight as i32,
+            ..ChartView::default()
+        };
+
+        let (position, v_scale) = get_v_axis(&axes, view.vertical_axis).unwrap();
+
+        assert_eq!(AxisPosition::Right, position);
+        assert_eq!(ChartScaleKind::Log, v_scale.kind());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_v_axis_err() {
+        let mut axes = chart_axes_empty();
+        axes.axis_left = Some(chart_scale_linear());
+        let view = ChartView {
+            vertical_axis: ChartViewVerticalAxis::AxisRight as i32,
+            ..ChartView::default()
+        };
+
+        get_v_axis(&axes, view.vertical_axis).unwrap();
     }
 
     #[test]
@@ -411,4 +900,165 @@ mod tests {
         assert_eq!(10000, band_scale.range_end());
         assert_eq!(ScaleKind::Band, band_scale.kind());
     }
+
+    #[test]
+    fn get_log_scale_basic() {
+        let scale = chart_scale_log();
+
+        let log_scale = get_log_scale(&scale).unwrap();
+
+        assert!((log_scale.scale(&100_f32) - 250_f32).abs() < 0.001);
+        assert_eq!(5, log_scale.ticks().len());
+        assert!(log_scale.is_range_reversed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_log_scale_err() {
+        let mut scale = chart_scale_log();
+        scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 100_f32,
+        }));
+
+        get_log_scale(&scale).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_log_scale_wide_domain_err() {
+        let mut scale = chart_scale_log();
+        scale.log_base = Some(2_f32);
+        scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 1e-18_f32,
+            end: 1e18_f32,
+        }));
+
+        get_log_scale(&scale).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_log_base_err() {
+        get_log_base(Some(1_f32)).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_log_base_large_err() {
+        get_log_base(Some(1e9_f32)).unwrap();
+    }
+
+    #[test]
+    fn get_numeric_h_scale_basic() {
+        assert!(matches!(
+            get_numeric_h_scale(&chart_scale_log()).unwrap(),
+            NumericScale::Log(_)
+        ));
+        assert!(matches!(
+            get_numeric_h_scale(&chart_scale_linear()).unwrap(),
+            NumericScale::Linear(_)
+        ));
+    }
+
+    #[test]
+    fn get_time_scale_basic() {
+        let scale = chart_scale_time();
+
+        let time_scale = get_time_scale(&scale).unwrap();
+
+        assert!((time_scale.scale(&1614859200_f64) - 400_f32).abs() < 0.001);
+        assert_eq!(8, time_scale.ticks().len());
+        assert_eq!("02.03", time_scale.format_time(time_scale.ticks()[1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_time_scale_err() {
+        let mut scale = chart_scale_time();
+        scale.time_format = "%d.%m.%".to_string();
+
+        get_time_scale(&scale).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_time_scale_out_of_range_err() {
+        let mut scale = chart_scale_time();
+        scale.domain = Some(Domain::DomainTime(DomainTime {
+            start: Some(Timestamp {
+                seconds: 0,
+                nanos: 0,
+            }),
+            end: Some(Timestamp {
+                seconds: i64::MAX - 1,
+                nanos: 0,
+            }),
+        }));
+
+        get_time_scale(&scale).unwrap();
+    }
+
+    #[test]
+    fn get_line_h_scale_basic() {
+        assert!(matches!(
+            get_line_h_scale(&chart_scale_time()).unwrap(),
+            PointsScale::Time(_)
+        ));
+        assert!(matches!(
+            get_line_h_scale(&chart_scale_band()).unwrap(),
+            PointsScale::Band(_)
+        ));
+        assert!(matches!(
+            get_line_h_scale(&chart_scale_linear()).unwrap(),
+            PointsScale::Numeric(_)
+        ));
+    }
+
+    #[test]
+    fn get_axis_view_basic() {
+        let log_axis = get_left_axis(
+            Some(chart_scale_log()),
+            "latency".to_string(),
+            AxisOptions::default(),
+        )
+        .unwrap();
+        let time_axis = get_top_axis(
+            Some(chart_scale_time()),
+            String::new(),
+            AxisOptions::default(),
+        )
+        .unwrap();
+        let band_axis = get_bottom_axis(
+            Some(chart_scale_band()),
+            String::new(),
+            AxisOptions::default(),
+        )
+        .unwrap();
+
+        assert!(get_axis_view(&log_axis, AxisPosition::Left, 800, 500).is_some());
+        assert!(get_axis_view(&time_axis, AxisPosition::Top, 800, 500).is_some());
+        assert!(get_axis_view(&band_axis, AxisPosition::Bottom, 800, 500).is_none());
+    }
+
+    #[test]
+    fn get_axis_view_options() {
+        let options = AxisOptions {
+            tick_values: vec![80_f32, 120_f32, 200_f32],
+            number_format: NumberFormat::Fixed(1),
+            major_gridlines: true,
+            ..AxisOptions::default()
+        };
+        let linear_axis =
+            get_left_axis(Some(chart_scale_linear()), String::new(), options).unwrap();
+
+        let axis_svg = get_axis_view(&linear_axis, AxisPosition::Left, 800, 500)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert!(axis_svg.contains(r#"<g class="grid">"#));
+        assert!(axis_svg.contains(">\n120.0\n<"));
+        assert!(!axis_svg.contains(">\n200.0\n<"));
+    }
 }
@@ -0,0 +1,252 @@
+use crate::error::RendererError;
+use crate::proto::render::ChartReply;
+use crate::renderer::LOG_KEY_ERR;
+use crate::storage::{check_chart_id, is_expired, ChartStorage};
+use prost::Message;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const CHART_FILE_EXTENSION: &str = "pb";
+const EXPIRY_FILE_EXTENSION: &str = "expiry";
+const TMP_FILE_EXTENSION: &str = "tmp";
+
+// Age of a temporary file after which it's considered to be left by an interrupted write.
+const STALE_TMP_FILE_AGE: Duration = Duration::from_secs(60);
+
+const LOG_KEY_PATH: &str = "path";
+
+/// FsStorage keeps every chart as a protobuf encoded file inside of a directory,
+/// so charts remain available after restart.
+/// Expiration time of every chart is kept in a small sidecar file,
+/// so expired charts are deleted without reading their data.
+#[derive(Debug)]
+pub(crate) struct FsStorage {
+    log: slog::Logger,
+    dir: PathBuf,
+}
+
+impl FsStorage {
+    /// Create a new FsStorage, the directory is created if it doesn't exist.
+    pub(crate) async fn new<P: AsRef<Path>>(
+        log: slog::Logger,
+        dir: P,
+    ) -> Result<FsStorage, RendererError> {
+        tokio::fs::create_dir_all(dir.as_ref()).await?;
+
+        Ok(FsStorage {
+            log,
+            dir: dir.as_ref().to_path_buf(),
+        })
+    }
+
+    fn chart_path(&self, chart_id: &str) -> PathBuf {
+        self.dir.join(chart_id).with_extension(CHART_FILE_EXTENSION)
+    }
+
+    // Read a chart or an expiry sidecar, they're both encoded as ChartReply.
+    async fn read_chart(&self, path: &Path) -> Result<Option<ChartReply>, RendererError> {
+        let data = match tokio::fs::read(path).await {
+            Ok(data) => data,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Some(ChartReply::decode(data.as_slice())?))
+    }
+
+    // Write into a temporary file first so readers never get a partially written file.
+    async fn write_file(&self, path: &Path, message: &ChartReply) -> Result<(), RendererError> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".");
+        tmp_path.push(TMP_FILE_EXTENSION);
+        let mut data = Vec::with_capacity(message.encoded_len());
+        message.encode(&mut data)?;
+        tokio::fs::write(&tmp_path, data).await?;
+        tokio::fs::rename(&tmp_path, path).await?;
+
+        Ok(())
+    }
+
+    // Write an expiry sidecar of the chart that contains only its expiration time.
+    async fn write_expiry(
+        &self,
+        chart_path: &Path,
+        chart: &ChartReply,
+    ) -> Result<(), RendererError> {
+        let expiry = ChartReply {
+            deleted_at: chart.deleted_at.clone(),
+            ..ChartReply::default()
+        };
+
+        self.write_file(&chart_path.with_extension(EXPIRY_FILE_EXTENSION), &expiry)
+            .await
+    }
+
+    // Delete the chart if its expiry sidecar is expired and return true if the chart is deleted.
+    async fn delete_expired_file(
+        &self,
+        path: &Path,
+        now: SystemTime,
+    ) -> Result<bool, RendererError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(EXPIRY_FILE_EXTENSION) => match self.read_chart(path).await? {
+                Some(expiry) if is_expired(&expiry, now) => {
+                    remove_file(&path.with_extension(CHART_FILE_EXTENSION)).await?;
+                    remove_file(path).await?;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            },
+            Some(TMP_FILE_EXTENSION) => {
+                let modified = tokio::fs::metadata(path).await?.modified()?;
+                if modified + STALE_TMP_FILE_AGE <= now {
+                    remove_file(path).await?;
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+// Remove a file, it's not an error if it's already removed.
+async fn remove_file(path: &Path) -> Result<(), RendererError> {
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[tonic::async_trait]
+impl ChartStorage for FsStorage {
+    async fn put(&self, chart: ChartReply) -> Result<(), RendererError> {
+        check_chart_id(&chart.chart_id)?;
+
+        // Expiry sidecar is written before the chart so every chart file has it.
+        let path = self.chart_path(&chart.chart_id);
+        self.write_expiry(&path, &chart).await?;
+        self.write_file(&path, &chart).await
+    }
+
+    async fn get(
+        &self,
+        chart_id: &str,
+        now: SystemTime,
+    ) -> Result<Option<ChartReply>, RendererError> {
+        check_chart_id(chart_id)?;
+
+        match self.read_chart(&self.chart_path(chart_id)).await? {
+            Some(chart) if !is_expired(&chart, now) => Ok(Some(chart)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn delete_expired(&self, now: SystemTime) -> Result<usize, RendererError> {
+        let mut deleted = 0;
+
+        // Files that can't be read or deleted are skipped so they don't stop the eviction.
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            match self.delete_expired_file(&path, now).await {
+                Ok(true) => deleted += 1,
+                Ok(false) => {}
+                Err(err) => warn!(self.log, "Unable to evict chart file";
+                    LOG_KEY_PATH => path.display().to_string(),
+                    LOG_KEY_ERR => err.to_string(),
+                ),
+            }
+        }
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::TempDir;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn log() -> slog::Logger {
+        slog::Logger::root(slog::Discard, o!())
+    }
+
+    fn chart(chart_id: &str, deleted_at: SystemTime) -> ChartReply {
+        ChartReply {
+            chart_id: chart_id.to_string(),
+            deleted_at: Some(deleted_at.into()),
+            chart_data: vec![1, 2, 3],
+            defaulted_fields: Vec::new(),
+            ..ChartReply::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn fs_storage_basic() {
+        let dir = TempDir::new();
+        let now = SystemTime::now();
+        let a_id = Uuid::new_v4().to_string();
+        let b_id = Uuid::new_v4().to_string();
+
+        let storage = FsStorage::new(log(), dir.path()).await.unwrap();
+        storage
+            .put(chart(&a_id, now + Duration::from_secs(10)))
+            .await
+            .unwrap();
+        storage.put(chart(&b_id, now)).await.unwrap();
+
+        // Charts should be available for a new storage with the same directory.
+        let storage = FsStorage::new(log(), dir.path()).await.unwrap();
+        let a = storage.get(&a_id, now).await.unwrap().unwrap();
+        assert_eq!(vec![1, 2, 3], a.chart_data);
+        assert!(storage.get(&b_id, now).await.unwrap().is_none());
+
+        assert_eq!(1, storage.delete_expired(now).await.unwrap());
+        assert!(!storage.chart_path(&b_id).exists());
+        assert!(!storage
+            .chart_path(&b_id)
+            .with_extension(EXPIRY_FILE_EXTENSION)
+            .exists());
+        assert!(storage.chart_path(&a_id).exists());
+    }
+
+    #[tokio::test]
+    async fn fs_storage_delete_expired_files() {
+        let dir = TempDir::new();
+        let now = SystemTime::now();
+        let storage = FsStorage::new(log(), dir.path()).await.unwrap();
+
+        let expired_id = Uuid::new_v4().to_string();
+        storage.put(chart(&expired_id, now)).await.unwrap();
+
+        // Broken files are skipped, stale temporary files are removed.
+        let broken_path = storage.chart_path(&Uuid::new_v4().to_string());
+        std::fs::write(&broken_path, b"broken").unwrap();
+        std::fs::write(broken_path.with_extension(EXPIRY_FILE_EXTENSION), b"broken").unwrap();
+        let tmp_path = dir.join("chart.pb.tmp");
+        std::fs::write(&tmp_path, b"partial").unwrap();
+
+        assert_eq!(
+            1,
+            storage
+                .delete_expired(SystemTime::now() + STALE_TMP_FILE_AGE)
+                .await
+                .unwrap()
+        );
+        assert!(!storage.chart_path(&expired_id).exists());
+        assert!(broken_path.exists());
+        assert!(!tmp_path.exists());
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn fs_storage_invalid_id() {
+        let storage = FsStorage::new(log(), std::env::temp_dir()).await.unwrap();
+
+        storage.get("../chart", SystemTime::now()).await.unwrap();
+    }
+}
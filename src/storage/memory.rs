@@ -0,0 +1,93 @@
+use crate::error::RendererError;
+use crate::proto::render::ChartReply;
+use crate::storage::{check_chart_id, is_expired, ChartStorage};
+use std::collections::HashMap;
+use std::time::SystemTime;
+use tokio::sync::RwLock;
+
+/// MemoryStorage keeps charts in memory, they are lost after restart.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryStorage {
+    charts: RwLock<HashMap<String, ChartReply>>,
+}
+
+impl MemoryStorage {
+    pub(crate) fn new() -> MemoryStorage {
+        MemoryStorage::default()
+    }
+}
+
+#[tonic::async_trait]
+impl ChartStorage for MemoryStorage {
+    async fn put(&self, chart: ChartReply) -> Result<(), RendererError> {
+        check_chart_id(&chart.chart_id)?;
+
+        self.charts
+            .write()
+            .await
+            .insert(chart.chart_id.clone(), chart);
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        chart_id: &str,
+        now: SystemTime,
+    ) -> Result<Option<ChartReply>, RendererError> {
+        check_chart_id(chart_id)?;
+
+        match self.charts.read().await.get(chart_id) {
+            Some(chart) if !is_expired(chart, now) => Ok(Some(chart.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    async fn delete_expired(&self, now: SystemTime) -> Result<usize, RendererError> {
+        let mut charts = self.charts.write().await;
+        let count = charts.len();
+        charts.retain(|_, chart| !is_expired(chart, now));
+
+        Ok(count - charts.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn chart(chart_id: &str, deleted_at: SystemTime) -> ChartReply {
+        ChartReply {
+            chart_id: chart_id.to_string(),
+            deleted_at: Some(deleted_at.into()),
+            chart_data: vec![1, 2, 3],
+            defaulted_fields: Vec::new(),
+            ..ChartReply::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn memory_storage_basic() {
+        let now = SystemTime::now();
+        let a_id = Uuid::new_v4().to_string();
+        let b_id = Uuid::new_v4().to_string();
+        let c_id = Uuid::new_v4().to_string();
+
+        let storage = MemoryStorage::new();
+        storage
+            .put(chart(&a_id, now + Duration::from_secs(10)))
+            .await
+            .unwrap();
+        storage.put(chart(&b_id, now)).await.unwrap();
+
+        let a = storage.get(&a_id, now).await.unwrap().unwrap();
+        assert_eq!(vec![1, 2, 3], a.chart_data);
+        assert!(storage.get(&b_id, now).await.unwrap().is_none());
+        assert!(storage.get(&c_id, now).await.unwrap().is_none());
+
+        assert_eq!(1, storage.delete_expired(now).await.unwrap());
+        assert_eq!(1, storage.charts.read().await.len());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::RendererError;
+use crate::proto::render::ChartReply;
+use crate::renderer::LOG_KEY_ERR;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+pub(crate) mod fs;
+pub(crate) mod memory;
+
+const LOG_KEY_DELETED: &str = "deleted";
+
+/// ChartStorage keeps rendered charts with their metadata until they expire.
+#[tonic::async_trait]
+pub(crate) trait ChartStorage: Send + Sync + std::fmt::Debug {
+    /// Save the chart. Existing chart with the same ID is replaced.
+    async fn put(&self, chart: ChartReply) -> Result<(), RendererError>;
+
+    /// Get a chart by its ID if it exists and isn't expired at the provided moment.
+    async fn get(
+        &self,
+        chart_id: &str,
+        now: SystemTime,
+    ) -> Result<Option<ChartReply>, RendererError>;
+
+    /// Delete all charts that are expired at the provided moment and return their count.
+    async fn delete_expired(&self, now: SystemTime) -> Result<usize, RendererError>;
+}
+
+// Check that the chart ID can be used as a storage key.
+pub(crate) fn check_chart_id(chart_id: &str) -> Result<(), RendererError> {
+    match Uuid::parse_str(chart_id) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(RendererError::ChartIdIsInvalid),
+    }
+}
+
+// Check if the chart is expired at the provided moment.
+pub(crate) fn is_expired(chart: &ChartReply, now: SystemTime) -> bool {
+    match chart.deleted_at.clone() {
+        Some(deleted_at) => SystemTime::from(deleted_at) <= now,
+        None => false,
+    }
+}
+
+// Periodically delete expired charts from the storage.
+pub(crate) async fn evict_expired(
+    log: slog::Logger,
+    storage: Arc<dyn ChartStorage>,
+    period: Duration,
+) {
+    let mut interval = tokio::time::interval(period);
+    loop {
+        interval.tick().await;
+        match storage.delete_expired(SystemTime::now()).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(log, "Expired charts are deleted"; LOG_KEY_DELETED => deleted),
+            Err(err) => {
+                error!(log, "Unable to delete expired charts"; LOG_KEY_ERR => err.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_chart_id_basic() {
+        check_chart_id(&Uuid::new_v4().to_string()).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_chart_id_err() {
+        check_chart_id("../chart").unwrap();
+    }
+
+    #[test]
+    fn is_expired_basic() {
+        let now = SystemTime::now();
+        let mut chart = ChartReply::default();
+
+        assert!(!is_expired(&chart, now));
+
+        chart.deleted_at = Some((now + Duration::from_secs(1)).into());
+        assert!(!is_expired(&chart, now));
+
+        chart.deleted_at = Some(now.into());
+        assert!(is_expired(&chart, now));
+    }
+}
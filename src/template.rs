@@ -0,0 +1,211 @@
+use crate::annotation::{get_annotation_views, AnnotationViews};
+use crate::axis::get_axis_options;
+use crate::domain::get_resolved_axes;
+use crate::draw::axis::{AxisPosition, AxisView};
+use crate::draw::legend::LegendView;
+use crate::error::RendererError;
+use crate::legend::get_legend_view;
+use crate::margin::{get_margins, Margins};
+use crate::output::{check_output_size, get_output, get_output_format, get_output_scale, Output};
+use crate::proto::render::{
+    ChartAxes, ChartLayout, ChartOutputFormat, ChartView, RenderChartRequest,
+};
+use crate::scale::{
+    get_axis_view, get_bottom_axis, get_layout_scale, get_left_axis, get_right_axis, get_top_axis,
+    set_chart_bottom_axis, set_chart_left_axis, set_chart_right_axis, set_chart_top_axis, Axis,
+};
+use crate::size::{get_sizes, Sizes};
+use crate::theme::{get_theme, Theme};
+use crate::view::{get_view_templates, get_views, ViewTemplate};
+use lc_render::{Chart, View};
+
+/// Chart prepared from protobuf without view values.
+/// It can be rendered many times with different values of its views.
+pub(crate) struct ChartTemplate {
+    title: String,
+    sizes: Sizes,
+    margins: Margins,
+    axes: ChartAxes,
+    axis_top: Option<Axis>,
+    axis_bottom: Option<Axis>,
+    axis_left: Option<Axis>,
+    axis_right: Option<Axis>,
+    axis_views: Vec<AxisView>,
+    views: Vec<ViewTemplate>,
+    annotation_views: AnnotationViews,
+    legend_view: Option<LegendView>,
+    theme: Option<Theme>,
+    output_format: ChartOutputFormat,
+    output_scale: f32,
+}
+
+impl ChartTemplate {
+    // Prepare chart template from protobuf, view values are not used.
+    pub(crate) fn new(r_req: &RenderChartRequest) -> Result<ChartTemplate, RendererError> {
+        // Get chart output options.
+        let output_format = get_output_format(r_req.output_format)?;
+        let output_scale = get_output_scale(r_req.output_scale)?;
+
+        // Get chart sizes.
+        let sizes = get_sizes(r_req.sizes.clone())?;
+        check_output_size(output_format, sizes.width, sizes.height, output_scale)?;
+
+        // Get chart margins.
+        let margins = get_margins(r_req.margins.clone())?;
+        let view_width = sizes.width - margins.left - margins.right;
+        let view_height = sizes.height - margins.top - margins.bottom;
+
+        // Get chart theme, colors that are not set in views are taken from it.
+        let theme = get_theme(r_req.theme.clone())?;
+        let chart_views = get_themed_views(&theme, &r_req.views);
+
+        // Get axes with auto domains computed from values of the views.
+        let axes = get_resolved_axes(r_req.axes.as_ref(), &chart_views)?;
+
+        // Get chart views without values, axes are needed only for views on scales.
+        let views = get_view_templates(&chart_views, axes.as_ref(), view_width, view_height)?;
+
+        // Get annotations with their values resolved through the same axes as views.
+        let annotation_views =
+            get_annotation_views(&r_req.annotations, axes.as_ref(), view_width, view_height)?;
+        let axes = axes.unwrap_or_default();
+
+        // Get the needed axes with their options.
+        let axis_top = get_top_axis(
+            axes.axis_top.clone(),
+            axes.axis_top_label.clone(),
+            get_axis_options(axes.axis_top_options.clone(), axes.axis_top.as_ref())?,
+        )?;
+        let axis_bottom = get_bottom_axis(
+            axes.axis_bottom.clone(),
+            axes.axis_bottom_label.clone(),
+            get_axis_options(axes.axis_bottom_options.clone(), axes.axis_bottom.as_ref())?,
+        )?;
+        let axis_left = get_left_axis(
+            axes.axis_left.clone(),
+            axes.axis_left_label.clone(),
+            get_axis_options(axes.axis_left_options.clone(), axes.axis_left.as_ref())?,
+        )?;
+        let axis_right = get_right_axis(
+            axes.axis_right.clone(),
+            axes.axis_right_label.clone(),
+            get_axis_options(axes.axis_right_options.clone(), axes.axis_right.as_ref())?,
+        )?;
+
+        // Axes that can't be set on lc-render chart are drawn as views.
+        let axis_views = vec![
+            get_axis_view(&axis_top, AxisPosition::Top, view_width, view_height),
+            get_axis_view(&axis_bottom, AxisPosition::Bottom, view_width, view_height),
+            get_axis_view(&axis_left, AxisPosition::Left, view_width, view_height),
+            get_axis_view(&axis_right, AxisPosition::Right, view_width, view_height),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // Legend is prepared from views of the template.
+        let legend_view = get_legend_view(
+            r_req.legend.clone(),
+            &chart_views,
+            &margins,
+            view_width,
+            view_height,
+        )?;
+
+        Ok(ChartTemplate {
+            title: r_req.title.clone(),
+            sizes,
+            margins,
+            axes,
+            axis_top,
+            axis_bottom,
+            axis_left,
+            axis_right,
+            axis_views,
+            views,
+            annotation_views,
+            legend_view,
+            theme,
+            output_format,
+            output_scale,
+        })
+    }
+
+    // Get layout of the chart with values from protobuf views.
+    // Views are prepared the same way as for rendering but the chart isn't drawn.
+    pub(crate) fn layout(&self, views: &[ChartView]) -> Result<ChartLayout, RendererError> {
+        let views = get_themed_views(&self.theme, views);
+        get_views(&self.views, &views)?;
+
+        Ok(ChartLayout {
+            plot_width: self.sizes.width - self.margins.left - self.margins.right,
+            plot_height: self.sizes.height - self.margins.top - self.margins.bottom,
+            axis_top: get_layout_scale(self.axes.axis_top.clone()),
+            axis_bottom: get_layout_scale(self.axes.axis_bottom.clone()),
+            axis_left: get_layout_scale(self.axes.axis_left.clone()),
+            axis_right: get_layout_scale(self.axes.axis_right.clone()),
+        })
+    }
+
+    // Render chart with values from protobuf views.
+    // Views should be in the same order as views of the template.
+    pub(crate) fn render(&self, views: &[ChartView]) -> Result<Output, RendererError> {
+        let views = get_themed_views(&self.theme, views);
+        let chart_views = get_views(&self.views, &views)?;
+        let all_views: Vec<&dyn View> = self
+            .axis_views
+            .iter()
+            .map(|axis_view| axis_view as &dyn View)
+            .chain(
+                self.annotation_views
+                    .below
+                    .iter()
+                    .map(|annotation_view| annotation_view as &dyn View),
+            )
+            .chain(chart_views.iter().map(Box::as_ref))
+            .chain(
+                self.annotation_views
+                    .above
+                    .iter()
+                    .map(|annotation_view| annotation_view as &dyn View),
+            )
+            .chain(
+                self.legend_view
+                    .iter()
+                    .map(|legend_view| legend_view as &dyn View),
+            )
+            .collect();
+
+        // Prepare a chart.
+        let mut chart = Chart::new()
+            .set_width(self.sizes.width)
+            .set_height(self.sizes.height)
+            .set_margin_top(self.margins.top)
+            .set_margin_bottom(self.margins.bottom)
+            .set_margin_left(self.margins.left)
+            .set_margin_right(self.margins.right)
+            .set_title(&self.title)
+            .set_views(all_views);
+
+        // Set the needed axes.
+        chart = set_chart_top_axis(chart, &self.axis_top);
+        chart = set_chart_bottom_axis(chart, &self.axis_bottom);
+        chart = set_chart_left_axis(chart, &self.axis_left);
+        chart = set_chart_right_axis(chart, &self.axis_right);
+
+        let mut svg = chart.to_svg().to_string();
+        if let Some(theme) = &self.theme {
+            svg = theme.style_svg(&svg, self.sizes.width, self.sizes.height);
+        }
+
+        get_output(svg, self.output_format, self.output_scale)
+    }
+}
+
+// Get views with colors of the theme if it's set.
+pub(crate) fn get_themed_views(theme: &Option<Theme>, views: &[ChartView]) -> Vec<ChartView> {
+    match theme {
+        Some(theme) => theme.set_views_colors(views),
+        None => views.to_vec(),
+    }
+}
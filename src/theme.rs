@@ -0,0 +1,384 @@
+use crate::color::get_color;
+use crate::error::RendererError;
+use crate::proto::render::chart_element_color::ColorValue;
+use crate::proto::render::chart_theme::ChartThemeName;
+use crate::proto::render::chart_view::{ChartViewKind, Values};
+use crate::proto::render::chart_view_bars_values::ChartViewBarsColors;
+use crate::proto::render::{ChartElementColor, ChartTheme, ChartView, ChartViewColors};
+
+const LIGHT_PALETTE: &[&str] = &[
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+const DARK_PALETTE: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+const HIGH_CONTRAST_PALETTE: &[&str] = &[
+    "#0072b2", "#d55e00", "#009e73", "#cc79a7", "#e69f00", "#56b4e9", "#f0e442",
+];
+
+const DEFAULT_FONT_FAMILY: &str = "sans-serif";
+const DEFAULT_TITLE_FONT_SIZE: i32 = 24;
+
+// Selectors of text that is drawn with the default font family.
+const THEMED_FONT_SELECTORS: &str = ".title text, .x-axis text, .y-axis text, .legend text, \
+.bar text, .point text, .slice text, .annotation text";
+
+// Selectors of text that is drawn with the default font color, annotation labels
+// with explicit colors are not matched.
+const THEMED_FILL_SELECTORS: &str = ".x-axis text, .y-axis text, .legend text, .bar text, \
+.point text, .slice text, .annotation text.default-color";
+
+/// Theme contains default colors and fonts of a chart.
+#[derive(Clone)]
+pub(crate) struct Theme {
+    palette: Vec<String>,
+    background_color: String,
+    font_family: String,
+    font_color: String,
+    axis_color: String,
+    grid_color: String,
+    title_color: String,
+    title_font_size: i32,
+}
+
+impl Theme {
+    fn new(palette: &[&str], background_color: &str, font_color: &str, axis_color: &str) -> Self {
+        Theme {
+            palette: palette.iter().map(|color| color.to_string()).collect(),
+            background_color: background_color.to_string(),
+            font_family: DEFAULT_FONT_FAMILY.to_string(),
+            font_color: font_color.to_string(),
+            axis_color: axis_color.to_string(),
+            grid_color: axis_color.to_string(),
+            title_color: font_color.to_string(),
+            title_font_size: DEFAULT_TITLE_FONT_SIZE,
+        }
+    }
+
+    fn light() -> Self {
+        Theme::new(LIGHT_PALETTE, "#ffffff", "#080808", "#bbbbbb").set_grid_color("#e5e5e5")
+    }
+
+    fn dark() -> Self {
+        Theme::new(DARK_PALETTE, "#1e1e1e", "#e6e6e6", "#6b6b6b").set_grid_color("#333333")
+    }
+
+    fn high_contrast() -> Self {
+        Theme::new(HIGH_CONTRAST_PALETTE, "#ffffff", "#000000", "#000000").set_grid_color("#808080")
+    }
+
+    fn set_grid_color(mut self, grid_color: &str) -> Self {
+        self.grid_color = grid_color.to_string();
+        self
+    }
+
+    // Get palette color of a view, dataset or slice with the provided index.
+    fn palette_color(&self, idx: usize) -> Option<ChartElementColor> {
+        Some(ChartElementColor {
+            color_value: Some(ColorValue::ColorHex(
+                self.palette[idx % self.palette.len()].clone(),
+            )),
+        })
+    }
+
+    fn background(&self) -> Option<ChartElementColor> {
+        Some(ChartElementColor {
+            color_value: Some(ColorValue::ColorHex(self.background_color.clone())),
+        })
+    }
+
+    // Get views with colors from the theme that are not set in views.
+    // Palette colors are assigned to views and bars datasets in order,
+    // slices of every pie or donut view take palette colors from the start.
+    pub(crate) fn set_views_colors(&self, views: &[ChartView]) -> Vec<ChartView> {
+        let mut palette_idx = 0;
+        let mut result = Vec::with_capacity(views.len());
+
+        for view in views.iter() {
+            let mut view = view.clone();
+            let kind = ChartViewKind::from_i32(view.kind);
+            let is_pie = matches!(kind, Some(ChartViewKind::Pie) | Some(ChartViewKind::Donut));
+            let color = self.palette_color(palette_idx);
+
+            let colors = view.colors.get_or_insert_with(ChartViewColors::default);
+            colors.fill = colors.fill.take().or_else(|| color.clone());
+            colors.stroke = colors.stroke.take().or_else(|| match is_pie {
+                true => self.background(),
+                false => color.clone(),
+            });
+            colors.point_fill = colors.point_fill.take().or_else(|| color.clone());
+            colors.point_stroke = colors.point_stroke.take().or_else(|| color.clone());
+
+            match &mut view.values {
+                Some(Values::BarsValues(bars_values)) => {
+                    for dataset in bars_values.bars_datasets.iter_mut() {
+                        let color = self.palette_color(palette_idx);
+                        let colors = dataset
+                            .colors
+                            .get_or_insert_with(ChartViewBarsColors::default);
+                        colors.fill = colors.fill.take().or_else(|| color.clone());
+                        colors.stroke = colors.stroke.take().or(color);
+                        palette_idx += 1;
+                    }
+                }
+                Some(Values::SlicesValues(slices_values)) => {
+                    for (idx, slice) in slices_values.slices.iter_mut().enumerate() {
+                        slice.fill = slice.fill.take().or_else(|| self.palette_color(idx));
+                    }
+                }
+                _ => palette_idx += 1,
+            }
+
+            result.push(view);
+        }
+
+        result
+    }
+
+    // Add the theme background and styles to chart SVG document.
+    // Styles override text and axis attributes that are drawn with default values,
+    // so they are scoped to text that has no explicit color like user annotation labels.
+    pub(crate) fn style_svg(&self, svg: &str, width: i32, height: i32) -> String {
+        let style = format!(
+            r#"<rect fill="{}" height="{}" width="{}" x="0" y="0"/>
+<style>
+{} {{ font-family: {}; }}
+{} {{ fill: {}; }}
+.x-axis line, .y-axis line {{ stroke: {}; }}
+.grid line {{ stroke: {}; }}
+.title text {{ fill: {}; font-size: {}px; }}
+</style>"#,
+            self.background_color,
+            height,
+            width,
+            THEMED_FONT_SELECTORS,
+            self.font_family,
+            THEMED_FILL_SELECTORS,
+            self.font_color,
+            self.axis_color,
+            self.grid_color,
+            self.title_color,
+            self.title_font_size,
+        );
+
+        // Background is placed right after the opening tag so it's drawn under the chart.
+        match svg.find('>') {
+            Some(idx) => format!("{}\n{}{}", &svg[..=idx], style, &svg[idx + 1..]),
+            None => svg.to_string(),
+        }
+    }
+}
+
+// Get hex or RGB value of a theme color.
+// Only hex digits are accepted in hex colors since they are used in styles as is.
+fn get_theme_color(color: Option<ChartElementColor>) -> Result<Option<String>, RendererError> {
+    if let Some(ChartElementColor {
+        color_value: Some(ColorValue::ColorHex(hex)),
+    }) = &color
+    {
+        let digits = hex.strip_prefix('#').unwrap_or_default();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(RendererError::ThemeColorIsInvalid);
+        }
+    }
+
+    Ok(get_color(color).map(|color| color.to_string()))
+}
+
+// Get theme from protobuf with custom options set over a named theme.
+pub(crate) fn get_theme(theme: Option<ChartTheme>) -> Result<Option<Theme>, RendererError> {
+    let theme = match theme {
+        Some(theme) => theme,
+        None => return Ok(None),
+    };
+
+    let mut res = match ChartThemeName::from_i32(theme.name) {
+        Some(ChartThemeName::UnspecifiedThemeName) | Some(ChartThemeName::Light) => Theme::light(),
+        Some(ChartThemeName::Dark) => Theme::dark(),
+        Some(ChartThemeName::HighContrast) => Theme::high_contrast(),
+        None => return Err(RendererError::ThemeNameIsUnknown),
+    };
+
+    if !theme.palette.is_empty() {
+        let mut palette = Vec::with_capacity(theme.palette.len());
+        for color in theme.palette.into_iter() {
+            match get_theme_color(Some(color))? {
+                Some(color) => palette.push(color),
+                None => return Err(RendererError::ThemePaletteColorIsNotSpecified),
+            }
+        }
+        res.palette = palette;
+    }
+    if let Some(background_color) = get_theme_color(theme.background)? {
+        res.background_color = background_color;
+    }
+    if !theme.font_family.is_empty() {
+        if !theme
+            .font_family
+            .chars()
+            .all(|c| c.is_alphanumeric() || " ,-_'".contains(c))
+        {
+            return Err(RendererError::ThemeFontFamilyIsInvalid);
+        }
+        res.font_family = theme.font_family;
+    }
+    if let Some(font_color) = get_theme_color(theme.font_color)? {
+        res.font_color = font_color;
+    }
+    if let Some(axis_color) = get_theme_color(theme.axis_color)? {
+        res.axis_color = axis_color;
+    }
+    if let Some(grid_color) = get_theme_color(theme.grid_color)? {
+        res.grid_color = grid_color;
+    }
+    if let Some(title_color) = get_theme_color(theme.title_color)? {
+        res.title_color = title_color;
+    }
+    match theme.title_font_size {
+        Some(title_font_size) if title_font_size > 0 => res.title_font_size = title_font_size,
+        Some(_) => return Err(RendererError::ThemeTitleFontSizeIsInvalid),
+        None => {}
+    }
+
+    Ok(Some(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::get_view_colors;
+    use crate::proto::render::chart_view_bars_values::BarsDataset;
+    use crate::proto::render::chart_view_slices_values::Slice;
+    use crate::proto::render::{ChartViewBarsValues, ChartViewSlicesValues};
+
+    fn color(hex: &str) -> Option<ChartElementColor> {
+        Some(ChartElementColor {
+            color_value: Some(ColorValue::ColorHex(hex.to_string())),
+        })
+    }
+
+    #[test]
+    fn get_theme_basic() {
+        let theme = get_theme(Some(ChartTheme {
+            name: ChartThemeName::Dark as i32,
+            palette: vec![color("#aa0000").unwrap(), color("#00aa00").unwrap()],
+            background: None,
+            font_family: "DejaVu Sans".to_string(),
+            font_color: None,
+            axis_color: None,
+            grid_color: None,
+            title_color: color("#ff0000"),
+            title_font_size: Some(20),
+        }))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(vec!["#aa0000", "#00aa00"], theme.palette);
+        assert_eq!("#1e1e1e", theme.background_color);
+        assert_eq!("DejaVu Sans", theme.font_family);
+        assert_eq!("#e6e6e6", theme.font_color);
+        assert_eq!("#ff0000", theme.title_color);
+        assert_eq!(20, theme.title_font_size);
+    }
+
+    #[test]
+    fn get_theme_empty() {
+        assert!(get_theme(None).unwrap().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_theme_color_err() {
+        get_theme(Some(ChartTheme {
+            background: color("#fff;}"),
+            ..ChartTheme::default()
+        }))
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_theme_font_family_err() {
+        get_theme(Some(ChartTheme {
+            font_family: "serif; } text { fill: red".to_string(),
+            ..ChartTheme::default()
+        }))
+        .unwrap();
+    }
+
+    #[test]
+    fn set_views_colors_basic() {
+        let theme = Theme::light();
+        let views = vec![
+            ChartView {
+                kind: ChartViewKind::Line as i32,
+                colors: Some(ChartViewColors {
+                    stroke: color("#226974"),
+                    ..ChartViewColors::default()
+                }),
+                ..ChartView::default()
+            },
+            ChartView {
+                kind: ChartViewKind::VerticalBar as i32,
+                values: Some(Values::BarsValues(ChartViewBarsValues {
+                    bars_datasets: vec![BarsDataset::default(), BarsDataset::default()],
+                    categories: Vec::new(),
+                })),
+                ..ChartView::default()
+            },
+            ChartView {
+                kind: ChartViewKind::Pie as i32,
+                values: Some(Values::SlicesValues(ChartViewSlicesValues {
+                    slices: vec![Slice::default(), Slice::default()],
+                })),
+                ..ChartView::default()
+            },
+        ];
+
+        let views = theme.set_views_colors(&views);
+        let line_colors = get_view_colors(views[0].colors.clone()).unwrap();
+        let pie_colors = get_view_colors(views[2].colors.clone()).unwrap();
+
+        assert_eq!("#226974", line_colors.stroke_color.unwrap().to_string());
+        assert_eq!("#1f77b4", line_colors.point_fill_color.unwrap().to_string());
+        assert_eq!("#ffffff", pie_colors.stroke_color.unwrap().to_string());
+        match &views[1].values {
+            Some(Values::BarsValues(bars_values)) => {
+                assert_eq!(
+                    color("#ff7f0e"),
+                    bars_values.bars_datasets[0].colors.clone().unwrap().fill
+                );
+                assert_eq!(
+                    color("#2ca02c"),
+                    bars_values.bars_datasets[1].colors.clone().unwrap().fill
+                );
+            }
+            _ => panic!("expected bars values"),
+        }
+        match &views[2].values {
+            Some(Values::SlicesValues(slices_values)) => {
+                assert_eq!(color("#1f77b4"), slices_values.slices[0].fill);
+                assert_eq!(color("#ff7f0e"), slices_values.slices[1].fill);
+            }
+            _ => panic!("expected slices values"),
+        }
+    }
+
+    #[test]
+    fn style_svg_basic() {
+        let svg = Theme::dark().style_svg(
+            "<svg height=\"20\" width=\"10\">\n<g class=\"chart\">\n</g>\n</svg>",
+            10,
+            20,
+        );
+
+        assert!(svg.starts_with(
+            "<svg height=\"20\" width=\"10\">\n<rect fill=\"#1e1e1e\" height=\"20\" width=\"10\" x=\"0\" y=\"0\"/>\n<style>"
+        ));
+        assert!(svg.contains(".annotation text.default-color { fill: #e6e6e6; }"));
+        assert!(!svg.contains("\ntext {"));
+        assert!(svg.ends_with("</style>\n<g class=\"chart\">\n</g>\n</svg>"));
+    }
+}
@@ -0,0 +1,650 @@
+use crate::annotation::{get_annotation_view, is_below_views};
+use crate::axis::{get_axis_options, AxisOptions};
+use crate::domain::get_resolved_scale;
+use crate::draw::axis::AxisPosition;
+use crate::error::RendererError;
+use crate::legend::get_legend_view;
+use crate::margin::get_margins;
+use crate::output::{get_output_format, get_output_scale};
+use crate::proto::google::rpc::bad_request::FieldViolation;
+use crate::proto::google::rpc::{BadRequest, Status as RpcStatus};
+use crate::proto::render::chart_view::ChartViewKind;
+use crate::proto::render::{ChartAxes, RenderChartRequest};
+use crate::scale::{get_bottom_axis, get_left_axis, get_right_axis, get_top_axis};
+use crate::size::get_sizes;
+use crate::template::get_themed_views;
+use crate::theme::get_theme;
+use crate::view::{get_view_templates, get_views};
+use prost::Message;
+use tonic::{Code, Status};
+
+const BAD_REQUEST_TYPE_URL: &str = "type.googleapis.com/google.rpc.BadRequest";
+
+// Get path of the field that is invalid if the error is returned.
+// Paths are relative to the message the error is returned for: a request, an axis scale,
+// a view, a legend or a theme.
+fn get_error_field(err: &RendererError) -> Option<&'static str> {
+    let field = match err {
+        RendererError::OutputFormatIsUnknown => "output_format",
+        RendererError::OutputScaleIsInvalid => "output_scale",
+        RendererError::OutputSizeIsTooLarge => "sizes",
+        RendererError::ChartSizesAreNotSpecified => "sizes",
+        RendererError::ChartWidthIsNotSpecified => "sizes.width",
+        RendererError::ChartHeightIsNotSpecified => "sizes.height",
+        RendererError::ChartMarginsAreNotSpecified => "margins",
+        RendererError::ChartTopMarginIsNotSpecified => "margins.margin_top",
+        RendererError::ChartBottomMarginIsNotSpecified => "margins.margin_bottom",
+        RendererError::ChartLeftMarginIsNotSpecified => "margins.margin_left",
+        RendererError::ChartRightMarginIsNotSpecified => "margins.margin_right",
+        RendererError::ChartAxesAreNotSpecified
+        | RendererError::TopOrBottomAxisShouldBeSpecified
+        | RendererError::LeftOrRightAxisShouldBeSpecified => "axes",
+        RendererError::TopAxisIsSetButItsKindIsUnknown
+        | RendererError::BottomAxisIsSetButItsKindIsUnknown
+        | RendererError::LeftAxisIsSetButItsKindIsUnknown
+        | RendererError::RightAxisIsSetButItsKindIsUnknown
+        | RendererError::ViewKindIsUnknown => "kind",
+        RendererError::ScaleRangeStartIsNotSpecified => "range_start",
+        RendererError::ScaleRangeEndIsNotSpecified => "range_end",
+        RendererError::ScaleDomainIsNotSpecified => "domain",
+        RendererError::LinearScaleNumericDomainIsNotSpecified
+        | RendererError::LogScaleNumericDomainIsNotSpecified
+        | RendererError::LogScaleDomainIsNotPositive
+        | RendererError::LogScaleDomainIsTooWide => "domain_numeric",
+        RendererError::BandScaleCategoriesDomainIsNotSpecified => "domain_categories",
+        RendererError::TimeScaleTimeDomainIsNotSpecified
+        | RendererError::TimeScaleDomainIsInvalid => "domain_time",
+        RendererError::LogScaleBaseIsInvalid => "log_base",
+        RendererError::TimeScaleFormatIsInvalid => "time_format",
+        RendererError::BandScaleInnerPaddingIsNotSpecified => "inner_padding",
+        RendererError::BandScaleOuterPaddingIsNotSpecified => "outer_padding",
+        RendererError::AutoDomainValuesAreNotSpecified => "domain_auto",
+        RendererError::ViewColorsAreNotSpecified => "colors",
+        RendererError::FillColorForAreaViewIsNotSpecified
+        | RendererError::FillColorForHistogramViewIsNotSpecified => "colors.fill",
+        RendererError::StrokeColorForAreaViewIsNotSpecified
+        | RendererError::StrokeColorForLineViewIsNotSpecified
+        | RendererError::StrokeColorForPieViewIsNotSpecified
+        | RendererError::StrokeColorForDonutViewIsNotSpecified
+        | RendererError::StrokeColorForHistogramViewIsNotSpecified => "colors.stroke",
+        RendererError::PointFillColorForAreaViewIsNotSpecified
+        | RendererError::PointFillColorForLineViewIsNotSpecified
+        | RendererError::PointFillColorForScatterViewIsNotSpecified => "colors.point_fill",
+        RendererError::PointStrokeColorForAreaViewIsNotSpecified
+        | RendererError::PointStrokeColorForLineViewIsNotSpecified
+        | RendererError::PointStrokeColorForScatterViewIsNotSpecified => "colors.point_stroke",
+        RendererError::PointVisibilityForAreaViewIsNotSpecified
+        | RendererError::PointVisibilityForLineViewIsNotSpecified
+        | RendererError::PointVisibilityForScatterViewIsNotSpecified => "point_visible",
+        RendererError::PointLabelVisibilityForAreaViewIsNotSpecified
+        | RendererError::PointLabelVisibilityForLineViewIsNotSpecified
+        | RendererError::PointLabelVisibilityForScatterViewIsNotSpecified => "point_label_visible",
+        RendererError::BarLabelVisibilityForHorizontalBarViewIsNotSpecified
+        | RendererError::BarLabelVisibilityForVerticalBarViewIsNotSpecified
+        | RendererError::BarLabelVisibilityForHistogramViewIsNotSpecified => "bar_label_visible",
+        RendererError::SliceLabelVisibilityForPieViewIsNotSpecified
+        | RendererError::SliceLabelVisibilityForDonutViewIsNotSpecified => "slice_label_visible",
+        RendererError::InnerRadiusForDonutViewIsNotSpecified
+        | RendererError::InnerRadiusForDonutViewIsInvalid => "inner_radius",
+        RendererError::PointTypeIsUnknown => "point_type",
+        RendererError::PointLabelPositionIsUnknown => "point_label_position",
+        RendererError::BarLabelPositionIsUnknown => "bar_label_position",
+        RendererError::BarLayoutIsUnknown => "bar_layout",
+        RendererError::HistogramBinningKindIsUnknown => "histogram_binning.kind",
+        RendererError::HistogramBinCountIsInvalid => "histogram_binning.bin_count",
+        RendererError::HistogramBinWidthIsInvalid => "histogram_binning.bin_width",
+        RendererError::ViewHorizontalAxisIsUnknown
+        | RendererError::ViewHorizontalAxisIsNotSpecified
+        | RendererError::ViewHorizontalAxisScaleKindIsInvalid => "horizontal_axis",
+        RendererError::AnnotationKindIsUnknown => "kind",
+        RendererError::AnnotationLayerIsUnknown => "layer",
+        RendererError::AnnotationXIsNotSpecified | RendererError::AnnotationXIsInvalid => "x",
+        RendererError::AnnotationXEndIsNotSpecified | RendererError::AnnotationXEndIsInvalid => {
+            "x_end"
+        }
+        RendererError::AnnotationYIsNotSpecified | RendererError::AnnotationYIsInvalid => "y",
+        RendererError::AnnotationYEndIsNotSpecified | RendererError::AnnotationYEndIsInvalid => {
+            "y_end"
+        }
+        RendererError::AxisTickCountIsInvalid => "tick_count",
+        RendererError::AxisTickValuesAreInvalid => "tick_values",
+        RendererError::AxisNumberFormatIsUnknown => "number_format.kind",
+        RendererError::AxisNumberFormatDecimalsAreInvalid => "number_format.decimals",
+        RendererError::ViewVerticalAxisIsUnknown
+        | RendererError::ViewVerticalAxisIsNotSpecified
+        | RendererError::ViewVerticalAxisScaleKindIsInvalid => "vertical_axis",
+        RendererError::StackedAreaViewRequiresBandScale
+        | RendererError::StackedAreaViewRequiresLinearScale => "stack_group",
+        RendererError::ColorsForBarsValuesAreNotSpecified
+        | RendererError::FillColorForBarsValuesIsNotSpecified
+        | RendererError::StrokeColorForBarsValuesIsNotSpecified
+        | RendererError::BarsDatasetValuesCountIsInvalid => "bars_values",
+        RendererError::FillColorForSliceIsNotSpecified | RendererError::SlicesValuesAreInvalid => {
+            "slices_values"
+        }
+        RendererError::ViewValuesAreNotSpecified
+        | RendererError::ExpectedScalarValues
+        | RendererError::ExpectedBarsValues
+        | RendererError::ExpectedPointsValues
+        | RendererError::ExpectedSlicesValues
+        | RendererError::PointTimeIsNotSpecified
+        | RendererError::PointTimeIsInvalid
+        | RendererError::LogScaleValueIsNotPositive
+        | RendererError::ValuesBoundsCountIsInvalid
+        | RendererError::ValuesBoundsAreInvalid
+        | RendererError::HistogramSamplesAreInvalid => "values",
+        RendererError::LegendPositionIsUnknown => "position",
+        RendererError::LegendOrientationIsUnknown => "orientation",
+        RendererError::LegendFontSizeIsInvalid => "font_size",
+        RendererError::ThemeNameIsUnknown => "name",
+        RendererError::ThemePaletteColorIsNotSpecified => "palette",
+        RendererError::ThemeFontFamilyIsInvalid => "font_family",
+        RendererError::ThemeTitleFontSizeIsInvalid => "title_font_size",
+        _ => return None,
+    };
+
+    Some(field)
+}
+
+// Collects field violations of a request with their paths.
+struct Violations {
+    violations: Vec<FieldViolation>,
+}
+
+impl Violations {
+    // Add a violation for the error of a message with the path, chart-level errors
+    // are added once for the request.
+    fn add(&mut self, path: &str, err: RendererError) {
+        let field = match (get_error_field(&err), &err) {
+            (
+                Some(field),
+                RendererError::ChartAxesAreNotSpecified
+                | RendererError::TopOrBottomAxisShouldBeSpecified
+                | RendererError::LeftOrRightAxisShouldBeSpecified,
+            ) => field.to_string(),
+            (Some(field), _) if !path.is_empty() => format!("{}.{}", path, field),
+            (Some(field), _) => field.to_string(),
+            (None, _) => path.to_string(),
+        };
+        let description = err.to_string();
+        if self
+            .violations
+            .iter()
+            .any(|violation| violation.field == field && violation.description == description)
+        {
+            return;
+        }
+        self.violations.push(FieldViolation { field, description });
+    }
+
+    fn check<T>(&mut self, path: &str, res: Result<T, RendererError>) -> Option<T> {
+        match res {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.add(path, err);
+                None
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.violations.len()
+    }
+}
+
+// Get field violations of the request with paths of all invalid fields.
+// Independent parts of the request are validated separately, so every one of them is
+// reported, a part that depends on invalid ones is skipped.
+pub(crate) fn validate(r_req: &RenderChartRequest) -> Vec<FieldViolation> {
+    let mut violations = Violations {
+        violations: Vec::new(),
+    };
+
+    violations.check("", get_output_format(r_req.output_format));
+    violations.check("", get_output_scale(r_req.output_scale));
+
+    let sizes = match &r_req.sizes {
+        Some(sizes) => {
+            if sizes.width.is_none() {
+                violations.add("", RendererError::ChartWidthIsNotSpecified);
+            }
+            if sizes.height.is_none() {
+                violations.add("", RendererError::ChartHeightIsNotSpecified);
+            }
+            get_sizes(Some(sizes.clone())).ok()
+        }
+        None => violations.check("", get_sizes(None)),
+    };
+    let margins = match &r_req.margins {
+        Some(margins) => {
+            if margins.margin_top.is_none() {
+                violations.add("", RendererError::ChartTopMarginIsNotSpecified);
+            }
+            if margins.margin_bottom.is_none() {
+                violations.add("", RendererError::ChartBottomMarginIsNotSpecified);
+            }
+            if margins.margin_left.is_none() {
+                violations.add("", RendererError::ChartLeftMarginIsNotSpecified);
+            }
+            if margins.margin_right.is_none() {
+                violations.add("", RendererError::ChartRightMarginIsNotSpecified);
+            }
+            get_margins(Some(margins.clone())).ok()
+        }
+        None => violations.check("", get_margins(None)),
+    };
+    let (view_width, view_height) = match (&sizes, &margins) {
+        (Some(sizes), Some(margins)) => (
+            sizes.width - margins.left - margins.right,
+            sizes.height - margins.top - margins.bottom,
+        ),
+        _ => (0, 0),
+    };
+
+    let theme = violations.check("theme", get_theme(r_req.theme.clone()));
+    let views = get_themed_views(&theme.flatten(), &r_req.views);
+
+    // Axes are validated before views since views are checked with their scales.
+    let count = violations.len();
+    let needs_axes = views.iter().any(|view| {
+        !matches!(
+            ChartViewKind::from_i32(view.kind),
+            Some(ChartViewKind::Pie) | Some(ChartViewKind::Donut) | None
+        )
+    });
+    let axes = match &r_req.axes {
+        Some(axes) => {
+            // Auto domains are resolved before scales of axes are checked.
+            let axis_top = violations
+                .check(
+                    "axes.axis_top",
+                    get_resolved_scale(axes, AxisPosition::Top, &views),
+                )
+                .flatten();
+            let axis_bottom = violations
+                .check(
+                    "axes.axis_bottom",
+                    get_resolved_scale(axes, AxisPosition::Bottom, &views),
+                )
+                .flatten();
+            let axis_left = violations
+                .check(
+                    "axes.axis_left",
+                    get_resolved_scale(axes, AxisPosition::Left, &views),
+                )
+                .flatten();
+            let axis_right = violations
+                .check(
+                    "axes.axis_right",
+                    get_resolved_scale(axes, AxisPosition::Right, &views),
+                )
+                .flatten();
+            violations.check(
+                "axes.axis_top",
+                get_top_axis(axis_top.clone(), String::new(), AxisOptions::default()),
+            );
+            violations.check(
+                "axes.axis_bottom",
+                get_bottom_axis(axis_bottom.clone(), String::new(), AxisOptions::default()),
+            );
+            violations.check(
+                "axes.axis_left",
+                get_left_axis(axis_left.clone(), String::new(), AxisOptions::default()),
+            );
+            violations.check(
+                "axes.axis_right",
+                get_right_axis(axis_right.clone(), String::new(), AxisOptions::default()),
+            );
+            violations.check(
+                "axes.axis_top_options",
+                get_axis_options(axes.axis_top_options.clone(), axis_top.as_ref()),
+            );
+            violations.check(
+                "axes.axis_bottom_options",
+                get_axis_options(axes.axis_bottom_options.clone(), axis_bottom.as_ref()),
+            );
+            violations.check(
+                "axes.axis_left_options",
+                get_axis_options(axes.axis_left_options.clone(), axis_left.as_ref()),
+            );
+            violations.check(
+                "axes.axis_right_options",
+                get_axis_options(axes.axis_right_options.clone(), axis_right.as_ref()),
+            );
+            Some(ChartAxes {
+                axis_top,
+                axis_bottom,
+                axis_left,
+                axis_right,
+                ..axes.clone()
+            })
+        }
+        None => {
+            if needs_axes {
+                violations.add("", RendererError::ChartAxesAreNotSpecified);
+            }
+            None
+        }
+    };
+    let axes_are_valid = violations.len() == count;
+
+    // Views are checked through the same templates they're rendered with, views that are
+    // drawn on scales are checked only if axes are valid.
+    let count = violations.len();
+    let view_axes = if axes_are_valid { axes.as_ref() } else { None };
+    for (idx, view) in views.iter().enumerate() {
+        let path = format!("views[{}]", idx);
+        let templates = match get_view_templates(
+            std::slice::from_ref(view),
+            view_axes,
+            view_width,
+            view_height,
+        ) {
+            Ok(templates) => templates,
+            Err(RendererError::ChartAxesAreNotSpecified) if !axes_are_valid => continue,
+            Err(err) => {
+                violations.add(&path, err);
+                continue;
+            }
+        };
+        violations.check(&path, get_views(&templates, std::slice::from_ref(view)));
+    }
+
+    // Views that depend on each other like stacked areas are checked together.
+    if axes_are_valid && violations.len() == count {
+        if let Ok(templates) = get_view_templates(&views, axes.as_ref(), view_width, view_height) {
+            violations.check("views", get_views(&templates, &views));
+        }
+    }
+
+    // Annotations are resolved only through the scales of valid axes.
+    for (idx, annotation) in r_req.annotations.iter().enumerate() {
+        let path = format!("annotations[{}]", idx);
+        violations.check(&path, is_below_views(annotation));
+        if axes_are_valid {
+            violations.check(
+                &path,
+                get_annotation_view(annotation, axes.as_ref(), view_width, view_height),
+            );
+        }
+    }
+
+    if let (Some(_), Some(margins)) = (&sizes, &margins) {
+        violations.check(
+            "legend",
+            get_legend_view(
+                r_req.legend.clone(),
+                &views,
+                margins,
+                view_width,
+                view_height,
+            ),
+        );
+    }
+
+    violations.violations
+}
+
+// Get field violations of the request that can't be rendered because of the error.
+// The error is returned as a violation of the whole request if fields are not found.
+pub(crate) fn get_field_violations(
+    r_req: &RenderChartRequest,
+    err: &RendererError,
+) -> Vec<FieldViolation> {
+    let violations = validate(r_req);
+    if !violations.is_empty() {
+        return violations;
+    }
+
+    vec![FieldViolation {
+        field: String::new(),
+        description: err.to_string(),
+    }]
+}
+
+// Get gRPC status for the request that can't be rendered because of the error.
+// Violations of all invalid fields are set as google.rpc.BadRequest details.
+pub(crate) fn get_invalid_argument_status(
+    r_req: &RenderChartRequest,
+    err: &RendererError,
+) -> Status {
+    let message = err.to_string();
+    let violations = get_field_violations(r_req, err);
+
+    let bad_request = BadRequest {
+        field_violations: violations,
+    };
+    let mut value = Vec::with_capacity(bad_request.encoded_len());
+    if bad_request.encode(&mut value).is_err() {
+        return Status::invalid_argument(message);
+    }
+    let rpc_status = RpcStatus {
+        code: Code::InvalidArgument as i32,
+        message: message.clone(),
+        details: vec![prost_types::Any {
+            type_url: BAD_REQUEST_TYPE_URL.to_string(),
+            value,
+        }],
+    };
+    let mut details = Vec::with_capacity(rpc_status.encoded_len());
+    if rpc_status.encode(&mut details).is_err() {
+        return Status::invalid_argument(message);
+    }
+
+    Status::with_details(Code::InvalidArgument, message, details.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::render::chart_annotation::ChartAnnotationKind;
+    use crate::proto::render::chart_annotation_value::Value as AnnotationValue;
+    use crate::proto::render::chart_element_color::ColorValue;
+    use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
+    use crate::proto::render::chart_view::{
+        ChartViewPointLabelPosition, ChartViewPointType, ChartViewVerticalAxis, Values,
+    };
+    use crate::proto::render::{
+        ChartAnnotation, ChartAnnotationValue, ChartAxes, ChartElementColor, ChartLegend,
+        ChartMargins, ChartScale, ChartSizes, ChartTheme, ChartView, ChartViewColors,
+        ChartViewScalarValues, DomainCategories, DomainNumeric,
+    };
+
+    fn color(hex: &str) -> Option<ChartElementColor> {
+        Some(ChartElementColor {
+            color_value: Some(ColorValue::ColorHex(hex.to_string())),
+        })
+    }
+
+    fn render_chart_request() -> RenderChartRequest {
+        RenderChartRequest {
+            sizes: Some(ChartSizes {
+                width: Some(800),
+                height: Some(600),
+            }),
+            margins: Some(ChartMargins {
+                margin_top: Some(90),
+                margin_bottom: Some(50),
+                margin_left: Some(60),
+                margin_right: Some(40),
+            }),
+            axes: Some(ChartAxes {
+                axis_bottom: Some(ChartScale {
+                    kind: ChartScaleKind::Band as i32,
+                    range_start: Some(0),
+                    range_end: Some(700),
+                    domain: Some(Domain::DomainCategories(DomainCategories {
+                        categories: vec!["a".to_string(), "b".to_string()],
+                    })),
+                    inner_padding: Some(0.1_f32),
+                    outer_padding: Some(0.1_f32),
+                    ..ChartScale::default()
+                }),
+                axis_left: Some(ChartScale {
+                    kind: ChartScaleKind::Linear as i32,
+                    range_start: Some(460),
+                    range_end: Some(0),
+                    domain: Some(Domain::DomainNumeric(DomainNumeric {
+                        start: 0_f32,
+                        end: 100_f32,
+                    })),
+                    ..ChartScale::default()
+                }),
+                ..ChartAxes::default()
+            }),
+            views: vec![ChartView {
+                kind: ChartViewKind::Line as i32,
+                colors: Some(ChartViewColors {
+                    stroke: color("#226974"),
+                    point_fill: color("#1a888b"),
+                    point_stroke: color("#50c5b6"),
+                    ..ChartViewColors::default()
+                }),
+                point_visible: Some(true),
+                point_type: ChartViewPointType::Circle as i32,
+                point_label_visible: Some(false),
+                point_label_position: ChartViewPointLabelPosition::Top as i32,
+                values: Some(Values::ScalarValues(ChartViewScalarValues {
+                    values: vec![10_f32, 20_f32],
+                    categories: Vec::new(),
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                })),
+                ..ChartView::default()
+            }],
+            ..RenderChartRequest::default()
+        }
+    }
+
+    #[test]
+    fn validate_basic() {
+        assert!(validate(&render_chart_request()).is_empty());
+    }
+
+    #[test]
+    fn validate_many_fields() {
+        let mut r_req = render_chart_request();
+        r_req.output_scale = Some(-1_f32);
+        r_req
+            .axes
+            .as_mut()
+            .unwrap()
+            .axis_left
+            .as_mut()
+            .unwrap()
+            .range_start = None;
+        r_req.views[0].colors.as_mut().unwrap().stroke = None;
+        r_req.views[0].point_visible = None;
+        r_req.views.push(ChartView::default());
+        r_req.legend = Some(ChartLegend {
+            position: 100,
+            ..ChartLegend::default()
+        });
+        r_req.theme = Some(ChartTheme {
+            name: 100,
+            ..ChartTheme::default()
+        });
+
+        let fields: Vec<_> = validate(&r_req)
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect();
+
+        assert_eq!(
+            vec![
+                "output_scale",
+                "theme.name",
+                "axes.axis_left.range_start",
+                "views[0].colors.stroke",
+                "views[1].kind",
+                "legend.position",
+            ],
+            fields
+        );
+    }
+
+    #[test]
+    fn validate_view_axes() {
+        let mut r_req = render_chart_request();
+        r_req.views.push(r_req.views[0].clone());
+        r_req.views[0].vertical_axis = ChartViewVerticalAxis::AxisRight as i32;
+        r_req.views[1].horizontal_axis = 100;
+
+        let fields: Vec<_> = validate(&r_req)
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect();
+
+        assert_eq!(
+            vec!["views[0].vertical_axis", "views[1].horizontal_axis"],
+            fields
+        );
+    }
+
+    #[test]
+    fn validate_annotations() {
+        let mut r_req = render_chart_request();
+        r_req.annotations = vec![
+            ChartAnnotation {
+                kind: ChartAnnotationKind::VerticalRange as i32,
+                x: Some(ChartAnnotationValue {
+                    value: Some(AnnotationValue::Category("a".to_string())),
+                }),
+                ..ChartAnnotation::default()
+            },
+            ChartAnnotation {
+                kind: ChartAnnotationKind::HorizontalLine as i32,
+                y: Some(ChartAnnotationValue {
+                    value: Some(AnnotationValue::Category("a".to_string())),
+                }),
+                layer: 100,
+                ..ChartAnnotation::default()
+            },
+        ];
+
+        let fields: Vec<_> = validate(&r_req)
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect();
+
+        assert_eq!(
+            vec![
+                "annotations[0].x_end",
+                "annotations[1].layer",
+                "annotations[1].y",
+            ],
+            fields
+        );
+    }
+
+    #[test]
+    fn get_invalid_argument_status_basic() {
+        let mut r_req = render_chart_request();
+        r_req.sizes = None;
+        r_req.views[0].colors = None;
+
+        let status = get_invalid_argument_status(&r_req, &RendererError::ChartSizesAreNotSpecified);
+        let rpc_status = RpcStatus::decode(status.details()).unwrap();
+        let bad_request = BadRequest::decode(rpc_status.details[0].value.as_slice()).unwrap();
+
+        assert_eq!(Code::InvalidArgument, status.code());
+        assert_eq!(
+            RendererError::ChartSizesAreNotSpecified.to_string(),
+            status.message()
+        );
+        assert_eq!(BAD_REQUEST_TYPE_URL, rpc_status.details[0].type_url);
+        assert_eq!(
+            vec![
+                FieldViolation {
+                    field: "sizes".to_string(),
+                    description: RendererError::ChartSizesAreNotSpecified.to_string(),
+                },
+                FieldViolation {
+                    field: "views[0].colors".to_string(),
+                    description: RendererError::ViewColorsAreNotSpecified.to_string(),
+                },
+            ],
+            bad_request.field_violations
+        );
+    }
+}
@@ -1,39 +1,112 @@
 use crate::color::get_color;
+use crate::draw::view::SliceValue;
 use crate::error::RendererError;
 use crate::proto::render::chart_view::Values;
 use crate::proto::render::{
-    ChartView, ChartViewBarsValues, ChartViewPointsValues, ChartViewScalarValues,
+    chart_view_values, ChartView, ChartViewBarsValues, ChartViewPointsValues,
+    ChartViewScalarValues, ChartViewSlicesValues, ChartViewValues,
 };
+use crate::scale::{get_time, is_time_valid};
 use lc_render::BarsValues;
 
 // Get scalar values from chart view.
 pub(crate) fn get_scalar_values(view: &ChartView) -> Result<&[f32], RendererError> {
-    let chart_view_scalar_values: &ChartViewScalarValues;
-
-    match &view.values {
-        Some(view_values) => {
-            chart_view_scalar_values = match view_values {
-                Values::ScalarValues(scalar_values) => scalar_values,
-                _ => return Err(RendererError::ExpectedScalarValues),
-            };
-        }
+    let chart_view_scalar_values: &ChartViewScalarValues = match &view.values {
+        Some(Values::ScalarValues(scalar_values)) => scalar_values,
+        Some(_) => return Err(RendererError::ExpectedScalarValues),
         None => return Err(RendererError::ViewValuesAreNotSpecified),
     };
 
     Ok(&chart_view_scalar_values.values)
 }
 
-// Get bars values from chart view.
-pub(crate) fn get_bars_values(view: &ChartView) -> Result<Vec<BarsValues>, RendererError> {
-    let chart_view_bars_values: &ChartViewBarsValues;
+// Get lower and upper bounds of values, a bound that is not set equals the value.
+// Bounds are empty if neither lower nor upper bounds are set.
+fn get_bounds(
+    values: &[f32],
+    lower_bounds: &[f32],
+    upper_bounds: &[f32],
+) -> Result<Vec<(f32, f32)>, RendererError> {
+    if lower_bounds.is_empty() && upper_bounds.is_empty() {
+        return Ok(Vec::new());
+    }
+    let is_count_valid = |bounds: &[f32]| bounds.is_empty() || bounds.len() == values.len();
+    if !is_count_valid(lower_bounds) || !is_count_valid(upper_bounds) {
+        return Err(RendererError::ValuesBoundsCountIsInvalid);
+    }
+    if lower_bounds
+        .iter()
+        .chain(upper_bounds.iter())
+        .any(|bound| !bound.is_finite())
+    {
+        return Err(RendererError::ValuesBoundsAreInvalid);
+    }
+
+    Ok(values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            (
+                *lower_bounds.get(idx).unwrap_or(value),
+                *upper_bounds.get(idx).unwrap_or(value),
+            )
+        })
+        .collect())
+}
+
+// Get bounds of scalar values from chart view.
+pub(crate) fn get_scalar_bounds(view: &ChartView) -> Result<Vec<(f32, f32)>, RendererError> {
+    match &view.values {
+        Some(Values::ScalarValues(scalar_values)) => get_bounds(
+            &scalar_values.values,
+            &scalar_values.lower_bounds,
+            &scalar_values.upper_bounds,
+        ),
+        Some(_) => Err(RendererError::ExpectedScalarValues),
+        None => Err(RendererError::ViewValuesAreNotSpecified),
+    }
+}
 
+// Get bounds of y values of points from chart view.
+pub(crate) fn get_points_bounds(view: &ChartView) -> Result<Vec<(f32, f32)>, RendererError> {
     match &view.values {
-        Some(view_values) => {
-            chart_view_bars_values = match view_values {
-                Values::BarsValues(bars_values) => bars_values,
-                _ => return Err(RendererError::ExpectedBarsValues),
-            };
+        Some(Values::PointsValues(points_values)) => {
+            let values: Vec<f32> = points_values.points.iter().map(|point| point.y).collect();
+            get_bounds(
+                &values,
+                &points_values.lower_bounds,
+                &points_values.upper_bounds,
+            )
         }
+        Some(_) => Err(RendererError::ExpectedPointsValues),
+        None => Err(RendererError::ViewValuesAreNotSpecified),
+    }
+}
+
+// Get bounds of values of every bars dataset from chart view.
+pub(crate) fn get_bars_bounds(view: &ChartView) -> Result<Vec<Vec<(f32, f32)>>, RendererError> {
+    match &view.values {
+        Some(Values::BarsValues(bars_values)) => bars_values
+            .bars_datasets
+            .iter()
+            .map(|dataset| {
+                get_bounds(
+                    &dataset.values,
+                    &dataset.lower_bounds,
+                    &dataset.upper_bounds,
+                )
+            })
+            .collect(),
+        Some(_) => Err(RendererError::ExpectedBarsValues),
+        None => Err(RendererError::ViewValuesAreNotSpecified),
+    }
+}
+
+// Get bars values from chart view.
+pub(crate) fn get_bars_values(view: &ChartView) -> Result<Vec<BarsValues>, RendererError> {
+    let chart_view_bars_values: &ChartViewBarsValues = match &view.values {
+        Some(Values::BarsValues(bars_values)) => bars_values,
+        Some(_) => return Err(RendererError::ExpectedBarsValues),
         None => return Err(RendererError::ViewValuesAreNotSpecified),
     };
 
@@ -63,15 +136,9 @@ pub(crate) fn get_bars_values(view: &ChartView) -> Result<Vec<BarsValues>, Rende
 
 // Get points values from chart view.
 pub(crate) fn get_points_values(view: &ChartView) -> Result<Vec<(f32, f32)>, RendererError> {
-    let chart_view_points_values: &ChartViewPointsValues;
-
-    match &view.values {
-        Some(view_values) => {
-            chart_view_points_values = match view_values {
-                Values::PointsValues(chart_view_points_values) => chart_view_points_values,
-                _ => return Err(RendererError::ExpectedPointsValues),
-            };
-        }
+    let chart_view_points_values: &ChartViewPointsValues = match &view.values {
+        Some(Values::PointsValues(chart_view_points_values)) => chart_view_points_values,
+        Some(_) => return Err(RendererError::ExpectedPointsValues),
         None => return Err(RendererError::ViewValuesAreNotSpecified),
     };
 
@@ -83,14 +150,82 @@ pub(crate) fn get_points_values(view: &ChartView) -> Result<Vec<(f32, f32)>, Ren
     Ok(values)
 }
 
+// Get points values with their time as x from chart view.
+pub(crate) fn get_time_points_values(view: &ChartView) -> Result<Vec<(f64, f32)>, RendererError> {
+    let chart_view_points_values: &ChartViewPointsValues = match &view.values {
+        Some(Values::PointsValues(chart_view_points_values)) => chart_view_points_values,
+        Some(_) => return Err(RendererError::ExpectedPointsValues),
+        None => return Err(RendererError::ViewValuesAreNotSpecified),
+    };
+
+    let mut values: Vec<(f64, f32)> = Vec::with_capacity(chart_view_points_values.points.len());
+    for points_value in chart_view_points_values.points.iter() {
+        let time = match &points_value.time {
+            Some(time) if is_time_valid(time) => get_time(time),
+            Some(_) => return Err(RendererError::PointTimeIsInvalid),
+            None => return Err(RendererError::PointTimeIsNotSpecified),
+        };
+        values.push((time, points_value.y));
+    }
+
+    Ok(values)
+}
+
+// Get slices values from chart view.
+pub(crate) fn get_slices_values(view: &ChartView) -> Result<Vec<SliceValue>, RendererError> {
+    let chart_view_slices_values: &ChartViewSlicesValues = match &view.values {
+        Some(Values::SlicesValues(slices_values)) => slices_values,
+        Some(_) => return Err(RendererError::ExpectedSlicesValues),
+        None => return Err(RendererError::ViewValuesAreNotSpecified),
+    };
+
+    let mut res = Vec::with_capacity(chart_view_slices_values.slices.len());
+    for slice in chart_view_slices_values.slices.iter() {
+        let fill_color = match get_color(slice.fill.clone()) {
+            Some(fill_color) => fill_color,
+            None => return Err(RendererError::FillColorForSliceIsNotSpecified),
+        };
+        res.push(SliceValue::new(&slice.label, slice.value, fill_color));
+    }
+
+    Ok(res)
+}
+
+// Get chart views that contain only values from frame views values.
+pub(crate) fn get_frame_views(views_values: Vec<ChartViewValues>) -> Vec<ChartView> {
+    views_values
+        .into_iter()
+        .map(|view_values| ChartView {
+            values: match view_values.values {
+                Some(chart_view_values::Values::ScalarValues(scalar_values)) => {
+                    Some(Values::ScalarValues(scalar_values))
+                }
+                Some(chart_view_values::Values::PointsValues(points_values)) => {
+                    Some(Values::PointsValues(points_values))
+                }
+                Some(chart_view_values::Values::BarsValues(bars_values)) => {
+                    Some(Values::BarsValues(bars_values))
+                }
+                Some(chart_view_values::Values::SlicesValues(slices_values)) => {
+                    Some(Values::SlicesValues(slices_values))
+                }
+                None => None,
+            },
+            ..ChartView::default()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::proto::render::chart_element_color::ColorValue;
     use crate::proto::render::chart_view_bars_values::{BarsDataset, ChartViewBarsColors};
     use crate::proto::render::chart_view_points_values::Point;
+    use crate::proto::render::chart_view_slices_values::Slice;
     use crate::proto::render::ChartElementColor;
     use lc_render::Color;
+    use prost_types::Timestamp;
 
     fn chart_view_empty() -> ChartView {
         ChartView {
@@ -103,6 +238,16 @@ mod tests {
             point_label_visible: Some(false),
             point_label_position: 0,
             values: None,
+            inner_radius: None,
+            start_angle: None,
+            slice_label_visible: None,
+            bar_layout: 0,
+            stack_group: String::new(),
+            stack_normalized: false,
+            name: String::new(),
+            horizontal_axis: 0,
+            vertical_axis: 0,
+            histogram_binning: None,
         }
     }
 
@@ -111,6 +256,9 @@ mod tests {
         let mut view = chart_view_empty();
         view.values = Some(Values::ScalarValues(ChartViewScalarValues {
             values: vec![1_f32, 2_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
 
         let scalar_values = get_scalar_values(&view).unwrap();
@@ -120,7 +268,7 @@ mod tests {
 
     #[test]
     fn get_bars_values_basic() {
-        let expected_bars_values = vec![
+        let expected_bars_values = [
             BarsValues::new(vec![1_f32, 2_f32])
                 .set_fill_color(Color::new_from_hex("#FA4988"))
                 .set_stroke_color(Color::new_from_hex("#9C0412")),
@@ -142,6 +290,9 @@ mod tests {
                             color_value: Some(ColorValue::ColorHex("#9C0412".to_string())),
                         }),
                     }),
+                    name: String::new(),
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
                 },
                 BarsDataset {
                     values: vec![3_f32, 4_f32],
@@ -153,8 +304,12 @@ mod tests {
                             color_value: Some(ColorValue::ColorHex("#004F84".to_string())),
                         }),
                     }),
+                    name: String::new(),
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
                 },
             ],
+            categories: Vec::new(),
         }));
 
         let bars_values = get_bars_values(&view).unwrap();
@@ -176,7 +331,20 @@ mod tests {
     fn get_points_values_basic() {
         let mut view = chart_view_empty();
         view.values = Some(Values::PointsValues(ChartViewPointsValues {
-            points: vec![Point { x: 1_f32, y: 2_f32 }, Point { x: 3_f32, y: 4_f32 }],
+            points: vec![
+                Point {
+                    x: 1_f32,
+                    y: 2_f32,
+                    time: None,
+                },
+                Point {
+                    x: 3_f32,
+                    y: 4_f32,
+                    time: None,
+                },
+            ],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
 
         let points_values = get_points_values(&view).unwrap();
@@ -184,6 +352,98 @@ mod tests {
         assert_eq!(vec![(1_f32, 2_f32), (3_f32, 4_f32)], points_values);
     }
 
+    #[test]
+    fn get_scalar_bounds_basic() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+            values: vec![1_f32, 2_f32],
+            categories: Vec::new(),
+            lower_bounds: vec![0.5_f32, 1.5_f32],
+            upper_bounds: Vec::new(),
+        }));
+
+        let scalar_bounds = get_scalar_bounds(&view).unwrap();
+
+        assert_eq!(vec![(0.5_f32, 1_f32), (1.5_f32, 2_f32)], scalar_bounds);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_points_bounds_err() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![Point {
+                x: 1_f32,
+                y: 2_f32,
+                time: None,
+            }],
+            lower_bounds: vec![1_f32, 2_f32],
+            upper_bounds: vec![3_f32],
+        }));
+
+        get_points_bounds(&view).unwrap();
+    }
+
+    #[test]
+    fn get_time_points_values_basic() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![Point {
+                x: 0_f32,
+                y: 2_f32,
+                time: Some(Timestamp {
+                    seconds: 1614556800,
+                    nanos: 500_000_000,
+                }),
+            }],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+
+        let points_values = get_time_points_values(&view).unwrap();
+
+        assert_eq!(vec![(1614556800.5_f64, 2_f32)], points_values);
+    }
+
+    #[test]
+    fn get_slices_values_basic() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::SlicesValues(ChartViewSlicesValues {
+            slices: vec![Slice {
+                label: "a".to_string(),
+                value: 2_f32,
+                fill: Some(ChartElementColor {
+                    color_value: Some(ColorValue::ColorHex("#FA4988".to_string())),
+                }),
+            }],
+        }));
+
+        let slices_values = get_slices_values(&view).unwrap();
+
+        assert_eq!(1, slices_values.len());
+    }
+
+    #[test]
+    fn get_frame_views_basic() {
+        let views = get_frame_views(vec![
+            ChartViewValues {
+                values: Some(chart_view_values::Values::ScalarValues(
+                    ChartViewScalarValues {
+                        values: vec![1_f32, 2_f32],
+                        categories: Vec::new(),
+                        lower_bounds: Vec::new(),
+                        upper_bounds: Vec::new(),
+                    },
+                )),
+            },
+            ChartViewValues { values: None },
+        ]);
+
+        assert_eq!(2, views.len());
+        assert_eq!(vec![1_f32, 2_f32], get_scalar_values(&views[0]).unwrap());
+        assert_eq!(None, views[1].values);
+    }
+
     #[test]
     #[should_panic]
     fn get_scalar_values_err() {
@@ -207,4 +467,56 @@ mod tests {
 
         get_points_values(&view).unwrap();
     }
+
+    #[test]
+    #[should_panic]
+    fn get_slices_values_err() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::SlicesValues(ChartViewSlicesValues {
+            slices: vec![Slice {
+                label: "a".to_string(),
+                value: 2_f32,
+                fill: None,
+            }],
+        }));
+
+        get_slices_values(&view).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_time_points_values_err() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![Point {
+                x: 1_f32,
+                y: 2_f32,
+                time: None,
+            }],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+
+        get_time_points_values(&view).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_time_points_values_out_of_range_err() {
+        let mut view = chart_view_empty();
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![Point {
+                x: 1_f32,
+                y: 2_f32,
+                time: Some(Timestamp {
+                    seconds: i64::MAX,
+                    nanos: 0,
+                }),
+            }],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+
+        get_time_points_values(&view).unwrap();
+    }
 }
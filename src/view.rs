@@ -1,41 +1,223 @@
-use crate::bar::get_bar_label_position;
+use crate::bar::{get_bar_label_position, get_bar_layout};
 use crate::color::get_view_colors;
+use crate::draw::scale::{NumericScale, PointsScale};
+use crate::draw::view::{
+    AreaView, HistogramView, HorizontalBarView, LineView, PieView, ScatterView, VerticalBarView,
+};
 use crate::error::RendererError;
+use crate::histogram::get_histogram_bins;
 use crate::point::{get_point_label_position, get_point_type};
+use crate::proto::render::chart_scale::ChartScaleKind;
 use crate::proto::render::chart_view::ChartViewKind;
-use crate::proto::render::{ChartScale, ChartView};
-use crate::scale::{get_band_h_scale, get_band_v_scale, get_linear_h_scale, get_linear_v_scale};
-use crate::value::{get_bars_values, get_points_values, get_scalar_values};
-use lc_render::{AreaView, HorizontalBarView, LineView, ScatterView, VerticalBarView, View};
+use crate::proto::render::{ChartAxes, ChartScale, ChartView};
+use crate::scale::{
+    get_band_h_scale, get_band_v_scale, get_h_axis, get_h_scale, get_line_h_scale,
+    get_numeric_h_scale, get_numeric_v_scale, get_scatter_h_scale, get_v_axis, get_v_scale,
+};
+use crate::value::{
+    get_bars_bounds, get_bars_values, get_points_bounds, get_points_values, get_scalar_bounds,
+    get_scalar_values, get_slices_values, get_time_points_values,
+};
+use lc_render::View;
+use std::collections::HashMap;
 
-pub(crate) fn get_views(
+// Bottom and top values of a stacked area.
+type AreaStack = (Vec<f32>, Vec<f32>);
+
+/// Chart view prepared from protobuf without its values.
+#[derive(Clone)]
+pub(crate) enum ViewTemplate {
+    Area(AreaView),
+    Histogram(HistogramView),
+    HorizontalBar(HorizontalBarView),
+    Line(LineView),
+    Pie(PieView),
+    Scatter(ScatterView),
+    VerticalBar(VerticalBarView),
+}
+
+impl ViewTemplate {
+    // Get a view with values from protobuf view.
+    pub(crate) fn set_values(&self, view: &ChartView) -> Result<Box<dyn View>, RendererError> {
+        match self {
+            ViewTemplate::Area(area_view) => match area_view.x_scale() {
+                PointsScale::Band(_) => {
+                    let values = get_scalar_values(view)?;
+                    let bounds = get_scalar_bounds(view)?;
+                    Ok(Box::new(area_view.clone().set_data(values, &bounds)?))
+                }
+                x_scale => {
+                    let values = get_x_points_values(x_scale, view)?;
+                    let bounds = get_points_bounds(view)?;
+                    Ok(Box::new(
+                        area_view.clone().set_points_data(&values, &bounds)?,
+                    ))
+                }
+            },
+            ViewTemplate::Histogram(histogram_view) => {
+                let bins = get_histogram_bins(view)?;
+                Ok(Box::new(histogram_view.clone().set_data(&bins)?))
+            }
+            ViewTemplate::HorizontalBar(horizontal_bar_view) => {
+                let values = get_bars_values(view)?;
+                let bounds = get_bars_bounds(view)?;
+                Ok(Box::new(
+                    horizontal_bar_view.clone().set_data(&values, &bounds)?,
+                ))
+            }
+            ViewTemplate::Line(line_view) => match line_view.x_scale() {
+                PointsScale::Band(_) => {
+                    let values = get_scalar_values(view)?;
+                    let bounds = get_scalar_bounds(view)?;
+                    Ok(Box::new(line_view.clone().set_data(values, &bounds)?))
+                }
+                x_scale => {
+                    let values = get_x_points_values(x_scale, view)?;
+                    let bounds = get_points_bounds(view)?;
+                    Ok(Box::new(
+                        line_view.clone().set_points_data(&values, &bounds)?,
+                    ))
+                }
+            },
+            ViewTemplate::Pie(pie_view) => {
+                let values = get_slices_values(view)?;
+                Ok(Box::new(pie_view.clone().set_data(&values)?))
+            }
+            ViewTemplate::Scatter(scatter_view) => {
+                let values = get_x_points_values(scatter_view.x_scale(), view)?;
+                let bounds = get_points_bounds(view)?;
+                Ok(Box::new(scatter_view.clone().set_data(&values, &bounds)?))
+            }
+            ViewTemplate::VerticalBar(vertical_bar_view) => {
+                let values = get_bars_values(view)?;
+                let bounds = get_bars_bounds(view)?;
+                Ok(Box::new(
+                    vertical_bar_view.clone().set_data(&values, &bounds)?,
+                ))
+            }
+        }
+    }
+}
+
+// Get points values with x coordinates of the horizontal scale kind.
+fn get_x_points_values(
+    x_scale: &PointsScale,
+    view: &ChartView,
+) -> Result<Vec<(f64, f32)>, RendererError> {
+    match x_scale {
+        PointsScale::Time(_) => get_time_points_values(view),
+        _ => Ok(get_points_values(view)?
+            .into_iter()
+            .map(|(x, y)| (x as f64, y))
+            .collect()),
+    }
+}
+
+// Get scales of a view that is drawn on chart axes.
+// Scale kinds of the axes the view is bound to should be supported by the view kind.
+fn get_scales(
+    axes: Option<&ChartAxes>,
+    view: &ChartView,
+) -> Result<(ChartScale, ChartScale), RendererError> {
+    let axes = match axes {
+        Some(axes) => axes,
+        None => return Err(RendererError::ChartAxesAreNotSpecified),
+    };
+    let h_scale = get_h_scale(axes, view)?;
+    let v_scale = get_v_scale(axes, view)?;
+
+    let (h_kinds, v_kinds): (&[ChartScaleKind], &[ChartScaleKind]) =
+        match ChartViewKind::from_i32(view.kind) {
+            Some(ChartViewKind::Area) | Some(ChartViewKind::Line) => (
+                &[
+                    ChartScaleKind::Band,
+                    ChartScaleKind::Linear,
+                    ChartScaleKind::Log,
+                    ChartScaleKind::Time,
+                ],
+                &[ChartScaleKind::Linear, ChartScaleKind::Log],
+            ),
+            Some(ChartViewKind::Scatter) => (
+                &[
+                    ChartScaleKind::Linear,
+                    ChartScaleKind::Log,
+                    ChartScaleKind::Time,
+                ],
+                &[ChartScaleKind::Linear, ChartScaleKind::Log],
+            ),
+            Some(ChartViewKind::HorizontalBar) => (
+                &[ChartScaleKind::Linear, ChartScaleKind::Log],
+                &[ChartScaleKind::Band],
+            ),
+            Some(ChartViewKind::VerticalBar) => (
+                &[ChartScaleKind::Band],
+                &[ChartScaleKind::Linear, ChartScaleKind::Log],
+            ),
+            Some(ChartViewKind::Histogram) => (
+                &[ChartScaleKind::Linear],
+                &[ChartScaleKind::Linear, ChartScaleKind::Log],
+            ),
+            _ => return Err(RendererError::ViewKindIsUnknown),
+        };
+    // Unknown scale kinds are reported by axes.
+    let is_supported = |kind: i32, kinds: &[ChartScaleKind]| match ChartScaleKind::from_i32(kind) {
+        Some(ChartScaleKind::UnspecifiedScale) | None => true,
+        Some(kind) => kinds.contains(&kind),
+    };
+    if !is_supported(h_scale.kind, h_kinds) {
+        return Err(RendererError::ViewHorizontalAxisScaleKindIsInvalid);
+    }
+    if !is_supported(v_scale.kind, v_kinds) {
+        return Err(RendererError::ViewVerticalAxisScaleKindIsInvalid);
+    }
+
+    Ok((h_scale, v_scale))
+}
+
+// Get view templates from protobuf views.
+// Axes are required only for views that are drawn on scales, fields of a view are checked
+// before its scales.
+pub(crate) fn get_view_templates(
     views: &[ChartView],
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
-) -> Result<Vec<Box<dyn View>>, RendererError> {
-    let mut result: Vec<Box<dyn View>> = Vec::new();
+    axes: Option<&ChartAxes>,
+    view_width: i32,
+    view_height: i32,
+) -> Result<Vec<ViewTemplate>, RendererError> {
+    let mut result = Vec::with_capacity(views.len());
 
     for proto_view in views.iter() {
         match ChartViewKind::from_i32(proto_view.kind) {
             Some(ChartViewKind::Area) => {
-                let area_view = get_area_view(proto_view, h_scale, v_scale)?;
-                result.push(Box::new(area_view));
+                let area_view = get_area_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::Area(area_view));
             }
             Some(ChartViewKind::HorizontalBar) => {
-                let horizontal_bar_view = get_horizontal_bar_view(proto_view, h_scale, v_scale)?;
-                result.push(Box::new(horizontal_bar_view));
+                let horizontal_bar_view = get_horizontal_bar_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::HorizontalBar(horizontal_bar_view));
             }
             Some(ChartViewKind::Line) => {
-                let line_view = get_line_view(proto_view, h_scale, v_scale)?;
-                result.push(Box::new(line_view));
+                let line_view = get_line_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::Line(line_view));
             }
             Some(ChartViewKind::Scatter) => {
-                let scatter_view = get_scatter_view(proto_view, h_scale, v_scale)?;
-                result.push(Box::new(scatter_view));
+                let scatter_view = get_scatter_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::Scatter(scatter_view));
             }
             Some(ChartViewKind::VerticalBar) => {
-                let vertical_bar_view = get_vertical_bar_view(proto_view, h_scale, v_scale)?;
-                result.push(Box::new(vertical_bar_view));
+                let vertical_bar_view = get_vertical_bar_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::VerticalBar(vertical_bar_view));
+            }
+            Some(ChartViewKind::Histogram) => {
+                let histogram_view = get_histogram_view_template(proto_view, axes)?;
+                result.push(ViewTemplate::Histogram(histogram_view));
+            }
+            Some(ChartViewKind::Pie) => {
+                let pie_view = get_pie_view_template(proto_view, view_width, view_height)?;
+                result.push(ViewTemplate::Pie(pie_view));
+            }
+            Some(ChartViewKind::Donut) => {
+                let donut_view = get_donut_view_template(proto_view, view_width, view_height)?;
+                result.push(ViewTemplate::Pie(donut_view));
             }
             _ => return Err(RendererError::ViewKindIsUnknown),
         }
@@ -44,20 +226,102 @@ pub(crate) fn get_views(
     Ok(result)
 }
 
-fn get_area_view(
+// Get views from view templates with values from protobuf views.
+pub(crate) fn get_views(
+    templates: &[ViewTemplate],
+    views: &[ChartView],
+) -> Result<Vec<Box<dyn View>>, RendererError> {
+    if templates.len() != views.len() {
+        return Err(RendererError::ViewValuesCountIsInvalid);
+    }
+
+    let stacks = get_area_stacks(templates, views)?;
+    let mut result: Vec<Box<dyn View>> = Vec::with_capacity(views.len());
+    for ((template, view), stack) in templates.iter().zip(views.iter()).zip(stacks.iter()) {
+        match (template, stack) {
+            (ViewTemplate::Area(area_view), Some((bottom, top))) => {
+                let values = get_scalar_values(view)?;
+                let bounds = get_scalar_bounds(view)?;
+                result.push(Box::new(
+                    area_view
+                        .clone()
+                        .set_stacked_data(values, bottom, top, &bounds)?,
+                ));
+            }
+            _ => result.push(template.set_values(view)?),
+        }
+    }
+
+    Ok(result)
+}
+
+// Get bottom and top values of area views that are stacked in groups.
+// Areas of a group are stacked in order of views, values of a normalized group
+// are converted to percentages of the group totals.
+fn get_area_stacks(
+    templates: &[ViewTemplate],
+    views: &[ChartView],
+) -> Result<Vec<Option<AreaStack>>, RendererError> {
+    // Collect totals of every stack group first.
+    let mut groups: HashMap<&str, (&AreaView, Vec<f32>)> = HashMap::new();
+    for (template, view) in templates.iter().zip(views.iter()) {
+        let area_view = match template {
+            ViewTemplate::Area(area_view) if !area_view.stack_group().is_empty() => area_view,
+            _ => continue,
+        };
+        let values = get_scalar_values(view)?;
+        let (first_view, totals) = groups
+            .entry(area_view.stack_group())
+            .or_insert_with(|| (area_view, vec![0_f32; values.len()]));
+        if first_view.stack_normalized() != area_view.stack_normalized() {
+            return Err(RendererError::StackNormalizationIsInconsistent);
+        }
+        if first_view.stack_axes() != area_view.stack_axes() {
+            return Err(RendererError::StackAxesAreInconsistent);
+        }
+        if totals.len() != values.len() {
+            return Err(RendererError::StackedAreaValuesCountIsInvalid);
+        }
+        for (total, value) in totals.iter_mut().zip(values.iter()) {
+            *total += value;
+        }
+    }
+
+    let mut bottoms: HashMap<&str, Vec<f32>> = HashMap::new();
+    let mut result = Vec::with_capacity(views.len());
+    for (template, view) in templates.iter().zip(views.iter()) {
+        let area_view = match template {
+            ViewTemplate::Area(area_view) if !area_view.stack_group().is_empty() => area_view,
+            _ => {
+                result.push(None);
+                continue;
+            }
+        };
+        let totals = &groups[area_view.stack_group()].1;
+        let values = get_scalar_values(view)?;
+        let bottom = bottoms
+            .entry(area_view.stack_group())
+            .or_insert_with(|| vec![0_f32; values.len()]);
+        let mut top = Vec::with_capacity(values.len());
+        for ((value, total), start) in values.iter().zip(totals.iter()).zip(bottom.iter()) {
+            let value = match (area_view.stack_normalized(), *total) {
+                (false, _) => *value,
+                (true, total) if total != 0_f32 => value / total * 100_f32,
+                (true, _) => 0_f32,
+            };
+            top.push(start + value);
+        }
+        result.push(Some((bottom.clone(), top.clone())));
+        *bottom = top;
+    }
+
+    Ok(result)
+}
+
+fn get_area_view_template(
     view: &ChartView,
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
+    axes: Option<&ChartAxes>,
 ) -> Result<AreaView, RendererError> {
-    let values = get_scalar_values(view)?;
-    let x_scale = match get_band_h_scale(h_scale) {
-        Ok(x_scale) => x_scale,
-        Err(err) => return Err(err),
-    };
-    let y_scale = match get_linear_v_scale(v_scale) {
-        Ok(y_scale) => y_scale,
-        Err(err) => return Err(err),
-    };
     let point_type = get_point_type(view)?;
     let point_label_position = get_point_label_position(view)?;
     let view_colors = get_view_colors(view.colors.clone())?;
@@ -85,7 +349,26 @@ fn get_area_view(
         Some(point_label_visible) => point_label_visible,
         None => return Err(RendererError::PointLabelVisibilityForAreaViewIsNotSpecified),
     };
-    let area_view = match AreaView::new(x_scale, y_scale)
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_line_h_scale(&h_scale)?;
+    let y_scale = get_numeric_v_scale(&v_scale)?;
+    if !view.stack_group.is_empty() && !matches!(x_scale, PointsScale::Band(_)) {
+        return Err(RendererError::StackedAreaViewRequiresBandScale);
+    }
+    if !view.stack_group.is_empty() && !matches!(y_scale, NumericScale::Linear(_)) {
+        return Err(RendererError::StackedAreaViewRequiresLinearScale);
+    }
+    let (h_axis, v_axis) = match axes {
+        Some(axes) => (
+            get_h_axis(axes, view.horizontal_axis)?.0,
+            get_v_axis(axes, view.vertical_axis)?.0,
+        ),
+        None => return Err(RendererError::ChartAxesAreNotSpecified),
+    };
+    let area_view = AreaView::new(x_scale, y_scale)
+        .set_stack_group(&view.stack_group)
+        .set_stack_normalized(view.stack_normalized)
+        .set_stack_axes(h_axis, v_axis)
         .set_fill_color(fill_color)
         .set_stroke_color(stroke_color)
         .set_point_fill_color(point_fill_color)
@@ -93,61 +376,36 @@ fn get_area_view(
         .set_point_type(point_type)
         .set_point_visible(point_visible)
         .set_point_label_visible(point_label_visible)
-        .set_point_label_position(point_label_position)
-        .set_data(values)
-    {
-        Ok(area_view) => area_view,
-        Err(err) => return Err(RendererError::RenderError(err)),
-    };
+        .set_point_label_position(point_label_position);
 
     Ok(area_view)
 }
 
-fn get_horizontal_bar_view(
+fn get_horizontal_bar_view_template(
     view: &ChartView,
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
+    axes: Option<&ChartAxes>,
 ) -> Result<HorizontalBarView, RendererError> {
-    let values = get_bars_values(view)?;
-    let x_scale = match get_linear_h_scale(h_scale) {
-        Ok(x_scale) => x_scale,
-        Err(err) => return Err(err),
-    };
-    let y_scale = match get_band_v_scale(v_scale) {
-        Ok(y_scale) => y_scale,
-        Err(err) => return Err(err),
-    };
     let bar_label_position = get_bar_label_position(view)?;
+    let bar_layout = get_bar_layout(view)?;
     let bar_label_visible = match view.bar_label_visible {
         Some(bar_label_visible) => bar_label_visible,
         None => return Err(RendererError::BarLabelVisibilityForHorizontalBarViewIsNotSpecified),
     };
-    let horizontal_bar_view = match HorizontalBarView::new(x_scale, y_scale)
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_numeric_h_scale(&h_scale)?;
+    let y_scale = get_band_v_scale(&v_scale)?;
+    let horizontal_bar_view = HorizontalBarView::new(x_scale, y_scale)
         .set_bar_label_visible(bar_label_visible)
         .set_bar_label_position(bar_label_position)
-        .set_data(&values)
-    {
-        Ok(horizontal_bar_view) => horizontal_bar_view,
-        Err(err) => return Err(RendererError::RenderError(err)),
-    };
+        .set_bar_layout(bar_layout);
 
     Ok(horizontal_bar_view)
 }
 
-fn get_line_view(
+fn get_line_view_template(
     view: &ChartView,
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
+    axes: Option<&ChartAxes>,
 ) -> Result<LineView, RendererError> {
-    let values = get_scalar_values(view)?;
-    let x_scale = match get_band_h_scale(h_scale) {
-        Ok(x_scale) => x_scale,
-        Err(err) => return Err(err),
-    };
-    let y_scale = match get_linear_v_scale(v_scale) {
-        Ok(y_scale) => y_scale,
-        Err(err) => return Err(err),
-    };
     let point_type = get_point_type(view)?;
     let point_label_position = get_point_label_position(view)?;
     let view_colors = get_view_colors(view.colors.clone())?;
@@ -171,37 +429,25 @@ fn get_line_view(
         Some(point_label_visible) => point_label_visible,
         None => return Err(RendererError::PointLabelVisibilityForLineViewIsNotSpecified),
     };
-    let line_view = match LineView::new(x_scale, y_scale)
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_line_h_scale(&h_scale)?;
+    let y_scale = get_numeric_v_scale(&v_scale)?;
+    let line_view = LineView::new(x_scale, y_scale)
         .set_stroke_color(stroke_color)
         .set_point_fill_color(point_fill_color)
         .set_point_stroke_color(point_stroke_color)
         .set_point_type(point_type)
         .set_point_visible(point_visible)
         .set_point_label_visible(point_label_visible)
-        .set_point_label_position(point_label_position)
-        .set_data(values)
-    {
-        Ok(line_view) => line_view,
-        Err(err) => return Err(RendererError::RenderError(err)),
-    };
+        .set_point_label_position(point_label_position);
 
     Ok(line_view)
 }
 
-fn get_scatter_view(
+fn get_scatter_view_template(
     view: &ChartView,
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
+    axes: Option<&ChartAxes>,
 ) -> Result<ScatterView, RendererError> {
-    let values = get_points_values(view)?;
-    let x_scale = match get_linear_h_scale(h_scale) {
-        Ok(x_scale) => x_scale,
-        Err(err) => return Err(err),
-    };
-    let y_scale = match get_linear_v_scale(v_scale) {
-        Ok(y_scale) => y_scale,
-        Err(err) => return Err(err),
-    };
     let point_type = get_point_type(view)?;
     let point_label_position = get_point_label_position(view)?;
     let view_colors = get_view_colors(view.colors.clone())?;
@@ -221,67 +467,140 @@ fn get_scatter_view(
         Some(point_label_visible) => point_label_visible,
         None => return Err(RendererError::PointLabelVisibilityForScatterViewIsNotSpecified),
     };
-    let scatter_view = match ScatterView::new(x_scale, y_scale)
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_scatter_h_scale(&h_scale)?;
+    let y_scale = get_numeric_v_scale(&v_scale)?;
+    let scatter_view = ScatterView::new(x_scale, y_scale)
         .set_point_fill_color(point_fill_color)
         .set_point_stroke_color(point_stroke_color)
         .set_point_type(point_type)
         .set_point_visible(point_visible)
         .set_point_label_visible(point_label_visible)
-        .set_point_label_position(point_label_position)
-        .set_data(&values)
-    {
-        Ok(scatter_view) => scatter_view,
-        Err(err) => return Err(RendererError::RenderError(err)),
-    };
+        .set_point_label_position(point_label_position);
 
     Ok(scatter_view)
 }
 
-fn get_vertical_bar_view(
+fn get_vertical_bar_view_template(
     view: &ChartView,
-    h_scale: &ChartScale,
-    v_scale: &ChartScale,
+    axes: Option<&ChartAxes>,
 ) -> Result<VerticalBarView, RendererError> {
-    let values = get_bars_values(view)?;
-    let x_scale = match get_band_h_scale(h_scale) {
-        Ok(x_scale) => x_scale,
-        Err(err) => return Err(err),
-    };
-    let y_scale = match get_linear_v_scale(v_scale) {
-        Ok(y_scale) => y_scale,
-        Err(err) => return Err(err),
-    };
     let bar_label_position = get_bar_label_position(view)?;
+    let bar_layout = get_bar_layout(view)?;
     let bar_label_visible = match view.bar_label_visible {
         Some(bar_label_visible) => bar_label_visible,
         None => return Err(RendererError::BarLabelVisibilityForVerticalBarViewIsNotSpecified),
     };
-    let vertical_bar_view = match VerticalBarView::new(x_scale, y_scale)
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_band_h_scale(&h_scale)?;
+    let y_scale = get_numeric_v_scale(&v_scale)?;
+    let vertical_bar_view = VerticalBarView::new(x_scale, y_scale)
         .set_bar_label_visible(bar_label_visible)
         .set_bar_label_position(bar_label_position)
-        .set_data(&values)
-    {
-        Ok(vertical_bar_view) => vertical_bar_view,
-        Err(err) => return Err(RendererError::RenderError(err)),
-    };
+        .set_bar_layout(bar_layout);
 
     Ok(vertical_bar_view)
 }
 
+fn get_histogram_view_template(
+    view: &ChartView,
+    axes: Option<&ChartAxes>,
+) -> Result<HistogramView, RendererError> {
+    let bar_label_position = get_bar_label_position(view)?;
+    let view_colors = get_view_colors(view.colors.clone())?;
+    let fill_color = match view_colors.fill_color {
+        Some(fill_color) => fill_color,
+        None => return Err(RendererError::FillColorForHistogramViewIsNotSpecified),
+    };
+    let stroke_color = match view_colors.stroke_color {
+        Some(stroke_color) => stroke_color,
+        None => return Err(RendererError::StrokeColorForHistogramViewIsNotSpecified),
+    };
+    let bar_label_visible = match view.bar_label_visible {
+        Some(bar_label_visible) => bar_label_visible,
+        None => return Err(RendererError::BarLabelVisibilityForHistogramViewIsNotSpecified),
+    };
+    let (h_scale, v_scale) = get_scales(axes, view)?;
+    let x_scale = get_numeric_h_scale(&h_scale)?;
+    let y_scale = get_numeric_v_scale(&v_scale)?;
+    let histogram_view = HistogramView::new(x_scale, y_scale)
+        .set_fill_color(fill_color)
+        .set_stroke_color(stroke_color)
+        .set_bar_label_visible(bar_label_visible)
+        .set_bar_label_position(bar_label_position);
+
+    Ok(histogram_view)
+}
+
+fn get_pie_view_template(
+    view: &ChartView,
+    view_width: i32,
+    view_height: i32,
+) -> Result<PieView, RendererError> {
+    let view_colors = get_view_colors(view.colors.clone())?;
+    let stroke_color = match view_colors.stroke_color {
+        Some(stroke_color) => stroke_color,
+        None => return Err(RendererError::StrokeColorForPieViewIsNotSpecified),
+    };
+    let slice_label_visible = match view.slice_label_visible {
+        Some(slice_label_visible) => slice_label_visible,
+        None => return Err(RendererError::SliceLabelVisibilityForPieViewIsNotSpecified),
+    };
+    let pie_view = PieView::new(view_width, view_height)
+        .set_start_angle(view.start_angle.unwrap_or_default())
+        .set_stroke_color(stroke_color)
+        .set_slice_label_visible(slice_label_visible);
+
+    Ok(pie_view)
+}
+
+fn get_donut_view_template(
+    view: &ChartView,
+    view_width: i32,
+    view_height: i32,
+) -> Result<PieView, RendererError> {
+    let view_colors = get_view_colors(view.colors.clone())?;
+    let stroke_color = match view_colors.stroke_color {
+        Some(stroke_color) => stroke_color,
+        None => return Err(RendererError::StrokeColorForDonutViewIsNotSpecified),
+    };
+    let slice_label_visible = match view.slice_label_visible {
+        Some(slice_label_visible) => slice_label_visible,
+        None => return Err(RendererError::SliceLabelVisibilityForDonutViewIsNotSpecified),
+    };
+    let inner_radius = match view.inner_radius {
+        Some(inner_radius) if inner_radius > 0_f32 && inner_radius < 1_f32 => inner_radius,
+        Some(_) => return Err(RendererError::InnerRadiusForDonutViewIsInvalid),
+        None => return Err(RendererError::InnerRadiusForDonutViewIsNotSpecified),
+    };
+    let donut_view = PieView::new(view_width, view_height)
+        .set_inner_radius(inner_radius)
+        .set_start_angle(view.start_angle.unwrap_or_default())
+        .set_stroke_color(stroke_color)
+        .set_slice_label_visible(slice_label_visible);
+
+    Ok(donut_view)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::proto::render::chart_element_color::ColorValue;
     use crate::proto::render::chart_scale::{ChartScaleKind, Domain};
     use crate::proto::render::chart_view::{
-        ChartViewBarLabelPosition, ChartViewPointLabelPosition, ChartViewPointType, Values,
+        ChartViewBarLabelPosition, ChartViewBarLayout, ChartViewHorizontalAxis,
+        ChartViewPointLabelPosition, ChartViewPointType, ChartViewVerticalAxis, Values,
     };
     use crate::proto::render::chart_view_bars_values::{BarsDataset, ChartViewBarsColors};
+    use crate::proto::render::chart_view_histogram_binning::ChartViewHistogramBinningKind;
     use crate::proto::render::chart_view_points_values::Point;
+    use crate::proto::render::chart_view_slices_values::Slice;
     use crate::proto::render::{
-        ChartElementColor, ChartViewBarsValues, ChartViewColors, ChartViewPointsValues,
-        ChartViewScalarValues, DomainCategories, DomainNumeric,
+        ChartElementColor, ChartViewBarsValues, ChartViewColors, ChartViewHistogramBinning,
+        ChartViewPointsValues, ChartViewScalarValues, ChartViewSlicesValues, DomainCategories,
+        DomainNumeric, DomainTime,
     };
+    use prost_types::Timestamp;
 
     fn chart_view_colors() -> ChartViewColors {
         ChartViewColors {
@@ -312,6 +631,8 @@ mod tests {
             no_boundaries_offset: false,
             inner_padding: Some(0.1_f32),
             outer_padding: Some(0.1_f32),
+            log_base: None,
+            time_format: String::new(),
         }
     }
 
@@ -326,6 +647,25 @@ mod tests {
             no_boundaries_offset: false,
             inner_padding: Some(0.1_f32),
             outer_padding: Some(0.1_f32),
+            log_base: None,
+            time_format: String::new(),
+        }
+    }
+
+    fn chart_axes(h_scale: ChartScale, v_scale: ChartScale) -> ChartAxes {
+        ChartAxes {
+            axis_top: None,
+            axis_top_label: String::new(),
+            axis_bottom: Some(h_scale),
+            axis_bottom_label: String::new(),
+            axis_left: Some(v_scale),
+            axis_left_label: String::new(),
+            axis_right: None,
+            axis_right_label: String::new(),
+            axis_top_options: None,
+            axis_bottom_options: None,
+            axis_left_options: None,
+            axis_right_options: None,
         }
     }
 
@@ -340,26 +680,47 @@ mod tests {
             point_label_visible: Some(false),
             point_label_position: 0,
             values: None,
+            inner_radius: None,
+            start_angle: None,
+            slice_label_visible: Some(false),
+            bar_layout: 0,
+            stack_group: String::new(),
+            stack_normalized: false,
+            name: String::new(),
+            horizontal_axis: 0,
+            vertical_axis: 0,
+            histogram_binning: None,
         }
     }
 
     #[test]
     fn get_area_view_basic() {
         let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Area as i32;
         view.values = Some(Values::ScalarValues(ChartViewScalarValues {
             values: vec![4_f32, 8_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
         view.point_type = ChartViewPointType::X as i32;
         view.point_label_position = ChartViewPointLabelPosition::BottomLeft as i32;
         view.point_visible = Some(true);
         view.point_label_visible = Some(true);
 
-        get_area_view(&view, &chart_scale_band(), &chart_scale_linear()).unwrap();
+        let template = get_area_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+        )
+        .unwrap();
+
+        ViewTemplate::Area(template).set_values(&view).unwrap();
     }
 
     #[test]
     fn get_horizontal_bar_view_basic() {
         let mut view = chart_view_empty();
+        view.kind = ChartViewKind::HorizontalBar as i32;
         view.values = Some(Values::BarsValues(ChartViewBarsValues {
             bars_datasets: vec![BarsDataset {
                 values: vec![16_f32, 32_f32],
@@ -371,51 +732,88 @@ mod tests {
                         color_value: Some(ColorValue::ColorHex("#7d69ac".to_string())),
                     }),
                 }),
+                name: String::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
             }],
+            categories: Vec::new(),
         }));
         view.bar_label_visible = Some(true);
         view.bar_label_position = ChartViewBarLabelPosition::EndOutside as i32;
 
-        get_horizontal_bar_view(&view, &chart_scale_linear(), &chart_scale_band()).unwrap();
+        let template = get_horizontal_bar_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_band())),
+        )
+        .unwrap();
+
+        ViewTemplate::HorizontalBar(template)
+            .set_values(&view)
+            .unwrap();
     }
 
     #[test]
     fn get_line_view_basic() {
         let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
         view.values = Some(Values::ScalarValues(ChartViewScalarValues {
             values: vec![4_f32, 8_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
         view.point_type = ChartViewPointType::Square as i32;
         view.point_label_position = ChartViewPointLabelPosition::TopLeft as i32;
         view.point_visible = Some(true);
         view.point_label_visible = Some(true);
 
-        get_line_view(&view, &chart_scale_band(), &chart_scale_linear()).unwrap();
+        let template = get_line_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+        )
+        .unwrap();
+
+        ViewTemplate::Line(template).set_values(&view).unwrap();
     }
 
     #[test]
     fn get_scatter_view_basic() {
         let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Scatter as i32;
         view.values = Some(Values::PointsValues(ChartViewPointsValues {
             points: vec![
-                Point { x: 2_f32, y: 4_f32 },
+                Point {
+                    x: 2_f32,
+                    y: 4_f32,
+                    time: None,
+                },
                 Point {
                     x: 32_f32,
                     y: 16_f32,
+                    time: None,
                 },
             ],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
         view.point_type = ChartViewPointType::Circle as i32;
         view.point_label_position = ChartViewPointLabelPosition::TopRight as i32;
         view.point_visible = Some(true);
         view.point_label_visible = Some(true);
 
-        get_scatter_view(&view, &chart_scale_linear(), &chart_scale_linear()).unwrap();
+        let template = get_scatter_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_linear())),
+        )
+        .unwrap();
+
+        ViewTemplate::Scatter(template).set_values(&view).unwrap();
     }
 
     #[test]
     fn get_vertical_bar_view_basic() {
         let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
         view.values = Some(Values::BarsValues(ChartViewBarsValues {
             bars_datasets: vec![BarsDataset {
                 values: vec![64_f32, 32_f32],
@@ -427,12 +825,207 @@ mod tests {
                         color_value: Some(ColorValue::ColorHex("#02b502".to_string())),
                     }),
                 }),
+                name: String::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
             }],
+            categories: Vec::new(),
         }));
         view.bar_label_visible = Some(true);
         view.bar_label_position = ChartViewBarLabelPosition::StartInside as i32;
 
-        get_vertical_bar_view(&view, &chart_scale_band(), &chart_scale_linear()).unwrap();
+        let template = get_vertical_bar_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+        )
+        .unwrap();
+
+        ViewTemplate::VerticalBar(template)
+            .set_values(&view)
+            .unwrap();
+    }
+
+    fn chart_view_slices_values() -> ChartViewSlicesValues {
+        ChartViewSlicesValues {
+            slices: vec![
+                Slice {
+                    label: "a".to_string(),
+                    value: 30_f32,
+                    fill: Some(ChartElementColor {
+                        color_value: Some(ColorValue::ColorHex("#cc6633".to_string())),
+                    }),
+                },
+                Slice {
+                    label: "b".to_string(),
+                    value: 10_f32,
+                    fill: Some(ChartElementColor {
+                        color_value: Some(ColorValue::ColorHex("#ff9933".to_string())),
+                    }),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn get_pie_view_basic() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Pie as i32;
+        view.values = Some(Values::SlicesValues(chart_view_slices_values()));
+        view.start_angle = Some(90_f32);
+        view.slice_label_visible = Some(true);
+
+        let templates = get_view_templates(&[view.clone()], None, 400, 300).unwrap();
+        let pie_svg = get_views(&templates, &[view]).unwrap()[0]
+            .to_svg()
+            .to_string();
+
+        assert!(pie_svg.starts_with(r#"<g transform="translate(200,150)">"#));
+        assert!(pie_svg.contains(">\na 75%\n<"));
+        assert!(pie_svg.contains(">\nb 25%\n<"));
+    }
+
+    #[test]
+    fn get_donut_view_basic() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Donut as i32;
+        view.values = Some(Values::SlicesValues(chart_view_slices_values()));
+        view.inner_radius = Some(0.5_f32);
+
+        let template = get_donut_view_template(&view, 400, 300).unwrap();
+
+        ViewTemplate::Pie(template).set_values(&view).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_donut_view_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Donut as i32;
+        view.inner_radius = Some(1_f32);
+
+        get_view_templates(&[view], None, 400, 300).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_views_axes_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
+
+        get_view_templates(&[view], None, 400, 300).unwrap();
+    }
+
+    fn chart_view_bars_values() -> ChartViewBarsValues {
+        ChartViewBarsValues {
+            bars_datasets: vec![
+                BarsDataset {
+                    values: vec![300_f32, 600_f32],
+                    colors: Some(ChartViewBarsColors {
+                        fill: Some(ChartElementColor {
+                            color_value: Some(ColorValue::ColorHex("#028c02".to_string())),
+                        }),
+                        stroke: Some(ChartElementColor {
+                            color_value: Some(ColorValue::ColorHex("#02b502".to_string())),
+                        }),
+                    }),
+                    name: String::new(),
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                },
+                BarsDataset {
+                    values: vec![100_f32, 200_f32],
+                    colors: Some(ChartViewBarsColors {
+                        fill: Some(ChartElementColor {
+                            color_value: Some(ColorValue::ColorHex("#cc6633".to_string())),
+                        }),
+                        stroke: Some(ChartElementColor {
+                            color_value: Some(ColorValue::ColorHex("#ff9933".to_string())),
+                        }),
+                    }),
+                    name: String::new(),
+                    lower_bounds: Vec::new(),
+                    upper_bounds: Vec::new(),
+                },
+            ],
+            categories: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn get_vertical_bar_view_grouped() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
+        view.values = Some(Values::BarsValues(chart_view_bars_values()));
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        view.bar_layout = ChartViewBarLayout::Grouped as i32;
+        let mut v_scale = chart_scale_linear();
+        v_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 1000_f32,
+        }));
+
+        let template =
+            get_vertical_bar_view_template(&view, Some(&chart_axes(chart_scale_band(), v_scale)))
+                .unwrap();
+        let bars_svg = ViewTemplate::VerticalBar(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert_eq!(4, bars_svg.matches("<rect").count());
+        assert_eq!(4, bars_svg.matches(r#"width="21.428572""#).count());
+        assert!(bars_svg.contains(r#"<g class="bar" transform="translate(4.7619057,0)">"#));
+        assert!(bars_svg.contains(r#"<g class="bar" transform="translate(26.190477,0)">"#));
+    }
+
+    #[test]
+    fn get_horizontal_bar_view_percent_stacked() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::HorizontalBar as i32;
+        view.values = Some(Values::BarsValues(chart_view_bars_values()));
+        view.bar_label_visible = Some(true);
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        view.bar_layout = ChartViewBarLayout::PercentStacked as i32;
+        let mut h_scale = chart_scale_linear();
+        h_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 100_f32,
+        }));
+
+        let template =
+            get_horizontal_bar_view_template(&view, Some(&chart_axes(h_scale, chart_scale_band())))
+                .unwrap();
+        let bars_svg = ViewTemplate::HorizontalBar(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert_eq!(2, bars_svg.matches(">\n75%\n<").count());
+        assert_eq!(2, bars_svg.matches(">\n25%\n<").count());
+        assert_eq!(2, bars_svg.matches(r#"width="25" x="75""#).count());
+    }
+
+    #[test]
+    fn get_vertical_bar_view_values_count_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
+        let mut bars_values = chart_view_bars_values();
+        bars_values.bars_datasets[1].values.pop();
+        view.values = Some(Values::BarsValues(bars_values));
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+
+        let template = get_vertical_bar_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            ViewTemplate::VerticalBar(template).set_values(&view),
+            Err(RendererError::BarsDatasetValuesCountIsInvalid)
+        ));
     }
 
     #[test]
@@ -440,6 +1033,9 @@ mod tests {
         let mut line_view = chart_view_empty();
         line_view.values = Some(Values::ScalarValues(ChartViewScalarValues {
             values: vec![10_f32, 100_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
         }));
         line_view.point_type = ChartViewPointType::Circle as i32;
         line_view.point_label_position = ChartViewPointLabelPosition::Right as i32;
@@ -459,7 +1055,11 @@ mod tests {
                         color_value: Some(ColorValue::ColorHex("#ff9933".to_string())),
                     }),
                 }),
+                name: String::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
             }],
+            categories: Vec::new(),
         }));
         vertical_bar_view.bar_label_visible = Some(true);
         vertical_bar_view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
@@ -467,13 +1067,551 @@ mod tests {
 
         let views = vec![line_view, vertical_bar_view];
 
-        get_views(&views, &chart_scale_band(), &chart_scale_linear()).unwrap();
+        let templates = get_view_templates(
+            &views,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+            100,
+            100,
+        )
+        .unwrap();
+
+        get_views(&templates, &views).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn get_views_err() {
         let views = vec![chart_view_empty()];
-        get_views(&views, &chart_scale_linear(), &chart_scale_band()).unwrap();
+        get_view_templates(
+            &views,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_band())),
+            100,
+            100,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn get_views_dual_axes() {
+        let mut left_view = chart_view_empty();
+        left_view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+            values: vec![300_f32, 700_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        left_view.kind = ChartViewKind::Line as i32;
+        left_view.point_type = ChartViewPointType::Circle as i32;
+        left_view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+
+        let mut right_view = left_view.clone();
+        right_view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+            values: vec![0.2_f32, 0.8_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        right_view.vertical_axis = ChartViewVerticalAxis::AxisRight as i32;
+
+        let mut axes = chart_axes(chart_scale_band(), chart_scale_linear());
+        let mut right_scale = chart_scale_linear();
+        right_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 1_f32,
+        }));
+        axes.axis_right = Some(right_scale);
+        let views = vec![left_view, right_view];
+
+        let templates = get_view_templates(&views, Some(&axes), 100, 100).unwrap();
+
+        get_views(&templates, &views).unwrap();
+    }
+
+    #[test]
+    fn get_views_axis_scale_kind_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        view.horizontal_axis = ChartViewHorizontalAxis::AxisTop as i32;
+        let mut axes = chart_axes(chart_scale_band(), chart_scale_linear());
+        axes.axis_top = Some(chart_scale_linear());
+
+        assert!(matches!(
+            get_view_templates(&[view], Some(&axes), 100, 100),
+            Err(RendererError::ViewHorizontalAxisScaleKindIsInvalid)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_views_count_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+        let templates = get_view_templates(
+            &[view],
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+            100,
+            100,
+        )
+        .unwrap();
+
+        get_views(&templates, &[]).unwrap();
+    }
+
+    fn stacked_area_views(values: &[Vec<f32>]) -> (Vec<ViewTemplate>, Vec<ChartView>) {
+        let mut v_scale = chart_scale_linear();
+        v_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 100_f32,
+        }));
+        let mut templates = Vec::new();
+        let mut views = Vec::new();
+        for values in values.iter() {
+            let mut view = chart_view_empty();
+            view.kind = ChartViewKind::Area as i32;
+            view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+                values: values.clone(),
+                categories: Vec::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
+            }));
+            view.point_type = ChartViewPointType::Circle as i32;
+            view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+            view.point_visible = Some(true);
+            view.point_label_visible = Some(true);
+            view.stack_group = "stack".to_string();
+            templates.push(ViewTemplate::Area(
+                get_area_view_template(
+                    &view,
+                    Some(&chart_axes(chart_scale_band(), v_scale.clone())),
+                )
+                .unwrap(),
+            ));
+            views.push(view);
+        }
+
+        (templates, views)
+    }
+
+    #[test]
+    fn get_area_views_stacked() {
+        let (templates, views) = stacked_area_views(&[vec![10_f32, 20_f32], vec![30_f32, 40_f32]]);
+
+        let area_svg = get_views(&templates, &views).unwrap()[1]
+            .to_svg()
+            .to_string();
+
+        assert!(area_svg
+            .contains(r#"d="M26.190477,40 L73.809525,60.000004 L73.809525,20 L26.190477,10 z""#));
+        assert!(area_svg.contains(">\n30\n<"));
+        assert!(area_svg.contains(">\n40\n<"));
+    }
+
+    #[test]
+    fn get_area_views_stacked_normalized() {
+        let (mut templates, views) =
+            stacked_area_views(&[vec![10_f32, 30_f32], vec![30_f32, 10_f32]]);
+        for template in templates.iter_mut() {
+            if let ViewTemplate::Area(area_view) = template {
+                *area_view = area_view.clone().set_stack_normalized(true);
+            }
+        }
+
+        let area_svg = get_views(&templates, &views).unwrap()[1]
+            .to_svg()
+            .to_string();
+
+        assert!(
+            area_svg.contains(r#"d="M26.190477,100 L73.809525,100 L73.809525,75 L26.190477,25 z""#)
+        );
+        assert!(area_svg.contains(">\n30\n<"));
+        assert!(area_svg.contains(">\n10\n<"));
+    }
+
+    #[test]
+    fn get_area_views_stacked_count_err() {
+        let (templates, views) = stacked_area_views(&[vec![10_f32, 20_f32], vec![30_f32]]);
+
+        assert!(matches!(
+            get_views(&templates, &views),
+            Err(RendererError::StackedAreaValuesCountIsInvalid)
+        ));
+    }
+
+    #[test]
+    fn get_area_views_stacked_normalization_err() {
+        let (mut templates, views) =
+            stacked_area_views(&[vec![10_f32, 20_f32], vec![30_f32, 40_f32]]);
+        if let ViewTemplate::Area(area_view) = &mut templates[0] {
+            *area_view = area_view.clone().set_stack_normalized(true);
+        }
+
+        assert!(matches!(
+            get_views(&templates, &views),
+            Err(RendererError::StackNormalizationIsInconsistent)
+        ));
+    }
+
+    #[test]
+    fn get_area_views_stacked_axes_err() {
+        let (_, mut views) = stacked_area_views(&[vec![10_f32, 20_f32], vec![30_f32, 40_f32]]);
+        views[1].vertical_axis = ChartViewVerticalAxis::AxisRight as i32;
+        let mut axes = chart_axes(chart_scale_band(), chart_scale_linear());
+        axes.axis_right = Some(chart_scale_linear());
+
+        let templates = get_view_templates(&views, Some(&axes), 100, 100).unwrap();
+
+        assert!(matches!(
+            get_views(&templates, &views),
+            Err(RendererError::StackAxesAreInconsistent)
+        ));
+    }
+
+    #[test]
+    fn get_area_view_stacked_log_scale_err() {
+        let (_, views) = stacked_area_views(&[vec![10_f32, 20_f32]]);
+        let mut v_scale = chart_scale_linear();
+        v_scale.kind = ChartScaleKind::Log as i32;
+        v_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 1_f32,
+            end: 100_f32,
+        }));
+
+        assert!(matches!(
+            get_area_view_template(&views[0], Some(&chart_axes(chart_scale_band(), v_scale))),
+            Err(RendererError::StackedAreaViewRequiresLinearScale)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_area_view_stacked_scale_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Area as i32;
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+        view.point_visible = Some(true);
+        view.point_label_visible = Some(true);
+        view.stack_group = "stack".to_string();
+
+        get_area_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_linear())),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn get_area_view_linear_scale() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Area as i32;
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![
+                Point {
+                    x: 700_f32,
+                    y: 250_f32,
+                    time: None,
+                },
+                Point {
+                    x: 210_f32,
+                    y: 400_f32,
+                    time: None,
+                },
+                Point {
+                    x: 330_f32,
+                    y: 300_f32,
+                    time: None,
+                },
+            ],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+
+        let template = get_area_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_linear())),
+        )
+        .unwrap();
+        let area_svg = ViewTemplate::Area(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert!(area_svg.contains(r#"d="M1.6666667,33.333336 L21.666666,16.666668 L83.33333,8.333334 L83.33333,100 L1.6666667,100 z""#));
+    }
+
+    #[test]
+    fn get_line_view_linear_scale() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![
+                Point {
+                    x: 700_f32,
+                    y: 250_f32,
+                    time: None,
+                },
+                Point {
+                    x: 210_f32,
+                    y: 400_f32,
+                    time: None,
+                },
+                Point {
+                    x: 330_f32,
+                    y: 300_f32,
+                    time: None,
+                },
+            ],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+
+        let template = get_line_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_linear())),
+        )
+        .unwrap();
+        let line_svg = ViewTemplate::Line(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert!(line_svg
+            .contains(r#"d="M1.6666667,33.333336 L21.666666,16.666668 L83.33333,8.333334""#));
+    }
+
+    #[test]
+    fn get_line_view_bounds() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![
+                Point {
+                    x: 700_f32,
+                    y: 250_f32,
+                    time: None,
+                },
+                Point {
+                    x: 210_f32,
+                    y: 400_f32,
+                    time: None,
+                },
+            ],
+            lower_bounds: vec![200_f32, 350_f32],
+            upper_bounds: vec![300_f32, 450_f32],
+        }));
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+
+        let template = get_line_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_linear(), chart_scale_linear())),
+        )
+        .unwrap();
+        let line_svg = ViewTemplate::Line(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert!(line_svg.contains(r#"<path class="interval" d="M1.6666667,41.666664 L83.33333,16.666668 L83.33333,0 L1.6666667,25 z""#));
+    }
+
+    #[test]
+    fn get_vertical_bar_view_error_bars() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
+        let mut bars_values = chart_view_bars_values();
+        bars_values.bars_datasets[0].lower_bounds = vec![250_f32, 500_f32];
+        bars_values.bars_datasets[0].upper_bounds = vec![350_f32, 700_f32];
+        view.values = Some(Values::BarsValues(bars_values));
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        view.bar_layout = ChartViewBarLayout::Grouped as i32;
+
+        let template = get_vertical_bar_view_template(
+            &view,
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+        )
+        .unwrap();
+        let bars_svg = ViewTemplate::VerticalBar(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert_eq!(2, bars_svg.matches(r#"<g class="error-bar">"#).count());
+        assert!(bars_svg.contains(r#"x1="15.4761915" x2="15.4761915" y1="8.333334" y2="25""#));
+    }
+
+    #[test]
+    fn get_histogram_view_basic() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Histogram as i32;
+        view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+            values: vec![1_f32, 2_f32, 2_f32, 3_f32, 9_f32],
+            ..ChartViewScalarValues::default()
+        }));
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        view.histogram_binning = Some(ChartViewHistogramBinning {
+            kind: ChartViewHistogramBinningKind::FixedWidth as i32,
+            bin_width: Some(5_f32),
+            ..ChartViewHistogramBinning::default()
+        });
+        let mut h_scale = chart_scale_linear();
+        h_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 10_f32,
+        }));
+        let mut v_scale = chart_scale_linear();
+        v_scale.range_start = Some(100);
+        v_scale.range_end = Some(0);
+        v_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 0_f32,
+            end: 4_f32,
+        }));
+
+        let template =
+            get_histogram_view_template(&view, Some(&chart_axes(h_scale, v_scale))).unwrap();
+        let histogram_svg = ViewTemplate::Histogram(template)
+            .set_values(&view)
+            .unwrap()
+            .to_svg()
+            .to_string();
+
+        assert_eq!(2, histogram_svg.matches(r#"class="bar""#).count());
+        assert!(histogram_svg.contains(r#"<g class="bar" transform="translate(50,0)">"#));
+        assert!(histogram_svg.contains(r#"height="25""#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_histogram_view_scale_kind_err() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Histogram as i32;
+
+        get_view_templates(
+            &[view],
+            Some(&chart_axes(chart_scale_band(), chart_scale_linear())),
+            100,
+            100,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn get_views_time_scale() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::Line as i32;
+        view.values = Some(Values::PointsValues(ChartViewPointsValues {
+            points: vec![
+                Point {
+                    x: 0_f32,
+                    y: 400_f32,
+                    time: Some(Timestamp {
+                        seconds: 1614643200,
+                        nanos: 0,
+                    }),
+                },
+                Point {
+                    x: 0_f32,
+                    y: 300_f32,
+                    time: Some(Timestamp {
+                        seconds: 1614556800,
+                        nanos: 0,
+                    }),
+                },
+            ],
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        view.point_type = ChartViewPointType::Circle as i32;
+        view.point_label_position = ChartViewPointLabelPosition::Top as i32;
+        let mut h_scale = chart_scale_linear();
+        h_scale.kind = ChartScaleKind::Time as i32;
+        h_scale.domain = Some(Domain::DomainTime(DomainTime {
+            start: Some(Timestamp {
+                seconds: 1614556800,
+                nanos: 0,
+            }),
+            end: Some(Timestamp {
+                seconds: 1614643200,
+                nanos: 0,
+            }),
+        }));
+
+        let templates = get_view_templates(
+            &[view.clone()],
+            Some(&chart_axes(h_scale, chart_scale_linear())),
+            100,
+            100,
+        )
+        .unwrap();
+        get_views(&templates, &[view.clone()]).unwrap();
+
+        view.values = Some(Values::ScalarValues(ChartViewScalarValues {
+            values: vec![10_f32, 100_f32],
+            categories: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+        }));
+        assert!(matches!(
+            templates[0].set_values(&view),
+            Err(RendererError::ExpectedPointsValues)
+        ));
+    }
+
+    #[test]
+    fn get_views_log_scale() {
+        let mut view = chart_view_empty();
+        view.kind = ChartViewKind::VerticalBar as i32;
+        view.values = Some(Values::BarsValues(ChartViewBarsValues {
+            bars_datasets: vec![BarsDataset {
+                values: vec![20_f32, 2000_f32],
+                colors: Some(ChartViewBarsColors {
+                    fill: Some(ChartElementColor {
+                        color_value: Some(ColorValue::ColorHex("#cc6633".to_string())),
+                    }),
+                    stroke: Some(ChartElementColor {
+                        color_value: Some(ColorValue::ColorHex("#ff9933".to_string())),
+                    }),
+                }),
+                name: String::new(),
+                lower_bounds: Vec::new(),
+                upper_bounds: Vec::new(),
+            }],
+            categories: Vec::new(),
+        }));
+        view.bar_label_position = ChartViewBarLabelPosition::Center as i32;
+        let mut v_scale = chart_scale_linear();
+        v_scale.kind = ChartScaleKind::Log as i32;
+        v_scale.domain = Some(Domain::DomainNumeric(DomainNumeric {
+            start: 1_f32,
+            end: 10000_f32,
+        }));
+
+        let templates = get_view_templates(
+            &[view.clone()],
+            Some(&chart_axes(chart_scale_band(), v_scale)),
+            100,
+            100,
+        )
+        .unwrap();
+        get_views(&templates, &[view.clone()]).unwrap();
+
+        if let Some(Values::BarsValues(bars_values)) = &mut view.values {
+            bars_values.bars_datasets[0].values = vec![-20_f32, 20_f32];
+        }
+        assert!(matches!(
+            templates[0].set_values(&view),
+            Err(RendererError::LogScaleValueIsNotPositive)
+        ));
     }
 }